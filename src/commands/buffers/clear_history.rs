@@ -2,6 +2,8 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_1_5")]
+use crate::IntoTargetPane;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -47,8 +49,8 @@ impl<'a> ClearHistory<'a> {
 
     /// `[-t target-pane]`
     #[cfg(feature = "tmux_1_5")]
-    pub fn target_pane<S: Into<Cow<'a, str>>>(mut self, target_pane: S) -> Self {
-        self.target_pane = Some(target_pane.into());
+    pub fn target_pane<S: IntoTargetPane<'a>>(mut self, target_pane: S) -> Self {
+        self.target_pane = Some(target_pane.into_target());
         self
     }
 
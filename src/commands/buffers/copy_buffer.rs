@@ -2,6 +2,8 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
+use crate::IntoTargetSession;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -47,15 +49,15 @@ impl<'a> CopyBuffer<'a> {
 
     /// `[-s src-session]`
     #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
-    pub fn src_session<S: Into<Cow<'a, str>>>(mut self, src_session: S) -> Self {
-        self.src_session = Some(src_session.into());
+    pub fn src_session<S: IntoTargetSession<'a>>(mut self, src_session: S) -> Self {
+        self.src_session = Some(src_session.into_target());
         self
     }
 
     /// `[-t dst-session]`
     #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
-    pub fn dst_session<S: Into<Cow<'a, str>>>(mut self, dst_session: S) -> Self {
-        self.dst_session = Some(dst_session.into());
+    pub fn dst_session<S: IntoTargetSession<'a>>(mut self, dst_session: S) -> Self {
+        self.dst_session = Some(dst_session.into_target());
         self
     }
 
@@ -2,6 +2,8 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
+use crate::IntoTargetSession;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -65,8 +67,8 @@ impl<'a> DeleteBuffer<'a> {
 
     /// `[-t target-session]`
     #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
-    pub fn target_session<S: Into<Cow<'a, str>>>(mut self, target_session: S) -> Self {
-        self.target_session = Some(target_session.into());
+    pub fn target_session<S: IntoTargetSession<'a>>(mut self, target_session: S) -> Self {
+        self.target_session = Some(target_session.into_target());
         self
     }
 
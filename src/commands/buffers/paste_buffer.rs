@@ -2,6 +2,10 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_1_5")]
+use crate::IntoTargetPane;
+#[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
+use crate::IntoTargetWindow;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -112,15 +116,15 @@ impl<'a> PasteBuffer<'a> {
 
     /// `[-t target-window]`
     #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
-    pub fn target_window<S: Into<Cow<'a, str>>>(mut self, target_window: S) -> Self {
-        self.target_window = Some(target_window.into());
+    pub fn target_window<S: IntoTargetWindow<'a>>(mut self, target_window: S) -> Self {
+        self.target_window = Some(target_window.into_target());
         self
     }
 
     /// `[-t target-pane]`
     #[cfg(feature = "tmux_1_5")]
-    pub fn target_pane<S: Into<Cow<'a, str>>>(mut self, target_pane: S) -> Self {
-        self.target_pane = Some(target_pane.into());
+    pub fn target_pane<S: IntoTargetPane<'a>>(mut self, target_pane: S) -> Self {
+        self.target_pane = Some(target_pane.into_target());
         self
     }
 
@@ -2,6 +2,10 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_3_2")]
+use crate::IntoTargetClient;
+#[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
+use crate::IntoTargetSession;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -104,15 +108,15 @@ impl<'a> SetBuffer<'a> {
 
     /// `[-t target-client]`
     #[cfg(feature = "tmux_3_2")]
-    pub fn target_client<S: Into<Cow<'a, str>>>(mut self, target_client: S) -> Self {
-        self.target_client = Some(target_client.into());
+    pub fn target_client<S: IntoTargetClient<'a>>(mut self, target_client: S) -> Self {
+        self.target_client = Some(target_client.into_target());
         self
     }
 
     /// `[-t target-session]`
     #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
-    pub fn target_session<S: Into<Cow<'a, str>>>(mut self, target_session: S) -> Self {
-        self.target_session = Some(target_session.into());
+    pub fn target_session<S: IntoTargetSession<'a>>(mut self, target_session: S) -> Self {
+        self.target_session = Some(target_session.into_target());
         self
     }
 
@@ -4,6 +4,8 @@
 use crate::commands::constants::*;
 #[cfg(feature = "tmux_3_2")]
 use crate::ClientFlags;
+#[cfg(feature = "tmux_0_8")]
+use crate::IntoTargetSession;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -128,8 +130,8 @@ impl<'a> AttachSession<'a> {
 
     /// `[-t target-session]`
     #[cfg(feature = "tmux_0_8")]
-    pub fn target_session<S: Into<Cow<'a, str>>>(mut self, target_session: S) -> Self {
-        self.target_session = Some(target_session.into());
+    pub fn target_session<S: IntoTargetSession<'a>>(mut self, target_session: S) -> Self {
+        self.target_session = Some(target_session.into_target());
         self
     }
 
@@ -2,6 +2,10 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_0_8")]
+use crate::IntoTargetClient;
+#[cfg(feature = "tmux_1_5")]
+use crate::IntoTargetSession;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -85,15 +89,15 @@ impl<'a> DetachClient<'a> {
 
     /// `[-s target-session]`
     #[cfg(feature = "tmux_1_5")]
-    pub fn target_session<S: Into<Cow<'a, str>>>(mut self, target_session: S) -> Self {
-        self.target_session = Some(target_session.into());
+    pub fn target_session<S: IntoTargetSession<'a>>(mut self, target_session: S) -> Self {
+        self.target_session = Some(target_session.into_target());
         self
     }
 
     /// `[-t target-client]`
     #[cfg(feature = "tmux_0_8")]
-    pub fn target_client<S: Into<Cow<'a, str>>>(mut self, target_client: S) -> Self {
-        self.target_client = Some(target_client.into());
+    pub fn target_client<S: IntoTargetClient<'a>>(mut self, target_client: S) -> Self {
+        self.target_client = Some(target_client.into_target());
         self
     }
 
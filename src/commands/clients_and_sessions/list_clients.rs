@@ -2,6 +2,8 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_1_5")]
+use crate::IntoTargetSession;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -64,8 +66,8 @@ impl<'a> ListClients<'a> {
 
     /// `[-t target-session]`
     #[cfg(feature = "tmux_1_5")]
-    pub fn target_session<S: Into<Cow<'a, str>>>(mut self, target_session: S) -> Self {
-        self.target_session = Some(target_session.into());
+    pub fn target_session<S: IntoTargetSession<'a>>(mut self, target_session: S) -> Self {
+        self.target_session = Some(target_session.into_target());
         self
     }
 
@@ -4,6 +4,8 @@
 use crate::commands::constants::*;
 #[cfg(feature = "tmux_3_2")]
 use crate::ClientFlags;
+#[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_4")))]
+use crate::IntoTargetSession;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -249,8 +251,8 @@ impl<'a> NewSession<'a> {
 
     /// `[-t target-session]`
     #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_4")))]
-    pub fn target_session<S: Into<Cow<'a, str>>>(mut self, target_session: S) -> Self {
-        self.target_session = Some(target_session.into());
+    pub fn target_session<S: IntoTargetSession<'a>>(mut self, target_session: S) -> Self {
+        self.target_session = Some(target_session.into_target());
         self
     }
 
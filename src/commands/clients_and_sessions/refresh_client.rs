@@ -5,6 +5,10 @@ use crate::commands::constants::*;
 
 #[cfg(feature = "tmux_2_9a")]
 use crate::ClientFlags;
+#[cfg(feature = "tmux_0_8")]
+use crate::IntoTargetClient;
+#[cfg(feature = "tmux_3_3")]
+use crate::IntoTargetPane;
 use crate::TmuxCommand;
 #[cfg(feature = "tmux_3_2")]
 use crate::{AllowActions, Subscribe};
@@ -232,8 +236,8 @@ impl<'a> RefreshClient<'a> {
     }
     /// `[-l target-pane]`
     #[cfg(feature = "tmux_3_3")]
-    pub fn target_pane<S: Into<Cow<'a, str>>>(mut self, target_pane: S) -> Self {
-        self.target_pane = Some(target_pane.into());
+    pub fn target_pane<S: IntoTargetPane<'a>>(mut self, target_pane: S) -> Self {
+        self.target_pane = Some(target_pane.into_target());
         self
     }
 
@@ -246,8 +250,8 @@ impl<'a> RefreshClient<'a> {
 
     /// `[-t target-client]`
     #[cfg(feature = "tmux_0_8")]
-    pub fn target_client<S: Into<Cow<'a, str>>>(mut self, target_client: S) -> Self {
-        self.target_client = Some(target_client.into());
+    pub fn target_client<S: IntoTargetClient<'a>>(mut self, target_client: S) -> Self {
+        self.target_client = Some(target_client.into_target());
         self
     }
 
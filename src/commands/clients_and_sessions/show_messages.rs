@@ -2,6 +2,8 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_1_5")]
+use crate::IntoTargetClient;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -75,8 +77,8 @@ impl<'a> ShowMessages<'a> {
 
     /// `[-t target-client]`
     #[cfg(feature = "tmux_1_5")]
-    pub fn target_client<S: Into<Cow<'a, str>>>(mut self, target_client: S) -> Self {
-        self.target_client = Some(target_client.into());
+    pub fn target_client<S: IntoTargetClient<'a>>(mut self, target_client: S) -> Self {
+        self.target_client = Some(target_client.into_target());
         self
     }
 
@@ -2,6 +2,10 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_3_2")]
+use crate::IntoTargetPane;
+#[cfg(all(feature = "tmux_2_2", not(feature = "tmux_3_2")))]
+use crate::IntoTargetSession;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -69,15 +73,15 @@ impl<'a> ShowHooks<'a> {
 
     /// `[-t target-session]`
     #[cfg(all(feature = "tmux_2_2", not(feature = "tmux_3_2")))]
-    pub fn target_session<S: Into<Cow<'a, str>>>(mut self, target_session: S) -> Self {
-        self.target_session = Some(target_session.into());
+    pub fn target_session<S: IntoTargetSession<'a>>(mut self, target_session: S) -> Self {
+        self.target_session = Some(target_session.into_target());
         self
     }
 
     /// `[-t target-pane]`
     #[cfg(feature = "tmux_3_2")]
-    pub fn target_pane<S: Into<Cow<'a, str>>>(mut self, target_pane: S) -> Self {
-        self.target_pane = Some(target_pane.into());
+    pub fn target_pane<S: IntoTargetPane<'a>>(mut self, target_pane: S) -> Self {
+        self.target_pane = Some(target_pane.into_target());
         self
     }
 
@@ -2,6 +2,12 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_3_4")]
+use crate::IntoTargetClient;
+#[cfg(feature = "tmux_2_4")]
+use crate::IntoTargetPane;
+#[cfg(all(feature = "tmux_0_8", not(feature = "tmux_2_4")))]
+use crate::IntoTargetWindow;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -172,22 +178,22 @@ impl<'a> SendKeys<'a> {
 
     /// `[-c target-client]`
     #[cfg(feature = "tmux_3_4")]
-    pub fn target_client<S: Into<Cow<'a, str>>>(mut self, target_client: S) -> Self {
-        self.target_client = Some(target_client.into());
+    pub fn target_client<S: IntoTargetClient<'a>>(mut self, target_client: S) -> Self {
+        self.target_client = Some(target_client.into_target());
         self
     }
 
     /// `[-t target-window]`
     #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_2_4")))]
-    pub fn target_window<S: Into<Cow<'a, str>>>(mut self, target_window: S) -> Self {
-        self.target_window = Some(target_window.into());
+    pub fn target_window<S: IntoTargetWindow<'a>>(mut self, target_window: S) -> Self {
+        self.target_window = Some(target_window.into_target());
         self
     }
 
     /// `[-t target-pane]`
     #[cfg(feature = "tmux_2_4")]
-    pub fn target_pane<S: Into<Cow<'a, str>>>(mut self, target_pane: S) -> Self {
-        self.target_pane = Some(target_pane.into());
+    pub fn target_pane<S: IntoTargetPane<'a>>(mut self, target_pane: S) -> Self {
+        self.target_pane = Some(target_pane.into_target());
         self
     }
 
@@ -2,6 +2,8 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_1_8")]
+use crate::IntoTargetPane;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -79,8 +81,8 @@ impl<'a> IfShell<'a> {
 
     /// `[-t target-pane]`
     #[cfg(feature = "tmux_1_8")]
-    pub fn target_pane<S: Into<Cow<'a, str>>>(mut self, target_pane: S) -> Self {
-        self.target_pane = Some(target_pane.into());
+    pub fn target_pane<S: IntoTargetPane<'a>>(mut self, target_pane: S) -> Self {
+        self.target_pane = Some(target_pane.into_target());
         self
     }
 
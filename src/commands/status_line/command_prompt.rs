@@ -2,6 +2,8 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_0_8")]
+use crate::IntoTargetClient;
 #[cfg(feature = "tmux_3_3")]
 use crate::PromptType;
 use crate::TmuxCommand;
@@ -194,8 +196,8 @@ impl<'a> CommandPrompt<'a> {
 
     /// `[-t target-client]`
     #[cfg(feature = "tmux_0_8")]
-    pub fn target_client<S: Into<Cow<'a, str>>>(mut self, target_client: S) -> Self {
-        self.target_client = Some(target_client.into());
+    pub fn target_client<S: IntoTargetClient<'a>>(mut self, target_client: S) -> Self {
+        self.target_client = Some(target_client.into_target());
         self
     }
 
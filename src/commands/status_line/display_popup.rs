@@ -2,6 +2,10 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_3_2")]
+use crate::IntoTargetClient;
+#[cfg(feature = "tmux_3_2")]
+use crate::IntoTargetPane;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -168,8 +172,8 @@ impl<'a> DisplayPopup<'a> {
 
     /// `[-c target-client]`
     #[cfg(feature = "tmux_3_2")]
-    pub fn target_client<S: Into<Cow<'a, str>>>(mut self, target_client: S) -> Self {
-        self.target_client = Some(target_client.into());
+    pub fn target_client<S: IntoTargetClient<'a>>(mut self, target_client: S) -> Self {
+        self.target_client = Some(target_client.into_target());
         self
     }
 
@@ -210,8 +214,8 @@ impl<'a> DisplayPopup<'a> {
 
     /// `[-t target-pane]`
     #[cfg(feature = "tmux_3_2")]
-    pub fn target_pane<S: Into<Cow<'a, str>>>(mut self, target_pane: S) -> Self {
-        self.target_pane = Some(target_pane.into());
+    pub fn target_pane<S: IntoTargetPane<'a>>(mut self, target_pane: S) -> Self {
+        self.target_pane = Some(target_pane.into_target());
         self
     }
 
@@ -1,3 +1,4 @@
+use crate::Error;
 use std::fmt;
 use std::process::ExitStatus;
 use std::process::Output;
@@ -44,4 +45,14 @@ impl TmuxOutput {
     pub fn code(&self) -> Option<i32> {
         self.0.status.code()
     }
+
+    /// Output of a successful command, otherwise [`Error::Tmux`] with tmux error message
+    pub fn checked(self) -> Result<Self, Error> {
+        if self.success() {
+            Ok(self)
+        } else {
+            let stderr = String::from_utf8_lossy(&self.0.stderr);
+            Err(Error::Tmux(stderr.trim_end().to_string()))
+        }
+    }
 }
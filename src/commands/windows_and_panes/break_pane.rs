@@ -2,6 +2,17 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(any(
+    all(feature = "tmux_1_5", not(feature = "tmux_2_1")),
+    feature = "tmux_2_1",
+    all(feature = "tmux_2_1", not(feature = "tmux_2_1"))
+))]
+use crate::IntoTargetPane;
+#[cfg(any(
+    all(feature = "tmux_0_8", not(feature = "tmux_1_5")),
+    feature = "tmux_2_2"
+))]
+use crate::IntoTargetWindow;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -148,36 +159,36 @@ impl<'a> BreakPane<'a> {
 
     /// `[-t target-window]`
     #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
-    pub fn target_window<S: Into<Cow<'a, str>>>(mut self, target_window: S) -> Self {
-        self.target_window = Some(target_window.into());
+    pub fn target_window<S: IntoTargetWindow<'a>>(mut self, target_window: S) -> Self {
+        self.target_window = Some(target_window.into_target());
         self
     }
 
     /// `[-t target-pane]`
     #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
-    pub fn target_pane<S: Into<Cow<'a, str>>>(mut self, target_pane: S) -> Self {
-        self.target_pane = Some(target_pane.into());
+    pub fn target_pane<S: IntoTargetPane<'a>>(mut self, target_pane: S) -> Self {
+        self.target_pane = Some(target_pane.into_target());
         self
     }
 
     /// `[-s src-pane]`
     #[cfg(feature = "tmux_2_1")]
-    pub fn src_pane<S: Into<Cow<'a, str>>>(mut self, src_pane: S) -> Self {
-        self.src_pane = Some(src_pane.into());
+    pub fn src_pane<S: IntoTargetPane<'a>>(mut self, src_pane: S) -> Self {
+        self.src_pane = Some(src_pane.into_target());
         self
     }
 
     /// `[-t dst-pane]`
     #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_2_1")))]
-    pub fn dst_pane<S: Into<Cow<'a, str>>>(mut self, dst_pane: S) -> Self {
-        self.dst_pane = Some(dst_pane.into());
+    pub fn dst_pane<S: IntoTargetPane<'a>>(mut self, dst_pane: S) -> Self {
+        self.dst_pane = Some(dst_pane.into_target());
         self
     }
 
     /// `[-t dst-window]`
     #[cfg(feature = "tmux_2_2")]
-    pub fn dst_window<S: Into<Cow<'a, str>>>(mut self, dst_window: S) -> Self {
-        self.dst_window = Some(dst_window.into());
+    pub fn dst_window<S: IntoTargetWindow<'a>>(mut self, dst_window: S) -> Self {
+        self.dst_window = Some(dst_window.into_target());
         self
     }
 
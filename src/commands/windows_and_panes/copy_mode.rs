@@ -2,6 +2,10 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(any(feature = "tmux_3_2", feature = "tmux_1_5"))]
+use crate::IntoTargetPane;
+#[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
+use crate::IntoTargetWindow;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -137,22 +141,22 @@ impl<'a> CopyMode<'a> {
 
     /// `[-s src-pane]`
     #[cfg(feature = "tmux_3_2")]
-    pub fn src_pane<S: Into<Cow<'a, str>>>(mut self, src_pane: S) -> Self {
-        self.src_pane = Some(src_pane.into());
+    pub fn src_pane<S: IntoTargetPane<'a>>(mut self, src_pane: S) -> Self {
+        self.src_pane = Some(src_pane.into_target());
         self
     }
 
     /// `[-t target-window]`
     #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
-    pub fn target_window<S: Into<Cow<'a, str>>>(mut self, target_window: S) -> Self {
-        self.target_window = Some(target_window.into());
+    pub fn target_window<S: IntoTargetWindow<'a>>(mut self, target_window: S) -> Self {
+        self.target_window = Some(target_window.into_target());
         self
     }
 
     /// `[-t target-pane]`
     #[cfg(feature = "tmux_1_5")]
-    pub fn target_pane<S: Into<Cow<'a, str>>>(mut self, target_pane: S) -> Self {
-        self.target_pane = Some(target_pane.into());
+    pub fn target_pane<S: IntoTargetPane<'a>>(mut self, target_pane: S) -> Self {
+        self.target_pane = Some(target_pane.into_target());
         self
     }
 
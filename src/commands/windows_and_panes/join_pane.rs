@@ -2,6 +2,8 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_1_5")]
+use crate::IntoTargetPane;
 use crate::PaneSize;
 use crate::TmuxCommand;
 use std::borrow::Cow;
@@ -116,15 +118,15 @@ impl<'a> JoinPane<'a> {
 
     /// `[-s src-pane]`
     #[cfg(feature = "tmux_1_5")]
-    pub fn src_pane<S: Into<Cow<'a, str>>>(mut self, src_pane: S) -> Self {
-        self.src_pane = Some(src_pane.into());
+    pub fn src_pane<S: IntoTargetPane<'a>>(mut self, src_pane: S) -> Self {
+        self.src_pane = Some(src_pane.into_target());
         self
     }
 
     /// `[-t dst-pane]`
     #[cfg(feature = "tmux_1_5")]
-    pub fn dst_pane<S: Into<Cow<'a, str>>>(mut self, dst_pane: S) -> Self {
-        self.dst_pane = Some(dst_pane.into());
+    pub fn dst_pane<S: IntoTargetPane<'a>>(mut self, dst_pane: S) -> Self {
+        self.dst_pane = Some(dst_pane.into_target());
         self
     }
 
@@ -2,6 +2,8 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_0_8")]
+use crate::IntoTargetWindow;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -90,15 +92,15 @@ impl<'a> LinkWindow<'a> {
 
     /// `[-s src-window]`
     #[cfg(feature = "tmux_0_8")]
-    pub fn src_window<S: Into<Cow<'a, str>>>(mut self, src_window: S) -> Self {
-        self.src_window = Some(src_window.into());
+    pub fn src_window<S: IntoTargetWindow<'a>>(mut self, src_window: S) -> Self {
+        self.src_window = Some(src_window.into_target());
         self
     }
 
     /// `[-t dst-window]`
     #[cfg(feature = "tmux_0_8")]
-    pub fn dst_window<S: Into<Cow<'a, str>>>(mut self, dst_window: S) -> Self {
-        self.dst_window = Some(dst_window.into());
+    pub fn dst_window<S: IntoTargetWindow<'a>>(mut self, dst_window: S) -> Self {
+        self.dst_window = Some(dst_window.into_target());
         self
     }
 
@@ -2,6 +2,8 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_1_7")]
+use crate::IntoTargetPane;
 use crate::PaneSize;
 use crate::TmuxCommand;
 use std::borrow::Cow;
@@ -124,15 +126,15 @@ impl<'a> MovePane<'a> {
 
     /// `[-s src-pane]`
     #[cfg(feature = "tmux_1_7")]
-    pub fn src_pane<S: Into<Cow<'a, str>>>(mut self, src_pane: S) -> Self {
-        self.src_pane = Some(src_pane.into());
+    pub fn src_pane<S: IntoTargetPane<'a>>(mut self, src_pane: S) -> Self {
+        self.src_pane = Some(src_pane.into_target());
         self
     }
 
     /// `[-t dst-pane]`
     #[cfg(feature = "tmux_1_7")]
-    pub fn dst_pane<S: Into<Cow<'a, str>>>(mut self, dst_pane: S) -> Self {
-        self.dst_pane = Some(dst_pane.into());
+    pub fn dst_pane<S: IntoTargetPane<'a>>(mut self, dst_pane: S) -> Self {
+        self.dst_pane = Some(dst_pane.into_target());
         self
     }
 
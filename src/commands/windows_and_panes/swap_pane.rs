@@ -2,6 +2,10 @@
 //
 
 use crate::commands::constants::*;
+#[cfg(feature = "tmux_1_5")]
+use crate::IntoTargetPane;
+#[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
+use crate::IntoTargetWindow;
 use crate::TmuxCommand;
 use std::borrow::Cow;
 
@@ -109,8 +113,8 @@ impl<'a> SwapPane<'a> {
 
     /// `[-s src-pane]`
     #[cfg(feature = "tmux_1_5")]
-    pub fn src_pane<S: Into<Cow<'a, str>>>(mut self, src_pane: S) -> Self {
-        self.src_pane = Some(src_pane.into());
+    pub fn src_pane<S: IntoTargetPane<'a>>(mut self, src_pane: S) -> Self {
+        self.src_pane = Some(src_pane.into_target());
         self
     }
 
@@ -123,15 +127,15 @@ impl<'a> SwapPane<'a> {
 
     /// `[-t target-window]`
     #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_5")))]
-    pub fn target_window<S: Into<Cow<'a, str>>>(mut self, target_window: S) -> Self {
-        self.target_window = Some(target_window.into());
+    pub fn target_window<S: IntoTargetWindow<'a>>(mut self, target_window: S) -> Self {
+        self.target_window = Some(target_window.into_target());
         self
     }
 
     /// `[-t dst-pane]`
     #[cfg(feature = "tmux_1_5")]
-    pub fn dst_pane<S: Into<Cow<'a, str>>>(mut self, dst_pane: S) -> Self {
-        self.dst_pane = Some(dst_pane.into());
+    pub fn dst_pane<S: IntoTargetPane<'a>>(mut self, dst_pane: S) -> Self {
+        self.dst_pane = Some(dst_pane.into_target());
         self
     }
 
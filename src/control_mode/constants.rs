@@ -89,3 +89,8 @@ pub const NOTIFICATION_WINDOW_RENAMED: &str = "%window-renamed";
 pub const CONTROL_MODE_SEPARATOR: char = ' ';
 /// additional separator used in extended-output notification
 pub const CONTROL_MODE_EXTENDED_OUTPUT_SEPARATOR: &str = " : ";
+
+/// DCS sequence start, control mode output is wrapped in it, if tmux was started with `-CC`
+pub const CONTROL_MODE_DCS_BEGIN: &str = "\x1bP1000p";
+/// string terminator, end of DCS sequence (`-CC`)
+pub const CONTROL_MODE_DCS_END: &str = "\x1b\\";
@@ -1,6 +1,11 @@
 use crate::control_mode::constants::*;
 use crate::Error;
 use crate::TmuxCommand;
+#[cfg(feature = "tmux_2_2")]
+use crate::WindowFlags;
+use crate::{ClientName, Layout, PaneId, SessionId, WindowId};
+use std::fmt;
+use std::io;
 use std::io::BufRead;
 use std::io::Lines;
 use std::io::Write;
@@ -165,6 +170,139 @@ pub enum Response {
     WindowRenamed { window_id: String, name: String },
 }
 
+// NOTE: fields are kept as received raw strings (forward compatibility with new tmux versions),
+// parsed values are returned by methods
+impl Response {
+    /// Parsed session id (`$id`) of the notification, if it contains one
+    pub fn session_id(&self) -> Option<SessionId> {
+        let session_id = match self {
+            #[cfg(feature = "tmux_2_4")]
+            Self::ClientSessionChanged { session_id, .. } => session_id,
+            #[cfg(feature = "tmux_1_8")]
+            Self::SessionChanged { session_id, .. } => session_id,
+            // `%session-renamed session-id name`
+            #[cfg(feature = "tmux_1_8")]
+            Self::SessionRenamed(s) => s.split(CONTROL_MODE_SEPARATOR).next()?,
+            #[cfg(feature = "tmux_2_5")]
+            Self::SessionWindowChanged { session_id, .. } => session_id,
+            #[cfg(feature = "tmux_3_2")]
+            Self::SubscriptionChanged { session_id, .. } => session_id,
+            _ => return None,
+        };
+        session_id.parse().ok()
+    }
+
+    /// Parsed window id (`@id`) of the notification, if it contains one
+    pub fn window_id(&self) -> Option<WindowId> {
+        let window_id = match self {
+            #[cfg(feature = "tmux_1_8")]
+            Self::LayoutChange { window_id, .. } => window_id,
+            #[cfg(feature = "tmux_2_5")]
+            Self::SessionWindowChanged { window_id, .. } => window_id,
+            #[cfg(feature = "tmux_3_2")]
+            Self::SubscriptionChanged { window_id, .. } => window_id,
+            #[cfg(feature = "tmux_1_8")]
+            Self::UnlinkedWindowAdd(window_id) => window_id,
+            #[cfg(feature = "tmux_3_3")]
+            Self::UnlinkedWindowClose(window_id) => window_id,
+            #[cfg(feature = "tmux_3_3")]
+            Self::UnlinkedWindowRenamed(window_id) => window_id,
+            #[cfg(feature = "tmux_1_8")]
+            Self::WindowAdd(window_id) => window_id,
+            #[cfg(feature = "tmux_1_8")]
+            Self::WindowClose(window_id) => window_id,
+            #[cfg(feature = "tmux_2_5")]
+            Self::WindowPaneChanged { window_id, .. } => window_id,
+            #[cfg(feature = "tmux_1_8")]
+            Self::WindowRenamed { window_id, .. } => window_id,
+            _ => return None,
+        };
+        window_id.parse().ok()
+    }
+
+    /// Parsed pane id (`%id`) of the notification, if it contains one
+    pub fn pane_id(&self) -> Option<PaneId> {
+        let pane_id = match self {
+            #[cfg(feature = "tmux_3_2")]
+            Self::Continue(pane_id) => pane_id,
+            #[cfg(feature = "tmux_3_2")]
+            Self::ExtendedOutput { pane_id, .. } => pane_id,
+            #[cfg(feature = "tmux_1_8")]
+            Self::Output { pane_id, .. } => pane_id,
+            #[cfg(feature = "tmux_2_5")]
+            Self::PaneModeChanged(pane_id) => pane_id,
+            #[cfg(feature = "tmux_3_2")]
+            Self::Pause(pane_id) => pane_id,
+            #[cfg(feature = "tmux_2_5")]
+            Self::WindowPaneChanged { pane_id, .. } => pane_id,
+            _ => return None,
+        };
+        pane_id.parse().ok()
+    }
+
+    /// Client name of the notification, if it contains one
+    pub fn client(&self) -> Option<ClientName> {
+        match self {
+            #[cfg(feature = "tmux_3_2")]
+            Self::ClientDetached(client) => Some(ClientName::from(client.as_str())),
+            #[cfg(feature = "tmux_2_4")]
+            Self::ClientSessionChanged { client, .. } => Some(ClientName::from(client.as_str())),
+            _ => None,
+        }
+    }
+
+    /// Session or window name of the notification, if it contains one
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            #[cfg(feature = "tmux_2_4")]
+            Self::ClientSessionChanged { name, .. } => Some(name),
+            #[cfg(feature = "tmux_1_8")]
+            Self::SessionChanged { name, .. } => Some(name),
+            // `%session-renamed session-id name`
+            #[cfg(feature = "tmux_1_8")]
+            Self::SessionRenamed(s) => match s.split_once(CONTROL_MODE_SEPARATOR) {
+                Some((session_id, name)) if session_id.parse::<SessionId>().is_ok() => Some(name),
+                _ => Some(s),
+            },
+            #[cfg(feature = "tmux_3_2")]
+            Self::SubscriptionChanged { name, .. } => Some(name),
+            #[cfg(feature = "tmux_1_8")]
+            Self::WindowRenamed { name, .. } => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Parsed `window-layout` of `%layout-change` notification
+    pub fn window_layout(&self) -> Option<Layout> {
+        match self {
+            #[cfg(feature = "tmux_1_8")]
+            Self::LayoutChange { window_layout, .. } => window_layout.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Parsed `window-visible-layout` of `%layout-change` notification
+    #[cfg(feature = "tmux_2_2")]
+    pub fn window_visible_layout(&self) -> Option<Layout> {
+        match self {
+            Self::LayoutChange {
+                window_visible_layout,
+                ..
+            } => window_visible_layout.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Parsed `window-flags` of `%layout-change` notification
+    #[cfg(feature = "tmux_2_2")]
+    pub fn window_flags(&self) -> Option<WindowFlags> {
+        match self {
+            Self::LayoutChange { window_flags, .. } => window_flags.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
 // wrapper structure around Lines type, which is Iterator
 //  which implements BufRead trait <B: BufRead>
 #[derive(Debug)]
@@ -224,58 +362,79 @@ impl<B: BufRead> ControlModeOutput<B> {
         }
     }
 
-    // TODO: remove unwrap
     // TODO: rename
     pub fn check_main(lines: &mut Lines<B>) -> Option<Response> {
-        let mut _time: usize = 0;
-        let mut _num: usize = 0;
-        let mut _flags: usize = 0;
-        let mut output_block = OutputBlock::default();
-
-        // checking in loop, because 3 parts block may be returned, which must be merged
-        // (`%begin ...  data .. %end/%error`)
-        for line in lines {
-            let output = line.unwrap().control_mode_line();
-            if let Ok(output) = output {
-                // check if output is part of output block?
-                match output {
-                    // if output block detected combine it from parts (`%begin ... data ... %end/%error`)
-                    // continue loop waiting for data and end/error
-                    Response::OutputBlockBegin { time, num, flags } => {
-                        _time = time;
-                        _num = num;
-                        _flags = flags;
-                    }
-                    // end of output block (ended with success), break loop, got whole block
-                    Response::OutputBlockEnd { time, num, flags } => {
-                        // XXX: check t, n
-                        output_block.time = time;
-                        output_block.num = num;
-                        output_block.flags = flags;
-                        output_block.success = true;
-                        return Some(Response::OutputBlock(output_block));
-                    }
-                    // end of output block (ended with an error), break loop, got whole block
-                    Response::OutputBlockError { time, num, flags } => {
-                        // XXX: check t, n
-                        output_block.time = time;
-                        output_block.num = num;
-                        output_block.flags = flags;
-                        output_block.success = false;
-                        return Some(Response::OutputBlock(output_block));
-                    }
-                    // data inside of output block
-                    Response::OutputBlockData(data) => output_block.data = Some(data),
-                    // TODO: only as single line? output check, \n ?
-                    // notification, break loop, got whole data
-                    other => return Some(other),
-                }
-            } else {
-                return None;
+        next_response(lines)?.ok()
+    }
+}
+
+/// Get next notification or whole output block from the lines, errors of reading or parsing a
+/// line are returned instead of ending the stream (the line is skipped)
+pub fn next_response<I>(lines: &mut I) -> Option<Result<Response, Error>>
+where
+    I: Iterator<Item = io::Result<String>>,
+{
+    let mut _time: usize = 0;
+    let mut _num: usize = 0;
+    let mut _flags: usize = 0;
+    let mut output_block = OutputBlock::default();
+
+    // checking in loop, because 3 parts block may be returned, which must be merged
+    // (`%begin ...  data .. %end/%error`)
+    for line in lines {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => return Some(Err(e.into())),
+        };
+        // DCS sequence end (`-CC`), only string terminator in line, nothing to parse
+        if strip_dcs(&line).is_empty() && line.contains(CONTROL_MODE_DCS_END) {
+            continue;
+        }
+        let output = match line.control_mode_line() {
+            Ok(output) => output,
+            Err(e) => return Some(Err(e)),
+        };
+        // check if output is part of output block?
+        match output {
+            // if output block detected combine it from parts (`%begin ... data ... %end/%error`)
+            // continue loop waiting for data and end/error
+            Response::OutputBlockBegin { time, num, flags } => {
+                _time = time;
+                _num = num;
+                _flags = flags;
+            }
+            // end of output block (ended with success), break loop, got whole block
+            Response::OutputBlockEnd { time, num, flags } => {
+                // XXX: check t, n
+                output_block.time = time;
+                output_block.num = num;
+                output_block.flags = flags;
+                output_block.success = true;
+                return Some(Ok(Response::OutputBlock(output_block)));
+            }
+            // end of output block (ended with an error), break loop, got whole block
+            Response::OutputBlockError { time, num, flags } => {
+                // XXX: check t, n
+                output_block.time = time;
+                output_block.num = num;
+                output_block.flags = flags;
+                output_block.success = false;
+                return Some(Ok(Response::OutputBlock(output_block)));
             }
+            // data inside of output block, may consist of multiple lines
+            Response::OutputBlockData(data) => match output_block.data.as_mut() {
+                Some(block_data) => {
+                    block_data.push('\n');
+                    block_data.push_str(&data);
+                }
+                None => output_block.data = Some(data),
+            },
+            // TODO: only as single line? output check, \n ?
+            // notification, break loop, got whole data
+            other => return Some(Ok(other)),
         }
-        None
     }
+    None
 }
 
 // https://dev.to/dandyvica/yarit-yet-another-rust-iterators-tutorial-46dk
@@ -301,6 +460,15 @@ pub trait ControlModeLine {
     fn control_mode_line(&self) -> Result<Response, Error>;
 }
 
+/// Remove DCS sequence framing (`\033P1000p ... \033\\`), used by tmux started with `-CC`, from
+/// the line
+pub fn strip_dcs(s: &str) -> &str {
+    let s = s.strip_prefix(CONTROL_MODE_DCS_BEGIN).unwrap_or(s);
+    s.strip_suffix(CONTROL_MODE_DCS_END)
+        .or_else(|| s.strip_prefix(CONTROL_MODE_DCS_END))
+        .unwrap_or(s)
+}
+
 fn parse_option_string(s: &str) -> Option<String> {
     if !s.is_empty() {
         Some(s.to_string())
@@ -321,7 +489,9 @@ impl<S: AsRef<str> + std::fmt::Display> ControlModeLine for S {
     // TODO: Result/Option parsing errors?
     // mb. option for fields too, if parse errors occur?
     fn control_mode_line(&self) -> Result<Response, Error> {
-        match self.as_ref() {
+        // lines wrapped in DCS sequence (`-CC`)
+        let line = strip_dcs(self.as_ref());
+        match line {
             // start of output block
             // %begin seconds-from-epoch command-number flags
             #[cfg(feature = "tmux_1_8")]
@@ -612,7 +782,7 @@ impl<S: AsRef<str> + std::fmt::Display> ControlModeLine for S {
 
             // `...` - data inside `%begin ... %end`
             #[cfg(feature = "tmux_1_8")]
-            _ => Ok(Response::OutputBlockData(self.to_string())),
+            _ => Ok(Response::OutputBlockData(line.to_string())),
         }
     }
 }
@@ -622,3 +792,286 @@ impl<S: AsRef<str> + std::fmt::Display> ControlModeLine for S {
 //ControlModeOutput::new(item)
 //}
 //}
+
+// XXX: octal escaping of `%output` value is kept as is (same as received from tmux), use
+// `escape_output()` and `unescape_output()` for conversion from/to raw bytes
+/// Escape pane output data the same way tmux does it in control mode (characters less than ASCII
+/// 32 and `\` are replaced with their octal `\xxx` representation)
+pub fn escape_output(data: &[u8]) -> String {
+    let mut escaped = Vec::with_capacity(data.len());
+    for &b in data {
+        if b < b' ' || b == b'\\' {
+            escaped.extend_from_slice(format!("\\{:03o}", b).as_bytes());
+        } else {
+            escaped.push(b);
+        }
+    }
+    String::from_utf8_lossy(&escaped).into_owned()
+}
+
+/// Unescape pane output data received in control mode (`\xxx` octal sequences are replaced by
+/// bytes)
+pub fn unescape_output(s: &str) -> Vec<u8> {
+    let bytes = s.as_bytes();
+    let mut data = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let octal = bytes
+            .get(i + 1..i + 4)
+            .filter(|b| b.iter().all(|c| (b'0'..=b'7').contains(c)));
+        match (bytes[i], octal) {
+            (b'\\', Some(octal)) => {
+                data.push(
+                    octal
+                        .iter()
+                        .fold(0u8, |acc, c| acc.wrapping_mul(8).wrapping_add(c - b'0')),
+                );
+                i += 4;
+            }
+            (b, _) => {
+                data.push(b);
+                i += 1;
+            }
+        }
+    }
+    data
+}
+
+// escape control characters only, backslashes are already escape sequences in the stored value
+fn escape_control(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c < ' ' {
+            escaped.push_str(&format!("\\{:03o}", c as u8));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+/// Output block as multiple lines (`%begin ... data ... %end/%error`)
+impl fmt::Display for OutputBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} {} {} {}",
+            OUTPUT_BLOCK_BEGIN, self.time, self.num, self.flags
+        )?;
+        if let Some(data) = &self.data {
+            writeln!(f, "{}", data)?;
+        }
+        let end = if self.success {
+            OUTPUT_BLOCK_END
+        } else {
+            OUTPUT_BLOCK_ERROR
+        };
+        write!(f, "{} {} {} {}", end, self.time, self.num, self.flags)
+    }
+}
+
+/// Response as control mode protocol line(s), without trailing newline
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = CONTROL_MODE_SEPARATOR;
+        match self {
+            // %begin seconds-from-epoch command-number flags
+            #[cfg(feature = "tmux_1_8")]
+            Self::OutputBlockBegin { time, num, flags } => {
+                write!(f, "{}{s}{}{s}{}{s}{}", OUTPUT_BLOCK_BEGIN, time, num, flags)
+            }
+            // %end seconds-from-epoch command-number flags
+            #[cfg(feature = "tmux_1_8")]
+            Self::OutputBlockEnd { time, num, flags } => {
+                write!(f, "{}{s}{}{s}{}{s}{}", OUTPUT_BLOCK_END, time, num, flags)
+            }
+            // %error seconds-from-epoch command-number flags
+            #[cfg(feature = "tmux_1_8")]
+            Self::OutputBlockError { time, num, flags } => {
+                write!(f, "{}{s}{}{s}{}{s}{}", OUTPUT_BLOCK_ERROR, time, num, flags)
+            }
+            // `...data...`
+            #[cfg(feature = "tmux_1_8")]
+            Self::OutputBlockData(data) => write!(f, "{}", data),
+            // `%begin ... data ... %end/%error`
+            #[cfg(feature = "tmux_1_8")]
+            Self::OutputBlock(output_block) => write!(f, "{}", output_block),
+            // `%client-detached client`
+            #[cfg(feature = "tmux_3_2")]
+            Self::ClientDetached(client) => {
+                write!(f, "{}{s}{}", NOTIFICATION_CLIENT_DETACHED, client)
+            }
+            // `%client-session-changed client session-id name`
+            #[cfg(feature = "tmux_2_4")]
+            Self::ClientSessionChanged {
+                client,
+                session_id,
+                name,
+            } => write!(
+                f,
+                "{}{s}{}{s}{}{s}{}",
+                NOTIFICATION_CLIENT_SESSION_CHANGED, client, session_id, name
+            ),
+            // `%config-error error`
+            #[cfg(feature = "tmux_3_4")]
+            Self::ConfigError(error) => write!(f, "{}{s}{}", NOTIFICATION_CONFIG_ERROR, error),
+            // `%continue pane-id`
+            #[cfg(feature = "tmux_3_2")]
+            Self::Continue(pane_id) => write!(f, "{}{s}{}", NOTIFICATION_CONTINUE, pane_id),
+            // `%exit [reason]`
+            #[cfg(feature = "tmux_1_8")]
+            Self::Exit(reason) => match reason {
+                Some(reason) => write!(f, "{}{s}{}", NOTIFICATION_EXIT, reason),
+                None => write!(f, "{}", NOTIFICATION_EXIT),
+            },
+            // `%extended-output pane-id age ... : value`
+            #[cfg(feature = "tmux_3_2")]
+            Self::ExtendedOutput {
+                pane_id,
+                age,
+                reserved,
+                value,
+            } => {
+                write!(
+                    f,
+                    "{}{s}{}{s}{}",
+                    NOTIFICATION_EXTENDED_OUTPUT, pane_id, age
+                )?;
+                for r in reserved {
+                    write!(f, "{s}{}", r)?;
+                }
+                write!(
+                    f,
+                    "{}{}",
+                    CONTROL_MODE_EXTENDED_OUTPUT_SEPARATOR,
+                    escape_control(value)
+                )
+            }
+            // `%layout-change window-id window-layout window-visible-layout window-flags`
+            // `%layout-change window-id window-layout`
+            #[cfg(feature = "tmux_1_8")]
+            Self::LayoutChange {
+                window_id,
+                window_layout,
+                #[cfg(feature = "tmux_2_2")]
+                window_visible_layout,
+                #[cfg(feature = "tmux_2_2")]
+                window_flags,
+            } => {
+                write!(
+                    f,
+                    "{}{s}{}{s}{}",
+                    NOTIFICATION_LAYOUT_CHANGE, window_id, window_layout
+                )?;
+                #[cfg(feature = "tmux_2_2")]
+                write!(f, "{s}{}{s}{}", window_visible_layout, window_flags)?;
+                Ok(())
+            }
+            // `%output pane-id value`
+            #[cfg(feature = "tmux_1_8")]
+            Self::Output { pane_id, value } => write!(
+                f,
+                "{}{s}{}{s}{}",
+                NOTIFICATION_OUTPUT,
+                pane_id,
+                escape_control(value)
+            ),
+            // `%pane-mode-changed pane-id`
+            #[cfg(feature = "tmux_2_5")]
+            Self::PaneModeChanged(pane_id) => {
+                write!(f, "{}{s}{}", NOTIFICATION_PANE_MODE_CHANGED, pane_id)
+            }
+            // `%paste-buffer-changed name`
+            #[cfg(feature = "tmux_3_4")]
+            Self::PasteBufferChanged(name) => {
+                write!(f, "{}{s}{}", NOTIFICATION_PASTE_BUFFER_CHANGED, name)
+            }
+            // `%paste-buffer-deleted name`
+            #[cfg(feature = "tmux_3_4")]
+            Self::PasteBufferDeleted(name) => {
+                write!(f, "{}{s}{}", NOTIFICATION_PASTE_BUFFER_DELETED, name)
+            }
+            // `%pause pane-id`
+            #[cfg(feature = "tmux_3_2")]
+            Self::Pause(pane_id) => write!(f, "{}{s}{}", NOTIFICATION_PAUSE, pane_id),
+            // `%session-changed session-id name`
+            #[cfg(feature = "tmux_1_8")]
+            Self::SessionChanged { session_id, name } => write!(
+                f,
+                "{}{s}{}{s}{}",
+                NOTIFICATION_SESSION_CHANGED, session_id, name
+            ),
+            // `%session-renamed name`
+            #[cfg(feature = "tmux_1_8")]
+            Self::SessionRenamed(name) => {
+                write!(f, "{}{s}{}", NOTIFICATION_SESSION_RENAMED, name)
+            }
+            // `%session-window-changed session-id window-id`
+            #[cfg(feature = "tmux_2_5")]
+            Self::SessionWindowChanged {
+                session_id,
+                window_id,
+            } => write!(
+                f,
+                "{}{s}{}{s}{}",
+                NOTIFICATION_SESSION_WINDOW_CHANGED, session_id, window_id
+            ),
+            // `%sessions-changed`
+            #[cfg(feature = "tmux_1_8")]
+            Self::SessionsChanged => write!(f, "{}", NOTIFICATION_SESSIONS_CHANGED),
+            // `%subscription-changed name session-id window-id window-index`
+            #[cfg(feature = "tmux_3_2")]
+            Self::SubscriptionChanged {
+                name,
+                session_id,
+                window_id,
+                window_index,
+            } => write!(
+                f,
+                "{}{s}{}{s}{}{s}{}{s}{}",
+                NOTIFICATION_SUBSCRIPTION_CHANGED, name, session_id, window_id, window_index
+            ),
+            // `%unlinked-window-add window-id`
+            #[cfg(feature = "tmux_1_8")]
+            Self::UnlinkedWindowAdd(window_id) => {
+                write!(f, "{}{s}{}", NOTIFICATION_UNLINKED_WINDOW_ADD, window_id)
+            }
+            // `%unlinked-window-close window-id`
+            #[cfg(feature = "tmux_3_3")]
+            Self::UnlinkedWindowClose(window_id) => {
+                write!(f, "{}{s}{}", NOTIFICATION_UNLINKED_WINDOW_CLOSE, window_id)
+            }
+            // `%unlinked-window-renamed window-id`
+            #[cfg(feature = "tmux_3_3")]
+            Self::UnlinkedWindowRenamed(window_id) => {
+                write!(
+                    f,
+                    "{}{s}{}",
+                    NOTIFICATION_UNLINKED_WINDOW_RENAMED, window_id
+                )
+            }
+            // `%window-add window-id`
+            #[cfg(feature = "tmux_1_8")]
+            Self::WindowAdd(window_id) => write!(f, "{}{s}{}", NOTIFICATION_WINDOW_ADD, window_id),
+            // `%window-close window-id`
+            #[cfg(feature = "tmux_1_8")]
+            Self::WindowClose(window_id) => {
+                write!(f, "{}{s}{}", NOTIFICATION_WINDOW_CLOSE, window_id)
+            }
+            // `%window-pane-changed window-id pane-id`
+            #[cfg(feature = "tmux_2_5")]
+            Self::WindowPaneChanged { window_id, pane_id } => write!(
+                f,
+                "{}{s}{}{s}{}",
+                NOTIFICATION_WINDOW_PANE_CHANGED, window_id, pane_id
+            ),
+            // `%window-renamed window-id name`
+            #[cfg(feature = "tmux_1_8")]
+            Self::WindowRenamed { window_id, name } => write!(
+                f,
+                "{}{s}{}{s}{}",
+                NOTIFICATION_WINDOW_RENAMED, window_id, name
+            ),
+        }
+    }
+}
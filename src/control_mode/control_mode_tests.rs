@@ -319,3 +319,189 @@ fn for_loop() {
 //// send commands if needed using like socket
 //// listen like socket for notificeations
 //}
+
+// build control mode text from `control_mode::Response` structure tests
+#[test]
+fn control_mode_line_display() {
+    use crate::control_mode::control_mode::ControlModeLine;
+
+    let lines = [
+        #[cfg(feature = "tmux_1_8")]
+        "%begin 1618054308 777 1",
+        #[cfg(feature = "tmux_1_8")]
+        "%end 1618054308 777 1",
+        #[cfg(feature = "tmux_1_8")]
+        "%error 1618054308 777 1",
+        #[cfg(feature = "tmux_3_2")]
+        "%client-detached client0",
+        #[cfg(feature = "tmux_2_4")]
+        "%client-session-changed client0 $1 session name",
+        #[cfg(feature = "tmux_3_4")]
+        "%config-error error text",
+        #[cfg(feature = "tmux_3_2")]
+        "%continue %1",
+        #[cfg(feature = "tmux_1_8")]
+        "%exit",
+        #[cfg(feature = "tmux_1_8")]
+        "%exit detached",
+        #[cfg(feature = "tmux_3_2")]
+        "%extended-output %1 2 3 4 : value \\033[0m",
+        #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_2_2")))]
+        "%layout-change @1 bb62,159x48,0,0,1",
+        #[cfg(feature = "tmux_2_2")]
+        "%layout-change @1 bb62,159x48,0,0,1 bb62,159x48,0,0,1 *",
+        #[cfg(feature = "tmux_1_8")]
+        "%output %2 \\015\\012test \\134 result\\015\\012",
+        #[cfg(feature = "tmux_2_5")]
+        "%pane-mode-changed %1",
+        #[cfg(feature = "tmux_3_4")]
+        "%paste-buffer-changed buffer0",
+        #[cfg(feature = "tmux_3_4")]
+        "%paste-buffer-deleted buffer0",
+        #[cfg(feature = "tmux_3_2")]
+        "%pause %1",
+        #[cfg(feature = "tmux_1_8")]
+        "%session-changed $1 session name",
+        #[cfg(feature = "tmux_1_8")]
+        "%session-renamed $1 session name",
+        #[cfg(feature = "tmux_2_5")]
+        "%session-window-changed $1 @2",
+        #[cfg(feature = "tmux_1_8")]
+        "%sessions-changed",
+        #[cfg(feature = "tmux_3_2")]
+        "%subscription-changed sub $1 @2 3",
+        #[cfg(feature = "tmux_1_8")]
+        "%unlinked-window-add @1",
+        #[cfg(feature = "tmux_3_3")]
+        "%unlinked-window-close @1",
+        #[cfg(feature = "tmux_3_3")]
+        "%unlinked-window-renamed @1",
+        #[cfg(feature = "tmux_1_8")]
+        "%window-add @1",
+        #[cfg(feature = "tmux_1_8")]
+        "%window-close @1",
+        #[cfg(feature = "tmux_2_5")]
+        "%window-pane-changed @1 %2",
+        #[cfg(feature = "tmux_1_8")]
+        "%window-renamed @1 window name",
+        #[cfg(feature = "tmux_1_8")]
+        "data line",
+    ];
+
+    for line in lines.iter() {
+        let response = line.control_mode_line().unwrap();
+        assert_eq!(line.to_string(), response.to_string());
+        assert_eq!(response, response.to_string().control_mode_line().unwrap());
+    }
+}
+
+// merge multiple lines into output block and build them back
+#[test]
+fn control_mode_output_block() {
+    use crate::control_mode::control_mode::{ControlModeOutput, OutputBlock, Response};
+    use std::io::BufRead;
+
+    let text = "%begin 1618054308 777 1\nline 1\nline 2\n%end 1618054308 777 1";
+    let mut output = ControlModeOutput::new(text.as_bytes().lines());
+    let output_block = OutputBlock {
+        time: 1618054308,
+        num: 777,
+        flags: 1,
+        success: true,
+        data: Some("line 1\nline 2".to_string()),
+    };
+    let response = output.next().unwrap();
+    assert_eq!(Response::OutputBlock(output_block), response);
+    assert_eq!(text, response.to_string());
+}
+
+// control mode octal escaping of pane output
+#[test]
+fn control_mode_escape_output() {
+    use crate::control_mode::control_mode::{escape_output, unescape_output};
+
+    let data = b"\x1b[0m\\ test\r\n";
+    let escaped = "\\033[0m\\134 test\\015\\012";
+    assert_eq!(escaped, escape_output(data));
+    assert_eq!(data.to_vec(), unescape_output(escaped));
+    assert_eq!(b"\\1".to_vec(), unescape_output("\\1"));
+}
+
+// control mode output wrapped in DCS sequence (`tmux -CC`)
+#[test]
+fn control_mode_dcs() {
+    use crate::control_mode::control_mode::{
+        strip_dcs, ControlModeLine, ControlModeOutput, OutputBlock, Response,
+    };
+    use std::io::BufRead;
+
+    assert_eq!("%exit", strip_dcs("\x1bP1000p%exit"));
+    assert_eq!("%exit", strip_dcs("%exit\x1b\\"));
+    assert_eq!("", strip_dcs("\x1b\\"));
+    assert_eq!("%exit", strip_dcs("%exit"));
+    assert_eq!(
+        Response::WindowAdd("@1".to_string()),
+        "\x1bP1000p%window-add @1".control_mode_line().unwrap()
+    );
+
+    let text = "\x1bP1000p%begin 1618054308 777 1\n%end 1618054308 777 1\n%exit\n\x1b\\";
+    let output: Vec<Response> = ControlModeOutput::new(text.as_bytes().lines()).collect();
+    assert_eq!(
+        vec![
+            Response::OutputBlock(OutputBlock {
+                time: 1618054308,
+                num: 777,
+                flags: 1,
+                success: true,
+                data: None,
+            }),
+            Response::Exit(None)
+        ],
+        output
+    );
+}
+
+// typed ids, layouts and flags of notifications
+#[test]
+fn control_mode_typed() {
+    use crate::control_mode::control_mode::ControlModeLine;
+    use crate::{Layout, PaneId, SessionId, WindowId};
+
+    let response = "%window-renamed @1 window name"
+        .control_mode_line()
+        .unwrap();
+    assert_eq!(Some(WindowId(1)), response.window_id());
+    assert_eq!(None, response.session_id());
+    assert_eq!(None, response.pane_id());
+    assert_eq!(Some("window name"), response.name());
+
+    let response = "%session-renamed $2 session name"
+        .control_mode_line()
+        .unwrap();
+    assert_eq!(Some(SessionId(2)), response.session_id());
+    assert_eq!(Some("session name"), response.name());
+
+    let response = "%output %3 value".control_mode_line().unwrap();
+    assert_eq!(Some(PaneId(3)), response.pane_id());
+
+    // unparseable ids
+    let response = "%window-add 1".control_mode_line().unwrap();
+    assert_eq!(None, response.window_id());
+
+    #[cfg(feature = "tmux_2_2")]
+    {
+        use crate::WindowFlags;
+
+        let layout = "bb62,159x48,0,0{79x48,0,0,3,79x48,80,0,4}";
+        let response = format!("%layout-change @1 {} {} *Z", layout, layout)
+            .control_mode_line()
+            .unwrap();
+        assert_eq!(Some(WindowId(1)), response.window_id());
+        assert_eq!(layout.parse::<Layout>().ok(), response.window_layout());
+        assert_eq!(
+            layout.parse::<Layout>().ok(),
+            response.window_visible_layout()
+        );
+        assert_eq!("*Z".parse::<WindowFlags>().ok(), response.window_flags());
+    }
+}
@@ -0,0 +1,199 @@
+use crate::control_mode::constants::*;
+use crate::control_mode::control_mode::{next_response, strip_dcs, ControlModeOutput, Response};
+use crate::Error;
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+// Transcript - saved stdout of tmux running in control mode (`tmux -C ... > transcript.txt`)
+//
+// * `ControlModeTranscript` - read transcript, get responses with time
+// * `ControlModeReplay` - stream transcript data with original (or accelerated) timing, can be used
+// as input of `ControlModeOutput` instead of tmux stdout
+
+/// Response read from a transcript with time of the last output block (`%begin` seconds from
+/// epoch), notifications don't have own timestamps
+#[derive(Debug, PartialEq)]
+pub struct TranscriptEntry {
+    pub time: Option<usize>,
+    pub response: Response,
+}
+
+/// Reader of saved control mode transcripts
+///
+/// Invalid UTF-8 (`%output` passes bytes above 0x7f through) is replaced, lines which can't be
+/// parsed are returned as errors and skipped
+#[derive(Debug)]
+pub struct ControlModeTranscript<B: BufRead> {
+    lines: LossyLines<B>,
+    time: Option<usize>,
+}
+
+impl ControlModeTranscript<BufReader<File>> {
+    /// Open transcript file
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Ok(Self::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<B: BufRead> ControlModeTranscript<B> {
+    pub fn new(reader: B) -> Self {
+        ControlModeTranscript {
+            lines: LossyLines {
+                reader: Some(reader),
+            },
+            time: None,
+        }
+    }
+}
+
+impl<B: BufRead> Iterator for ControlModeTranscript<B> {
+    type Item = Result<TranscriptEntry, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let response = match next_response(&mut self.lines)? {
+            Ok(response) => response,
+            Err(e) => return Some(Err(e)),
+        };
+        if let Response::OutputBlock(output_block) = &response {
+            self.time = Some(output_block.time);
+        }
+        Some(Ok(TranscriptEntry {
+            time: self.time,
+            response,
+        }))
+    }
+}
+
+// lines with invalid UTF-8 replaced, ends after the first read error
+#[derive(Debug)]
+struct LossyLines<B: BufRead> {
+    reader: Option<B>,
+}
+
+impl<B: BufRead> Iterator for LossyLines<B> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = Vec::new();
+        match self.reader.as_mut()?.read_until(b'\n', &mut line) {
+            Ok(0) => None,
+            Ok(_) => {
+                if line.ends_with(b"\n") {
+                    line.pop();
+                    if line.ends_with(b"\r") {
+                        line.pop();
+                    }
+                }
+                Some(Ok(String::from_utf8_lossy(&line).into_owned()))
+            }
+            Err(e) => {
+                self.reader = None;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Replay of saved control mode transcripts
+///
+/// Transcript data is returned line by line (invalid UTF-8 replaced), delays between output
+/// blocks are taken from the `%begin` timestamps and divided by the speed factor
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::control_mode::control_mode_transcript::ControlModeReplay;
+///
+/// let transcript = "%begin 1 1 0\n%end 1 1 0\n%window-add @1\n";
+/// let output = ControlModeReplay::new(transcript.as_bytes())
+///     .speed(0.0)
+///     .into_output();
+/// assert_eq!(output.count(), 2);
+/// ```
+#[derive(Debug)]
+pub struct ControlModeReplay<B: BufRead> {
+    reader: B,
+    speed: f64,
+    time: Option<usize>,
+    line: Vec<u8>,
+    pos: usize,
+}
+
+impl ControlModeReplay<BufReader<File>> {
+    /// Open transcript file for replay
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Ok(Self::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<B: BufRead> ControlModeReplay<B> {
+    /// Replay with original speed
+    pub fn new(reader: B) -> Self {
+        ControlModeReplay {
+            reader,
+            speed: 1.0,
+            time: None,
+            line: Vec::new(),
+            pos: 0,
+        }
+    }
+
+    /// Set speed factor (`2.0` - twice as fast as original, `0.0` - without delays)
+    pub fn speed(mut self, speed: f64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Use replay as input for control mode output parser
+    pub fn into_output(self) -> ControlModeOutput<BufReader<Self>> {
+        ControlModeOutput::new(BufReader::new(self).lines())
+    }
+
+    // wait before output block, accordingly to the difference with the previous one
+    fn delay(&mut self) {
+        let line = String::from_utf8_lossy(&self.line);
+        let line = strip_dcs(&line);
+        if !line.starts_with(OUTPUT_BLOCK_BEGIN) {
+            return;
+        }
+        let time = line
+            .split(CONTROL_MODE_SEPARATOR)
+            .nth(1)
+            .and_then(|s| s.trim_end().parse::<usize>().ok());
+        if let (Some(prev), Some(time)) = (self.time, time) {
+            if self.speed > 0.0 && time > prev {
+                thread::sleep(Duration::from_secs_f64((time - prev) as f64 / self.speed));
+            }
+        }
+        if time.is_some() {
+            self.time = time;
+        }
+    }
+}
+
+impl<B: BufRead> Read for ControlModeReplay<B> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        // next line needed
+        if self.pos >= self.line.len() {
+            self.line.clear();
+            self.pos = 0;
+            if self.reader.read_until(b'\n', &mut self.line)? == 0 {
+                return Ok(0);
+            }
+            // `%output` can contain invalid UTF-8, output parser reads lines as strings
+            if let Cow::Owned(line) = String::from_utf8_lossy(&self.line) {
+                self.line = line.into_bytes();
+            }
+            self.delay();
+        }
+
+        let n = buf.len().min(self.line.len() - self.pos);
+        buf[..n].copy_from_slice(&self.line[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
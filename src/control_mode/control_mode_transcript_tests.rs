@@ -0,0 +1,108 @@
+const TRANSCRIPT: &str = "%begin 1618054308 1 0
+%end 1618054308 1 0
+%window-add @1
+%begin 1618054310 2 1
+line 1
+line 2
+%error 1618054310 2 1
+%sessions-changed
+";
+
+#[test]
+fn transcript() {
+    use crate::control_mode::control_mode::{OutputBlock, Response};
+    use crate::control_mode::control_mode_transcript::{ControlModeTranscript, TranscriptEntry};
+
+    let entries: Vec<TranscriptEntry> = ControlModeTranscript::new(TRANSCRIPT.as_bytes())
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            TranscriptEntry {
+                time: Some(1618054308),
+                response: Response::OutputBlock(OutputBlock {
+                    time: 1618054308,
+                    num: 1,
+                    flags: 0,
+                    success: true,
+                    data: None
+                })
+            },
+            TranscriptEntry {
+                time: Some(1618054308),
+                response: Response::WindowAdd("@1".to_string())
+            },
+            TranscriptEntry {
+                time: Some(1618054310),
+                response: Response::OutputBlock(OutputBlock {
+                    time: 1618054310,
+                    num: 2,
+                    flags: 1,
+                    success: false,
+                    data: Some("line 1\nline 2".to_string())
+                })
+            },
+            TranscriptEntry {
+                time: Some(1618054310),
+                response: Response::SessionsChanged
+            },
+        ]
+    );
+}
+
+#[test]
+fn transcript_invalid() {
+    use crate::control_mode::control_mode::Response;
+    use crate::control_mode::control_mode_transcript::{ControlModeReplay, ControlModeTranscript};
+
+    // raw bytes in `%output`, unparsable line in the middle
+    let transcript = b"%output %1 a\xff\xfeb\r\n%begin x\n%window-add @1\n";
+    let entries: Vec<_> = ControlModeTranscript::new(&transcript[..]).collect();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(
+        entries[0].as_ref().unwrap().response,
+        Response::Output {
+            pane_id: "%1".to_string(),
+            value: "a\u{fffd}\u{fffd}b".to_string()
+        }
+    );
+    assert!(entries[1].is_err());
+    assert_eq!(
+        entries[2].as_ref().unwrap().response,
+        Response::WindowAdd("@1".to_string())
+    );
+
+    let replayed: Vec<Response> = ControlModeReplay::new(&transcript[..])
+        .speed(0.0)
+        .into_output()
+        .collect();
+    assert_eq!(replayed.len(), 1);
+}
+
+#[test]
+fn replay() {
+    use crate::control_mode::control_mode::{ControlModeOutput, Response};
+    use crate::control_mode::control_mode_transcript::ControlModeReplay;
+    use std::io::{BufRead, Read};
+    use std::time::{Duration, Instant};
+
+    // replayed data is the same as original
+    let mut data = String::new();
+    ControlModeReplay::new(TRANSCRIPT.as_bytes())
+        .speed(0.0)
+        .read_to_string(&mut data)
+        .unwrap();
+    assert_eq!(TRANSCRIPT, data);
+
+    // 2 seconds between output blocks, accelerated by 20
+    let now = Instant::now();
+    let replayed: Vec<Response> = ControlModeReplay::new(TRANSCRIPT.as_bytes())
+        .speed(20.0)
+        .into_output()
+        .collect();
+    assert!(now.elapsed() >= Duration::from_millis(100));
+
+    let original: Vec<Response> = ControlModeOutput::new(TRANSCRIPT.as_bytes().lines()).collect();
+    assert_eq!(original, replayed);
+}
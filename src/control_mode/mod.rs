@@ -9,7 +9,17 @@ pub mod constants;
 pub mod control_mode;
 
 pub mod control_mode_ctl;
+#[cfg(feature = "tmux_1_8")]
+pub mod control_mode_transcript;
+#[cfg(feature = "tmux_1_8")]
+pub mod server_state;
 
 #[cfg(test)]
 #[cfg(feature = "tmux_1_8")]
 pub mod control_mode_tests;
+#[cfg(test)]
+#[cfg(feature = "tmux_1_8")]
+pub mod control_mode_transcript_tests;
+#[cfg(test)]
+#[cfg(feature = "tmux_1_8")]
+pub mod server_state_tests;
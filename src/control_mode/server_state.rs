@@ -0,0 +1,610 @@
+use crate::control_mode::control_mode::Response;
+#[cfg(feature = "tmux_2_0")]
+use crate::TargetResolver;
+#[cfg(feature = "tmux_2_2")]
+use crate::WindowFlags;
+#[cfg(feature = "tmux_3_3")]
+use crate::DisplayMessage;
+use crate::{
+    ClientName, Error, Layout, LayoutCell, ListPanes, Pane, PaneId, PanesCtl, Session, SessionId,
+    Snapshot, SnapshotCtl, Tmux, TmuxCommand, TmuxOutput, Window, WindowId,
+};
+use std::str::FromStr;
+
+// Live mirror of the tmux server structure (sessions -> windows -> panes)
+//
+// 1. seed using one `list-panes -a` query (`ServerState::refresh`)
+// 2. apply control mode notifications one by one (`ServerState::apply`)
+// 3. get list of changes after each applied notification
+//
+// NOTE: windows are stored per session, a window linked into multiple sessions is stored in
+// each of them (`window_active`, `window_index` are session dependent values)
+
+/// Window with all its panes
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct WindowState {
+    pub window: Window,
+    pub panes: Vec<Pane>,
+}
+
+/// Session with all its windows
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct SessionState {
+    pub session: Session,
+    pub windows: Vec<WindowState>,
+}
+
+/// Change of the server state, emitted after a notification was applied
+#[derive(Clone, PartialEq, Debug)]
+pub enum ServerStateEvent {
+    /// new session appeared (session id)
+    SessionAdded(SessionId),
+    /// session was closed (session id)
+    SessionClosed(SessionId),
+    /// session was renamed
+    SessionRenamed { session_id: SessionId, name: String },
+    /// session the control mode client is attached to was changed
+    SessionChanged { session_id: SessionId, name: String },
+    /// current window of the session was changed
+    SessionWindowChanged {
+        session_id: SessionId,
+        window_id: WindowId,
+    },
+    /// new window was linked to the session
+    WindowAdded {
+        session_id: SessionId,
+        window_id: WindowId,
+    },
+    /// window was unlinked from the session
+    WindowClosed {
+        session_id: SessionId,
+        window_id: WindowId,
+    },
+    /// index of the window in the session was changed
+    WindowMoved {
+        session_id: SessionId,
+        window_id: WindowId,
+        index: usize,
+    },
+    /// window was renamed
+    WindowRenamed { window_id: WindowId, name: String },
+    /// layout of the window was changed
+    LayoutChanged { window_id: WindowId },
+    /// new pane appeared in the window
+    PaneAdded {
+        window_id: WindowId,
+        pane_id: PaneId,
+    },
+    /// pane was closed
+    PaneClosed {
+        window_id: WindowId,
+        pane_id: PaneId,
+    },
+    /// pane size was changed
+    PaneResized {
+        window_id: WindowId,
+        pane_id: PaneId,
+        width: usize,
+        height: usize,
+    },
+    /// active pane of the window was changed
+    WindowPaneChanged {
+        window_id: WindowId,
+        pane_id: PaneId,
+    },
+    /// new client attached to the session (client name)
+    ClientAttached {
+        client: ClientName,
+        session_id: SessionId,
+    },
+    /// client was detached (client name)
+    ClientDetached(ClientName),
+    /// session the client is attached to was changed
+    ClientSessionChanged {
+        client: ClientName,
+        session_id: SessionId,
+        name: String,
+    },
+}
+
+pub struct ServerState<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    pub sessions: Vec<SessionState>,
+    /// id of the session the control mode client is attached to
+    pub current_session: Option<SessionId>,
+}
+
+impl<'a> Default for ServerState<'a> {
+    fn default() -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+            sessions: Vec::new(),
+            current_session: None,
+        }
+    }
+}
+
+impl<'a> ServerState<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self {
+            invoker,
+            ..Default::default()
+        }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Get the whole server state (sessions, windows, panes) from tmux
+    pub fn get(
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<Self, Error> {
+        let mut state = Self::with_invoker(invoker);
+        state.refresh()?;
+        Ok(state)
+    }
+
+    /// Request the whole server state from tmux again, returns changes between the old and the
+    /// new states
+    pub fn refresh(&mut self) -> Result<Vec<ServerStateEvent>, Error> {
+        // one `list-panes -a` query, windows closed between separate `list-*` queries would fail
+        // the whole refresh
+        let cmd = ListPanes::new()
+            .all()
+            .format(SnapshotCtl::formats().to_string());
+        let output = (self.invoker)(cmd.build())?.to_string();
+        let sessions = Self::sessions_from_snapshot(&Snapshot::from_str(&output)?);
+
+        let events = Self::diff(&self.sessions, &sessions);
+        self.sessions = sessions;
+        Ok(events)
+    }
+
+    // windows are stored per session with session dependent values (index, active)
+    fn sessions_from_snapshot(snapshot: &Snapshot) -> Vec<SessionState> {
+        let mut sessions = Vec::new();
+        for session in snapshot.sessions.iter() {
+            let mut windows = Vec::new();
+            for link in snapshot.links.iter().filter(|l| session.id == Some(l.session_id)) {
+                if let Some(snapshot_window) = snapshot.window(link.window_id) {
+                    let mut window = snapshot_window.window.clone();
+                    window.index = link.index;
+                    window.active = link.active;
+                    windows.push(WindowState {
+                        window,
+                        panes: snapshot_window.panes.clone(),
+                    });
+                }
+            }
+            sessions.push(SessionState {
+                session: session.clone(),
+                windows,
+            });
+        }
+        sessions
+    }
+
+    fn get_panes(&self, window_id: WindowId) -> Result<Vec<Pane>, Error> {
+        Ok(PanesCtl::get_all_ext(Some(window_id.to_string()), self.invoker())?.0)
+    }
+
+    // name of the window, `None` if it doesn't exist anymore
+    #[cfg(feature = "tmux_3_3")]
+    fn get_window_name(&self, window_id: WindowId) -> Result<Option<String>, Error> {
+        let cmd = DisplayMessage::new()
+            .print()
+            .target_pane(window_id.to_string())
+            .message("#{window_id} #{window_name}");
+        let output = (self.invoker)(cmd.build())?;
+        if !output.success() {
+            return Ok(None);
+        }
+        let output = output.to_string();
+        match output.trim_end_matches('\n').split_once(' ') {
+            Some((id, name)) if id.parse::<WindowId>().ok() == Some(window_id) => {
+                Ok(Some(name.to_string()))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Get session by id
+    pub fn session(&self, session_id: SessionId) -> Option<&SessionState> {
+        self.sessions
+            .iter()
+            .find(|s| s.session.id == Some(session_id))
+    }
+
+    /// Get window by id (first found, if linked in multiple sessions)
+    pub fn window(&self, window_id: WindowId) -> Option<&WindowState> {
+        self.sessions
+            .iter()
+            .flat_map(|s| s.windows.iter())
+            .find(|w| w.window.id == Some(window_id))
+    }
+
+    /// Get pane by id
+    pub fn pane(&self, pane_id: PaneId) -> Option<&Pane> {
+        self.sessions
+            .iter()
+            .flat_map(|s| s.windows.iter())
+            .flat_map(|w| w.panes.iter())
+            .find(|p| p.id == Some(pane_id))
+    }
+
+    /// Resolver of targets using the current state
+    #[cfg(feature = "tmux_2_0")]
+    pub fn resolver(&self) -> TargetResolver<'_> {
+        let mut resolver = TargetResolver::new();
+        for session_state in self.sessions.iter() {
+            resolver = resolver.session(&session_state.session);
+            if let Some(session_id) = session_state.session.id {
+                for window_state in session_state.windows.iter() {
+                    resolver = resolver.window(session_id, &window_state.window);
+                }
+            }
+        }
+        // panes of linked windows are added to all sessions at once
+        let mut window_ids = Vec::new();
+        for window_state in self.sessions.iter().flat_map(|s| s.windows.iter()) {
+            match window_state.window.id {
+                Some(window_id) if !window_ids.contains(&window_id) => {
+                    window_ids.push(window_id);
+                    for pane in window_state.panes.iter() {
+                        resolver = resolver.pane(window_id, pane);
+                    }
+                }
+                _ => {}
+            }
+        }
+        match self.current_session {
+            Some(session_id) => resolver.current_session(session_id),
+            None => resolver,
+        }
+    }
+
+    fn windows_mut(&mut self, window_id: WindowId) -> impl Iterator<Item = &mut WindowState> {
+        self.sessions
+            .iter_mut()
+            .flat_map(|s| s.windows.iter_mut())
+            .filter(move |w| w.window.id == Some(window_id))
+    }
+
+    /// Apply a control mode notification to the state, returns the list of changes
+    ///
+    /// Notifications which don't contain enough information (`%window-add`,
+    /// `%sessions-changed`, ...) cause requesting the affected part of the state from tmux
+    pub fn apply(&mut self, response: &Response) -> Result<Vec<ServerStateEvent>, Error> {
+        let mut events = Vec::new();
+
+        match response {
+            // `%sessions-changed`
+            // `%window-add window-id`
+            // `%unlinked-window-add window-id`
+            #[cfg(feature = "tmux_1_8")]
+            Response::SessionsChanged | Response::WindowAdd(_) | Response::UnlinkedWindowAdd(_) => {
+                events = self.refresh()?;
+            }
+
+            // `%window-close window-id`
+            #[cfg(feature = "tmux_1_8")]
+            Response::WindowClose(window_id) => {
+                events = self.remove_window(window_id)?;
+            }
+
+            // `%unlinked-window-close window-id`
+            #[cfg(feature = "tmux_3_3")]
+            Response::UnlinkedWindowClose(window_id) => {
+                events = self.remove_window(window_id)?;
+            }
+
+            // `%window-renamed window-id name`
+            #[cfg(feature = "tmux_1_8")]
+            Response::WindowRenamed { window_id, name } => {
+                let window_id = window_id.parse::<WindowId>()?;
+                for window_state in self.windows_mut(window_id) {
+                    window_state.window.name = Some(name.to_string());
+                }
+                events.push(ServerStateEvent::WindowRenamed {
+                    window_id,
+                    name: name.to_string(),
+                });
+            }
+
+            // `%client-session-changed client session-id name`
+            #[cfg(feature = "tmux_2_4")]
+            Response::ClientSessionChanged {
+                client,
+                session_id,
+                name,
+            } => {
+                events.push(ServerStateEvent::ClientSessionChanged {
+                    client: ClientName::from(client.as_str()),
+                    session_id: session_id.parse::<SessionId>()?,
+                    name: name.to_string(),
+                });
+            }
+
+            // `%client-detached client`
+            #[cfg(feature = "tmux_3_2")]
+            Response::ClientDetached(client) => {
+                events.push(ServerStateEvent::ClientDetached(client.as_str().into()));
+            }
+
+            // `%unlinked-window-renamed window-id`
+            #[cfg(feature = "tmux_3_3")]
+            Response::UnlinkedWindowRenamed(window_id) => {
+                let window_id = window_id.parse::<WindowId>()?;
+                // window closed meanwhile, `%unlinked-window-close` follows
+                if let Some(name) = self.get_window_name(window_id)? {
+                    for window_state in self.windows_mut(window_id) {
+                        window_state.window.name = Some(name.clone());
+                    }
+                    events.push(ServerStateEvent::WindowRenamed { window_id, name });
+                }
+            }
+
+            // `%session-renamed session-id name`
+            #[cfg(feature = "tmux_1_8")]
+            Response::SessionRenamed(s) => {
+                let (session_id, name) = s.split_once(' ').ok_or(Error::CMParseStr)?;
+                let session_id = session_id.parse::<SessionId>()?;
+                if let Some(session_state) = self
+                    .sessions
+                    .iter_mut()
+                    .find(|s| s.session.id == Some(session_id))
+                {
+                    session_state.session.name = Some(name.to_string());
+                }
+                events.push(ServerStateEvent::SessionRenamed {
+                    session_id,
+                    name: name.to_string(),
+                });
+            }
+
+            // `%session-changed session-id name`
+            #[cfg(feature = "tmux_1_8")]
+            Response::SessionChanged { session_id, name } => {
+                let session_id = session_id.parse::<SessionId>()?;
+                self.current_session = Some(session_id);
+                events.push(ServerStateEvent::SessionChanged {
+                    session_id,
+                    name: name.to_string(),
+                });
+            }
+
+            // `%session-window-changed session-id window-id`
+            #[cfg(feature = "tmux_2_5")]
+            Response::SessionWindowChanged {
+                session_id,
+                window_id,
+            } => {
+                let session_id = session_id.parse::<SessionId>()?;
+                let window_id = window_id.parse::<WindowId>()?;
+                if let Some(session_state) = self
+                    .sessions
+                    .iter_mut()
+                    .find(|s| s.session.id == Some(session_id))
+                {
+                    for window_state in session_state.windows.iter_mut() {
+                        window_state.window.active =
+                            Some(window_state.window.id == Some(window_id));
+                    }
+                }
+                events.push(ServerStateEvent::SessionWindowChanged {
+                    session_id,
+                    window_id,
+                });
+            }
+
+            // `%window-pane-changed window-id pane-id`
+            #[cfg(feature = "tmux_2_5")]
+            Response::WindowPaneChanged { window_id, pane_id } => {
+                let window_id = window_id.parse::<WindowId>()?;
+                let pane_id = pane_id.parse::<PaneId>()?;
+                for window_state in self.windows_mut(window_id) {
+                    for pane in window_state.panes.iter_mut() {
+                        pane.active = Some(pane.id == Some(pane_id));
+                    }
+                }
+                events.push(ServerStateEvent::WindowPaneChanged { window_id, pane_id });
+            }
+
+            // `%layout-change window-id window-layout window-visible-layout window-flags`
+            #[cfg(feature = "tmux_1_8")]
+            Response::LayoutChange {
+                window_id,
+                window_layout,
+                #[cfg(feature = "tmux_2_2")]
+                window_visible_layout,
+                #[cfg(feature = "tmux_2_2")]
+                window_flags,
+            } => {
+                let window_id = window_id.parse::<WindowId>()?;
+                let layout: Layout = window_layout.parse()?;
+                #[cfg(feature = "tmux_2_2")]
+                let visible_layout: Layout = window_visible_layout.parse()?;
+                #[cfg(feature = "tmux_2_2")]
+                let flags: WindowFlags = window_flags.parse()?;
+
+                let mut pane_ids = Vec::new();
+                layout_pane_ids(&layout.cell, &mut pane_ids);
+
+                let mut panes_changed = false;
+                for window_state in self.windows_mut(window_id) {
+                    window_state.window.layout = Some(layout.clone());
+                    #[cfg(feature = "tmux_2_2")]
+                    {
+                        window_state.window.visible_layout = Some(visible_layout.clone());
+                        window_state.window.flags = Some(flags.clone());
+                    }
+                    let mut known: Vec<PaneId> =
+                        window_state.panes.iter().filter_map(|p| p.id).collect();
+                    known.sort_unstable();
+                    pane_ids.sort_unstable();
+                    panes_changed |= known != pane_ids;
+                }
+
+                // panes were split or killed, layout contains ids only, request pane variables
+                if panes_changed {
+                    let panes = self.get_panes(window_id)?;
+                    let mut old_ids = Vec::new();
+                    for window_state in self.windows_mut(window_id) {
+                        old_ids = window_state.panes.iter().filter_map(|p| p.id).collect();
+                        window_state.panes = panes.clone();
+                    }
+                    let new_ids: Vec<PaneId> = panes.iter().filter_map(|p| p.id).collect();
+                    for pane_id in new_ids.iter().filter(|id| !old_ids.contains(id)) {
+                        events.push(ServerStateEvent::PaneAdded {
+                            window_id,
+                            pane_id: *pane_id,
+                        });
+                    }
+                    for pane_id in old_ids.iter().filter(|id| !new_ids.contains(id)) {
+                        events.push(ServerStateEvent::PaneClosed {
+                            window_id,
+                            pane_id: *pane_id,
+                        });
+                    }
+                }
+
+                events.push(ServerStateEvent::LayoutChanged { window_id });
+            }
+
+            // output blocks, pane output and other notifications don't change the state
+            _ => {}
+        }
+
+        Ok(events)
+    }
+
+    fn remove_window(&mut self, window_id: &str) -> Result<Vec<ServerStateEvent>, Error> {
+        let window_id = window_id.parse::<WindowId>()?;
+        let mut events = Vec::new();
+        for session_state in self.sessions.iter_mut() {
+            let len = session_state.windows.len();
+            session_state
+                .windows
+                .retain(|w| w.window.id != Some(window_id));
+            if let (Some(session_id), true) =
+                (session_state.session.id, len != session_state.windows.len())
+            {
+                events.push(ServerStateEvent::WindowClosed {
+                    session_id,
+                    window_id,
+                });
+            }
+        }
+        Ok(events)
+    }
+
+    // compare two states by ids, get structural changes
+    fn diff(old: &[SessionState], new: &[SessionState]) -> Vec<ServerStateEvent> {
+        let mut events = Vec::new();
+
+        let find_session = |sessions: &'_ [SessionState], id| {
+            sessions
+                .iter()
+                .find(|s| s.session.id == id)
+                .map(|s| s.windows.clone())
+        };
+
+        for session_state in new {
+            let session_id = match session_state.session.id {
+                Some(id) => id,
+                None => continue,
+            };
+            let old_windows = match find_session(old, Some(session_id)) {
+                Some(windows) => windows,
+                None => {
+                    events.push(ServerStateEvent::SessionAdded(session_id));
+                    Vec::new()
+                }
+            };
+            Self::diff_windows(
+                session_id,
+                &old_windows,
+                &session_state.windows,
+                &mut events,
+            );
+        }
+
+        for session_state in old {
+            if let Some(session_id) = session_state.session.id {
+                if find_session(new, Some(session_id)).is_none() {
+                    Self::diff_windows(session_id, &session_state.windows, &[], &mut events);
+                    events.push(ServerStateEvent::SessionClosed(session_id));
+                }
+            }
+        }
+
+        events
+    }
+
+    fn diff_windows(
+        session_id: SessionId,
+        old: &[WindowState],
+        new: &[WindowState],
+        events: &mut Vec<ServerStateEvent>,
+    ) {
+        let pane_ids = |w: &WindowState| w.panes.iter().filter_map(|p| p.id).collect::<Vec<_>>();
+
+        for window_state in new {
+            let window_id = match window_state.window.id {
+                Some(id) => id,
+                None => continue,
+            };
+            let old_panes = match old.iter().find(|w| w.window.id == Some(window_id)) {
+                Some(w) => pane_ids(w),
+                None => {
+                    events.push(ServerStateEvent::WindowAdded {
+                        session_id,
+                        window_id,
+                    });
+                    Vec::new()
+                }
+            };
+            let new_panes = pane_ids(window_state);
+            for pane_id in new_panes.iter().filter(|id| !old_panes.contains(id)) {
+                events.push(ServerStateEvent::PaneAdded {
+                    window_id,
+                    pane_id: *pane_id,
+                });
+            }
+            for pane_id in old_panes.iter().filter(|id| !new_panes.contains(id)) {
+                events.push(ServerStateEvent::PaneClosed {
+                    window_id,
+                    pane_id: *pane_id,
+                });
+            }
+        }
+
+        for window_state in old {
+            if let Some(window_id) = window_state.window.id {
+                if !new.iter().any(|w| w.window.id == Some(window_id)) {
+                    events.push(ServerStateEvent::WindowClosed {
+                        session_id,
+                        window_id,
+                    });
+                }
+            }
+        }
+    }
+}
+
+// collect pane ids from all leaf cells of the layout
+fn layout_pane_ids(cell: &LayoutCell, ids: &mut Vec<PaneId>) {
+    if let Some(id) = cell.id {
+        ids.push(PaneId(id));
+    }
+    if let Some(cells) = &cell.cells {
+        for cell in cells {
+            layout_pane_ids(cell, ids);
+        }
+    }
+}
@@ -0,0 +1,363 @@
+use crate::control_mode::server_state::{ServerState, SessionState, WindowState};
+use crate::{PaneId, SessionId, WindowId};
+
+fn server_state<'a>() -> ServerState<'a> {
+    use crate::{Pane, Session, Window};
+
+    let mut session = Session::new();
+    session.id = Some(SessionId(1));
+    session.name = Some("session1".to_string());
+
+    let mut window = Window::new();
+    window.id = Some(WindowId(2));
+    window.name = Some("window2".to_string());
+    window.active = Some(true);
+
+    let mut pane3 = Pane::new();
+    pane3.id = Some(PaneId(3));
+    pane3.active = Some(true);
+    let mut pane4 = Pane::new();
+    pane4.id = Some(PaneId(4));
+    pane4.active = Some(false);
+
+    let mut window5 = Window::new();
+    window5.id = Some(WindowId(5));
+    window5.active = Some(false);
+
+    let mut state = ServerState::new();
+    state.sessions.push(SessionState {
+        session,
+        windows: vec![
+            WindowState {
+                window,
+                panes: vec![pane3, pane4],
+            },
+            WindowState {
+                window: window5,
+                panes: Vec::new(),
+            },
+        ],
+    });
+    state
+}
+
+#[test]
+fn apply_renamed() {
+    use crate::control_mode::control_mode::Response;
+    use crate::control_mode::server_state::ServerStateEvent;
+
+    let mut state = server_state();
+
+    let events = state
+        .apply(&Response::WindowRenamed {
+            window_id: "@2".to_string(),
+            name: "renamed window".to_string(),
+        })
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![ServerStateEvent::WindowRenamed {
+            window_id: WindowId(2),
+            name: "renamed window".to_string()
+        }]
+    );
+    assert_eq!(
+        state.window(WindowId(2)).unwrap().window.name,
+        Some("renamed window".to_string())
+    );
+
+    let events = state
+        .apply(&Response::SessionRenamed("$1 renamed session".to_string()))
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![ServerStateEvent::SessionRenamed {
+            session_id: SessionId(1),
+            name: "renamed session".to_string()
+        }]
+    );
+    assert_eq!(
+        state.session(SessionId(1)).unwrap().session.name,
+        Some("renamed session".to_string())
+    );
+}
+
+#[test]
+fn apply_window_close() {
+    use crate::control_mode::control_mode::Response;
+    use crate::control_mode::server_state::ServerStateEvent;
+
+    let mut state = server_state();
+
+    let events = state
+        .apply(&Response::WindowClose("@2".to_string()))
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![ServerStateEvent::WindowClosed {
+            session_id: SessionId(1),
+            window_id: WindowId(2)
+        }]
+    );
+    assert!(state.window(WindowId(2)).is_none());
+    assert!(state.pane(PaneId(3)).is_none());
+    assert!(state.window(WindowId(5)).is_some());
+}
+
+#[cfg(feature = "tmux_2_5")]
+#[test]
+fn apply_active_changed() {
+    use crate::control_mode::control_mode::Response;
+    use crate::control_mode::server_state::ServerStateEvent;
+
+    let mut state = server_state();
+
+    let events = state
+        .apply(&Response::WindowPaneChanged {
+            window_id: "@2".to_string(),
+            pane_id: "%4".to_string(),
+        })
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![ServerStateEvent::WindowPaneChanged {
+            window_id: WindowId(2),
+            pane_id: PaneId(4)
+        }]
+    );
+    assert_eq!(state.pane(PaneId(3)).unwrap().active, Some(false));
+    assert_eq!(state.pane(PaneId(4)).unwrap().active, Some(true));
+
+    let events = state
+        .apply(&Response::SessionWindowChanged {
+            session_id: "$1".to_string(),
+            window_id: "@5".to_string(),
+        })
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![ServerStateEvent::SessionWindowChanged {
+            session_id: SessionId(1),
+            window_id: WindowId(5)
+        }]
+    );
+    assert_eq!(
+        state.window(WindowId(2)).unwrap().window.active,
+        Some(false)
+    );
+    assert_eq!(state.window(WindowId(5)).unwrap().window.active, Some(true));
+}
+
+#[test]
+fn apply_layout_change() {
+    use crate::control_mode::control_mode::Response;
+    use crate::control_mode::server_state::ServerStateEvent;
+    use crate::Layout;
+
+    let mut state = server_state();
+
+    // same panes, no tmux request needed
+    let layout = "bb62,159x48,0,0{79x48,0,0,3,79x48,80,0,4}";
+    let events = state
+        .apply(&Response::LayoutChange {
+            window_id: "@2".to_string(),
+            window_layout: layout.to_string(),
+            #[cfg(feature = "tmux_2_2")]
+            window_visible_layout: layout.to_string(),
+            #[cfg(feature = "tmux_2_2")]
+            window_flags: "*".to_string(),
+        })
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![ServerStateEvent::LayoutChanged {
+            window_id: WindowId(2)
+        }]
+    );
+    assert_eq!(
+        state.window(WindowId(2)).unwrap().window.layout,
+        Some(layout.parse::<Layout>().unwrap())
+    );
+}
+
+#[cfg(feature = "tmux_3_2")]
+#[test]
+fn apply_client() {
+    use crate::control_mode::control_mode::Response;
+    use crate::control_mode::server_state::ServerStateEvent;
+    use crate::ClientName;
+
+    let mut state = server_state();
+
+    let events = state
+        .apply(&Response::ClientSessionChanged {
+            client: "/dev/pts/1".to_string(),
+            session_id: "$1".to_string(),
+            name: "session1".to_string(),
+        })
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![ServerStateEvent::ClientSessionChanged {
+            client: ClientName::from("/dev/pts/1"),
+            session_id: SessionId(1),
+            name: "session1".to_string()
+        }]
+    );
+
+    let events = state
+        .apply(&Response::ClientDetached("/dev/pts/1".to_string()))
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![ServerStateEvent::ClientDetached(ClientName::from(
+            "/dev/pts/1"
+        ))]
+    );
+}
+
+#[test]
+fn apply_invalid_id() {
+    use crate::control_mode::control_mode::Response;
+
+    let mut state = server_state();
+    assert!(state
+        .apply(&Response::WindowClose("2".to_string()))
+        .is_err());
+}
+
+#[cfg(feature = "tmux_2_0")]
+#[test]
+fn resolver() {
+    use crate::TargetPaneExt;
+
+    let mut state = server_state();
+    state.current_session = Some(SessionId(1));
+    let resolver = state.resolver();
+
+    let target_pane = "session1:window2".parse::<TargetPaneExt>().unwrap();
+    assert_eq!(
+        resolver.resolve_pane(&target_pane).unwrap().id,
+        Some(PaneId(3))
+    );
+    let target_pane = "%4".parse::<TargetPaneExt>().unwrap();
+    assert_eq!(
+        resolver.resolve_pane(&target_pane).unwrap().id,
+        Some(PaneId(4))
+    );
+}
+
+// invoker returning `stdout` for any command, failing if `stdout` is `None`
+fn output(stdout: Option<String>) -> Result<crate::TmuxOutput, crate::Error> {
+    use crate::TmuxOutput;
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::{ExitStatus, Output};
+
+    Ok(TmuxOutput(Output {
+        status: ExitStatus::from_raw(if stdout.is_some() { 0 } else { 256 }),
+        stdout: stdout.unwrap_or_default().into_bytes(),
+        stderr: Vec::new(),
+    }))
+}
+
+#[test]
+fn apply_window_add() {
+    use crate::control_mode::control_mode::Response;
+    use crate::control_mode::server_state::ServerStateEvent;
+    use crate::{FormatsOutput, SnapshotCtl, Variable};
+
+    // `list-panes -a` record, values of not given variables are empty
+    let record = |given: &[(Variable, &str)]| {
+        let values: Vec<&str> = SnapshotCtl::formats()
+            .variables
+            .iter()
+            .map(|v| {
+                given
+                    .iter()
+                    .find(|(var, _)| var == v)
+                    .map_or("", |(_, value)| *value)
+            })
+            .collect();
+        FormatsOutput::delimited_record(&values)
+    };
+    // $1: @2 [%3, %4], $7: @2 [%3, %4] linked at index 5
+    let mut records = Vec::new();
+    for (session_id, index) in [("$1", "0"), ("$7", "5")].iter() {
+        for pane_id in ["%3", "%4"].iter() {
+            records.push(record(&[
+                (Variable::SessionId, session_id),
+                (Variable::WindowId, "@2"),
+                (Variable::WindowIndex, index),
+                (Variable::PaneId, pane_id),
+            ]));
+        }
+    }
+    let records = records.join("\n");
+    let invoker = move |_| output(Some(records.clone()));
+
+    let mut state = server_state();
+    state.invoker = &invoker;
+    let events = state
+        .apply(&Response::WindowAdd("@2".to_string()))
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![
+            ServerStateEvent::WindowClosed {
+                session_id: SessionId(1),
+                window_id: WindowId(5)
+            },
+            ServerStateEvent::SessionAdded(SessionId(7)),
+            ServerStateEvent::WindowAdded {
+                session_id: SessionId(7),
+                window_id: WindowId(2)
+            },
+            ServerStateEvent::PaneAdded {
+                window_id: WindowId(2),
+                pane_id: PaneId(3)
+            },
+            ServerStateEvent::PaneAdded {
+                window_id: WindowId(2),
+                pane_id: PaneId(4)
+            },
+        ]
+    );
+    let windows = &state.session(SessionId(7)).unwrap().windows;
+    assert_eq!(windows.len(), 1);
+    assert_eq!(windows[0].window.index, Some(5));
+    assert_eq!(windows[0].panes.len(), 2);
+    assert_eq!(state.session(SessionId(1)).unwrap().windows[0].window.index, Some(0));
+}
+
+#[cfg(feature = "tmux_3_3")]
+#[test]
+fn apply_unlinked_window_renamed() {
+    use crate::control_mode::control_mode::Response;
+    use crate::control_mode::server_state::ServerStateEvent;
+
+    let invoker = |_| output(Some("@2 new name\n".to_string()));
+    let mut state = server_state();
+    state.invoker = &invoker;
+    let events = state
+        .apply(&Response::UnlinkedWindowRenamed("@2".to_string()))
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![ServerStateEvent::WindowRenamed {
+            window_id: WindowId(2),
+            name: "new name".to_string()
+        }]
+    );
+    assert_eq!(
+        state.window(WindowId(2)).unwrap().window.name.as_deref(),
+        Some("new name")
+    );
+
+    // window closed meanwhile
+    let invoker = |_| output(None);
+    state.invoker = &invoker;
+    let events = state
+        .apply(&Response::UnlinkedWindowRenamed("@2".to_string()))
+        .unwrap();
+    assert!(events.is_empty());
+}
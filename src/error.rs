@@ -10,6 +10,17 @@ pub enum Error {
     ParseStatusKeys,
     ParseVersion,
     ParseWindowFlags,
+    ParseLayout,
+    ParseSessionId,
+    ParseWindowId,
+    ParsePaneId,
+    ParseTargetSession,
+    ParseTargetWindow,
+    ParseTargetPane,
+    ParseVariable,
+    ParseFormat,
+    ParseFormatsOutput,
+    ParseFormatValue,
     ParseSwitch,
     ParseSetClipboard,
     ParseActivity,
@@ -25,9 +36,18 @@ pub enum Error {
     ParseTerminalFeatures,
     ParseModeMouse,
     ParseDetachOnDestroy,
+    ParseAsciicast,
+    ParseProcessInfo,
+
+    /// Target can't be resolved (kind, target)
+    TargetNotFound(&'static str, String),
+    /// Target matches more than one object (kind, target, number of matches)
+    TargetAmbiguous(&'static str, String, usize),
 
     /// Tmux error message
     Tmux(String),
+    /// Pattern not seen before timeout (pattern, pane contents seen)
+    ExpectTimeout(String, String),
     /// IO error
     IO(std::io::Error),
 
@@ -57,7 +77,14 @@ impl std::error::Error for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Self::TargetNotFound(kind, ref target) => write!(f, "can't find {}: {}", kind, target),
+            Self::TargetAmbiguous(kind, ref target, n) => {
+                write!(f, "{} matches {} {}s", target, n, kind)
+            }
             Self::Tmux(ref msg) => write!(f, "{}", msg),
+            Self::ExpectTimeout(ref pattern, ref transcript) => {
+                write!(f, "timeout waiting for {}, pane contents:\n{}", pattern, transcript)
+            }
             Self::IO(ref err) => err.fmt(f),
             Self::ParseInt(ref err) => err.fmt(f),
             Self::Parse(ref err) => err.fmt(f),
@@ -0,0 +1,551 @@
+use super::format_value::FormatValue;
+use super::{
+    FormatArithmetic, FormatComparison, FormatExpr, FormatLogical, FormatLoop, FormatModifier,
+};
+use crate::{fn_match, Buffer, Client, Error, Pane, Session, Variable, Window};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// maximum depth of nested expansions, as tmux `FORMAT_LOOP_LIMIT`
+const FORMAT_LOOP_LIMIT: usize = 100;
+// maximum `pN` width, instead of allocating any padding asked for
+const FORMAT_PAD_LIMIT: usize = 10_000;
+
+/// Values used to evaluate formats locally, without a running tmux server
+///
+/// Variables are looked up in `values` first (options, user options, overrides), then in the
+/// current pane, window, session, client and buffer. Unknown variables expand to an empty
+/// string, like in tmux.
+///
+/// Not supported offline: shell commands `#()` expand to an empty string, regular expressions
+/// (`m/r`, `s/`) are matched literally, time is formatted in UTC
+///
+/// # Example
+///
+/// ```
+/// use tmux_interface::{FormatContext, Window};
+///
+/// let mut window = Window::new();
+/// window.name = Some("editor".to_string());
+/// window.active = Some(true);
+///
+/// let context = FormatContext::new().window(&window).value("@mark", "*");
+/// let output = context.expand("#{?window_active,#{@mark},}#{p8:window_name}|");
+/// assert_eq!(output.unwrap(), "*editor  |");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct FormatContext<'a> {
+    pub session: Option<&'a Session>,
+    pub window: Option<&'a Window>,
+    pub pane: Option<&'a Pane>,
+    pub client: Option<&'a Client>,
+    pub buffer: Option<&'a Buffer>,
+    /// Sessions, windows, panes and clients for `#{S:}`, `#{W:}`, `#{P:}` and `#{L:}` loops
+    pub sessions: &'a [Session],
+    pub windows: &'a [Window],
+    pub panes: &'a [Pane],
+    pub clients: &'a [Client],
+    pub values: HashMap<String, String>,
+}
+
+impl<'a> FormatContext<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn session(mut self, session: &'a Session) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    pub fn window(mut self, window: &'a Window) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    pub fn pane(mut self, pane: &'a Pane) -> Self {
+        self.pane = Some(pane);
+        self
+    }
+
+    pub fn client(mut self, client: &'a Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    pub fn buffer(mut self, buffer: &'a Buffer) -> Self {
+        self.buffer = Some(buffer);
+        self
+    }
+
+    pub fn sessions(mut self, sessions: &'a [Session]) -> Self {
+        self.sessions = sessions;
+        self
+    }
+
+    pub fn windows(mut self, windows: &'a [Window]) -> Self {
+        self.windows = windows;
+        self
+    }
+
+    pub fn panes(mut self, panes: &'a [Pane]) -> Self {
+        self.panes = panes;
+        self
+    }
+
+    pub fn clients(mut self, clients: &'a [Client]) -> Self {
+        self.clients = clients;
+        self
+    }
+
+    /// Set value of a variable or an option (`@user_option`)
+    pub fn value<S: Into<String>, T: Into<String>>(mut self, name: S, value: T) -> Self {
+        self.values.insert(name.into(), value.into());
+        self
+    }
+
+    /// Value of a variable or an option
+    pub fn get(&self, name: &str) -> Option<String> {
+        if let Some(value) = self.values.get(name) {
+            return Some(value.clone());
+        }
+        let variable: Variable = name.parse().ok()?;
+        self.pane
+            .and_then(|pane| pane.value(&variable))
+            .or_else(|| self.window.and_then(|window| window.value(&variable)))
+            .or_else(|| self.session.and_then(|session| session.value(&variable)))
+            .or_else(|| self.client.and_then(|client| client.value(&variable)))
+            .or_else(|| self.buffer.and_then(|buffer| buffer.value(&variable)))
+    }
+
+    /// Parse and evaluate a format string
+    pub fn expand(&self, format: &str) -> Result<String, Error> {
+        Ok(self.evaluate(&format.parse()?))
+    }
+
+    /// Evaluate a format expression
+    pub fn evaluate(&self, expr: &FormatExpr) -> String {
+        self.evaluate_depth(expr, 0)
+    }
+
+    // `depth` counts nested expansions (`#{E:}`, `#{T:}`, loops), a self-referential value would
+    // be expanded forever
+    fn evaluate_depth(&self, expr: &FormatExpr, depth: usize) -> String {
+        if depth >= FORMAT_LOOP_LIMIT {
+            return String::new();
+        }
+        match expr {
+            FormatExpr::Text(text) => text.clone(),
+            FormatExpr::Raw(format) => match format.parse() {
+                // unsupported expression
+                Ok(FormatExpr::Raw(_)) | Err(_) => String::new(),
+                Ok(expr) => self.evaluate_depth(&expr, depth),
+            },
+            FormatExpr::Variable(variable) => self.get(variable.name()).unwrap_or_default(),
+            // styles are kept for drawing
+            FormatExpr::Style(style) => format!("#[{}]", style),
+            FormatExpr::Shell(_) => String::new(),
+            FormatExpr::Concat(exprs) => exprs
+                .iter()
+                .map(|expr| self.evaluate_depth(expr, depth))
+                .collect(),
+            FormatExpr::Conditional(condition, then, otherwise) => {
+                match is_true(&self.evaluate_depth(condition, depth)) {
+                    true => self.evaluate_depth(then, depth),
+                    false => self.evaluate_depth(otherwise, depth),
+                }
+            }
+            FormatExpr::Comparison(op, a, b) => {
+                let (a, b) = (self.evaluate_depth(a, depth), self.evaluate_depth(b, depth));
+                bool_value(match op {
+                    FormatComparison::Equal => a == b,
+                    FormatComparison::NotEqual => a != b,
+                    FormatComparison::Less => a < b,
+                    FormatComparison::Greater => a > b,
+                    FormatComparison::LessOrEqual => a <= b,
+                    FormatComparison::GreaterOrEqual => a >= b,
+                })
+            }
+            FormatExpr::Logical(op, a, b) => {
+                let (a, b) = (
+                    is_true(&self.evaluate_depth(a, depth)),
+                    is_true(&self.evaluate_depth(b, depth)),
+                );
+                bool_value(match op {
+                    FormatLogical::Or => a || b,
+                    FormatLogical::And => a && b,
+                })
+            }
+            FormatExpr::Match {
+                regex,
+                ignore_case,
+                pattern,
+                string,
+            } => {
+                let (mut pattern, mut string) = (
+                    self.evaluate_depth(pattern, depth),
+                    self.evaluate_depth(string, depth),
+                );
+                if *ignore_case {
+                    pattern = pattern.to_lowercase();
+                    string = string.to_lowercase();
+                }
+                bool_value(match regex {
+                    true => string.contains(&pattern),
+                    false => fn_match(pattern.as_bytes(), string.as_bytes()),
+                })
+            }
+            FormatExpr::Arithmetic {
+                op,
+                float,
+                decimals,
+                left,
+                right,
+            } => arithmetic(
+                *op,
+                *float,
+                decimals.unwrap_or(0),
+                &self.evaluate_depth(left, depth),
+                &self.evaluate_depth(right, depth),
+            )
+            .unwrap_or_default(),
+            FormatExpr::Loop(kind, format, current) => {
+                self.evaluate_loop(*kind, format, current, depth + 1)
+            }
+            FormatExpr::Modified(modifiers, expr) => self.evaluate_modified(modifiers, expr, depth),
+        }
+    }
+
+    fn evaluate_loop(
+        &self,
+        kind: FormatLoop,
+        format: &FormatExpr,
+        current: &Option<Box<FormatExpr>>,
+        depth: usize,
+    ) -> String {
+        let mut output = String::new();
+        let mut push = |context: FormatContext, is_current: bool| {
+            let format = match current {
+                Some(current) if is_current => current,
+                _ => format,
+            };
+            output.push_str(&context.evaluate_depth(format, depth));
+        };
+        match kind {
+            FormatLoop::Sessions => {
+                for session in self.sessions {
+                    let context = FormatContext {
+                        session: Some(session),
+                        window: None,
+                        pane: None,
+                        ..self.clone()
+                    };
+                    push(context, self.session == Some(session));
+                }
+            }
+            FormatLoop::Windows => {
+                for window in self.windows {
+                    let context = FormatContext {
+                        window: Some(window),
+                        pane: None,
+                        ..self.clone()
+                    };
+                    push(context, self.window == Some(window));
+                }
+            }
+            FormatLoop::Panes => {
+                for pane in self.panes {
+                    let context = FormatContext {
+                        pane: Some(pane),
+                        ..self.clone()
+                    };
+                    push(context, self.pane == Some(pane));
+                }
+            }
+            FormatLoop::Clients => {
+                for client in self.clients {
+                    let context = FormatContext {
+                        client: Some(client),
+                        ..self.clone()
+                    };
+                    push(context, self.client == Some(client));
+                }
+            }
+        }
+        output
+    }
+
+    // modifiers are applied in the same order as tmux does, not the order they are written in
+    fn evaluate_modified(
+        &self,
+        modifiers: &[FormatModifier],
+        expr: &FormatExpr,
+        depth: usize,
+    ) -> String {
+        let mut value = match expr {
+            FormatExpr::Text(text) if modifiers.contains(&FormatModifier::Literal) => text.clone(),
+            expr => self.evaluate_depth(expr, depth),
+        };
+
+        for modifier in modifiers {
+            match modifier {
+                FormatModifier::Expand | FormatModifier::ExpandTime => {
+                    value = match value.parse() {
+                        Ok(expr) => self.evaluate_depth(&expr, depth + 1),
+                        Err(_) => value,
+                    }
+                }
+                FormatModifier::Time => value = format_time(&value, "%a %b %e %H:%M:%S %Y"),
+                FormatModifier::TimePretty => value = format_pretty_time(&value),
+                FormatModifier::TimeFormat(format) => value = format_time(&value, format),
+                _ => {}
+            }
+        }
+        for modifier in modifiers {
+            match modifier {
+                FormatModifier::Basename => {
+                    value = Path::new(&value)
+                        .file_name()
+                        .map_or(value.clone(), |name| name.to_string_lossy().into())
+                }
+                FormatModifier::Dirname => {
+                    value = match Path::new(&value).parent() {
+                        Some(parent) if parent.as_os_str().is_empty() => ".".to_string(),
+                        Some(parent) => parent.to_string_lossy().into(),
+                        None if value.starts_with('/') => "/".to_string(),
+                        None => ".".to_string(),
+                    }
+                }
+                FormatModifier::Quote => value = quote_shell(&value),
+                _ => {}
+            }
+        }
+        for modifier in modifiers {
+            if let FormatModifier::Substitute {
+                pattern,
+                replacement,
+                ignore_case,
+            } = modifier
+            {
+                value = substitute(&value, pattern, replacement, *ignore_case);
+            }
+        }
+        for modifier in modifiers {
+            match modifier {
+                FormatModifier::Truncate(limit, marker) => value = truncate(&value, *limit, marker),
+                FormatModifier::Pad(width) => value = pad(&value, *width),
+                _ => {}
+            }
+        }
+        if modifiers
+            .iter()
+            .any(|m| matches!(m, FormatModifier::Length | FormatModifier::Width))
+        {
+            value = value.chars().count().to_string();
+        }
+        value
+    }
+}
+
+// same as tmux `format_true()`
+fn is_true(s: &str) -> bool {
+    !s.is_empty() && s != "0"
+}
+
+fn bool_value(b: bool) -> String {
+    b.format_value()
+}
+
+fn arithmetic(
+    op: FormatArithmetic,
+    float: bool,
+    decimals: usize,
+    left: &str,
+    right: &str,
+) -> Option<String> {
+    let (mut left, mut right) = (
+        left.trim().parse::<f64>().ok()?,
+        right.trim().parse::<f64>().ok()?,
+    );
+    if !float {
+        left = left.trunc();
+        right = right.trunc();
+    }
+    let result = match op {
+        FormatArithmetic::Add => left + right,
+        FormatArithmetic::Subtract => left - right,
+        FormatArithmetic::Multiply => left * right,
+        FormatArithmetic::Divide if right == 0.0 => return None,
+        FormatArithmetic::Divide => left / right,
+        FormatArithmetic::Modulus if right == 0.0 => return None,
+        FormatArithmetic::Modulus => left % right,
+        FormatArithmetic::Equal => (left == right) as u8 as f64,
+        FormatArithmetic::NotEqual => (left != right) as u8 as f64,
+        FormatArithmetic::Less => (left < right) as u8 as f64,
+        FormatArithmetic::Greater => (left > right) as u8 as f64,
+        FormatArithmetic::LessOrEqual => (left <= right) as u8 as f64,
+        FormatArithmetic::GreaterOrEqual => (left >= right) as u8 as f64,
+    };
+    Some(match float {
+        true => format!("{:.*}", decimals, result),
+        false => format!("{}", result.trunc() as i64),
+    })
+}
+
+// `=N`, positive keeps the start, negative the end of the string
+fn truncate(value: &str, limit: isize, marker: &Option<String>) -> String {
+    let len = value.chars().count();
+    let limit_abs = limit.unsigned_abs();
+    if len <= limit_abs {
+        return value.to_string();
+    }
+    let marker = marker.as_deref().unwrap_or_default();
+    match limit >= 0 {
+        true => value.chars().take(limit_abs).collect::<String>() + marker,
+        false => marker.to_string() + &value.chars().skip(len - limit_abs).collect::<String>(),
+    }
+}
+
+// `pN`, positive pads on the right (left aligned), negative on the left (right aligned)
+fn pad(value: &str, width: isize) -> String {
+    let len = value.chars().count();
+    let limit = width.unsigned_abs().min(FORMAT_PAD_LIMIT);
+    let padding = " ".repeat(limit.saturating_sub(len));
+    match width >= 0 {
+        true => value.to_string() + &padding,
+        false => padding + value,
+    }
+}
+
+fn substitute(value: &str, pattern: &str, replacement: &str, ignore_case: bool) -> String {
+    if pattern.is_empty() {
+        return value.to_string();
+    }
+    if !ignore_case {
+        return value.replace(pattern, replacement);
+    }
+    // ASCII case folding only, so matches are found at offsets of `value` itself
+    let mut output = String::new();
+    let (mut start, mut i) = (0, 0);
+    while i + pattern.len() <= value.len() {
+        match value.is_char_boundary(i)
+            && value.as_bytes()[i..i + pattern.len()].eq_ignore_ascii_case(pattern.as_bytes())
+        {
+            true => {
+                output.push_str(&value[start..i]);
+                output.push_str(replacement);
+                i += pattern.len();
+                start = i;
+            }
+            false => i += 1,
+        }
+    }
+    output.push_str(&value[start..]);
+    output
+}
+
+// same characters as tmux `format_quote_shell()`
+fn quote_shell(value: &str) -> String {
+    let mut output = String::new();
+    for c in value.chars() {
+        if "|&;<>()$`\\\"'*?[# =%".contains(c) {
+            output.push('\\');
+        }
+        output.push(c);
+    }
+    output
+}
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+// broken down UTC time (year, month 1-12, day, hour, minute, second, weekday 0-6)
+fn utc_time(t: i64) -> (i64, usize, i64, i64, i64, i64, usize) {
+    let (days, secs) = (t.div_euclid(86400), t.rem_euclid(86400));
+    // days to civil date, H. Hinnant `civil_from_days()`
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    let weekday = (days + 4).rem_euclid(7);
+    (
+        year,
+        month as usize,
+        day,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+        weekday as usize,
+    )
+}
+
+// subset of `strftime(3)`, `value` is a unix timestamp
+fn format_time(value: &str, format: &str) -> String {
+    let t = match value.trim().parse::<i64>() {
+        Ok(t) => t,
+        Err(_) => return String::new(),
+    };
+    let (year, month, day, hour, minute, second, weekday) = utc_time(t);
+    let mut output = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('a') => output.push_str(WEEKDAYS[weekday]),
+            Some('b') | Some('h') => output.push_str(MONTHS[month - 1]),
+            Some('d') => output.push_str(&format!("{:02}", day)),
+            Some('e') => output.push_str(&format!("{:2}", day)),
+            Some('H') => output.push_str(&format!("{:02}", hour)),
+            Some('M') => output.push_str(&format!("{:02}", minute)),
+            Some('S') => output.push_str(&format!("{:02}", second)),
+            Some('m') => output.push_str(&format!("{:02}", month)),
+            Some('y') => output.push_str(&format!("{:02}", year % 100)),
+            Some('Y') => output.push_str(&year.to_string()),
+            Some('s') => output.push_str(&t.to_string()),
+            Some('%') => output.push('%'),
+            Some(c) => {
+                output.push('%');
+                output.push(c);
+            }
+            None => output.push('%'),
+        }
+    }
+    output
+}
+
+// same as tmux `format_pretty_time()`
+fn format_pretty_time(value: &str) -> String {
+    let t = match value.trim().parse::<i64>() {
+        Ok(t) => t,
+        Err(_) => return String::new(),
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64);
+    let age = now - t;
+    let (year, month, ..) = utc_time(t);
+    let (now_year, now_month, ..) = utc_time(now);
+
+    let format = if age < 24 * 3600 {
+        "%H:%M"
+    } else if (year == now_year && month == now_month) || age < 28 * 24 * 3600 {
+        "%a%d"
+    } else if (year == now_year && month < now_month) || (year == now_year - 1 && month > now_month)
+    {
+        "%d%b"
+    } else {
+        "%h%y"
+    };
+    format_time(value, format)
+}
@@ -0,0 +1,160 @@
+use crate::{FormatContext, Pane, PaneId, Session, Window};
+
+fn session(name: &str) -> Session {
+    let mut session = Session::new();
+    session.name = Some(name.to_string());
+    session.created = Some(86400 * 365 + 3600 * 13 + 60 * 5 + 7);
+    session
+}
+
+fn window(index: usize, name: &str) -> Window {
+    let mut window = Window::new();
+    window.index = Some(index);
+    window.name = Some(name.to_string());
+    window.active = Some(index == 0);
+    window
+}
+
+fn pane(id: usize, path: &str) -> Pane {
+    let mut pane = Pane::new();
+    pane.id = Some(PaneId(id));
+    pane.current_path = Some(path.to_string());
+    pane.title = Some("a very long pane title".to_string());
+    pane.width = Some(80);
+    pane
+}
+
+#[test]
+fn format_context_variables() {
+    let (session, window, pane) = (session("main"), window(0, "editor"), pane(3, "/home/user"));
+    let context = FormatContext::new()
+        .session(&session)
+        .window(&window)
+        .pane(&pane)
+        .value("@user", "value")
+        .value("window_name", "override");
+
+    assert_eq!(context.get("session_name").unwrap(), "main");
+    assert_eq!(context.get("window_active").unwrap(), "1");
+    assert_eq!(context.get("pane_id").unwrap(), "%3");
+    assert_eq!(context.get("@user").unwrap(), "value");
+    assert_eq!(context.get("window_name").unwrap(), "override");
+    assert_eq!(context.get("pane_pid"), None);
+    assert_eq!(context.get("no_such_variable"), None);
+
+    let output = context
+        .expand("#S:#{window_index} #{pane_id}#{unknown} ##")
+        .unwrap();
+    assert_eq!(output, "main:0 %3 #");
+    let output = context.expand("#[fg=red]#{@user}#(date)").unwrap();
+    assert_eq!(output, "#[fg=red]value");
+}
+
+#[test]
+fn format_context_conditionals() {
+    let (window, pane) = (window(1, "shell"), pane(3, "/home/user"));
+    let context = FormatContext::new().window(&window).pane(&pane);
+    let expand = |s: &str| context.expand(s).unwrap();
+
+    assert_eq!(expand("#{?window_active,yes,no}"), "no");
+    assert_eq!(expand("#{?pane_width,yes,no}"), "yes");
+    assert_eq!(expand("#{?unknown,yes,no}"), "no");
+    assert_eq!(expand("#{?window_active,a,pane_id,b,c}"), "b");
+    assert_eq!(expand("#{==:#{window_name},shell}"), "1");
+    assert_eq!(expand("#{!=:#{window_name},shell}"), "0");
+    assert_eq!(expand("#{<:a,b}"), "1");
+    assert_eq!(expand("#{||:#{window_active},1}"), "1");
+    assert_eq!(expand("#{&&:#{window_active},1}"), "0");
+    assert_eq!(expand("#{m:*ell,#{window_name}}"), "1");
+    assert_eq!(expand("#{m/i:SH*,#{window_name}}"), "1");
+    assert_eq!(expand("#{m/r:hel,#{window_name}}"), "1");
+}
+
+#[test]
+fn format_context_modifiers() {
+    let (session, pane) = (session("main"), pane(3, "/home/user/src"));
+    let context = FormatContext::new()
+        .session(&session)
+        .pane(&pane)
+        .value("@text", "\u{130} is IS");
+    let expand = |s: &str| context.expand(s).unwrap();
+
+    assert_eq!(expand("#{=6:pane_title}"), "a very");
+    assert_eq!(expand("#{=-5:pane_title}"), "title");
+    assert_eq!(expand("#{=/6/...:pane_title}"), "a very...");
+    assert_eq!(expand("#{=40:pane_title}"), "a very long pane title");
+    assert_eq!(expand("#{p6:session_name}|"), "main  |");
+    assert_eq!(expand("#{p-6:session_name}|"), "  main|");
+    assert_eq!(expand("#{=2;p4:session_name}|"), "ma  |");
+    assert_eq!(expand("#{p999999999:session_name}").len(), 10_000);
+    assert_eq!(expand("#{b:pane_current_path}"), "src");
+    assert_eq!(expand("#{d:pane_current_path}"), "/home/user");
+    assert_eq!(expand("#{s/very/not/:pane_title}"), "a not long pane title");
+    assert_eq!(
+        expand("#{s/VERY/not/i:pane_title}"),
+        "a not long pane title"
+    );
+    assert_eq!(expand("#{s/is/x/i:@text}"), "\u{130} x x");
+    assert_eq!(expand("#{q:pane_title}"), "a\\ very\\ long\\ pane\\ title");
+    assert_eq!(expand("#{n:session_name}"), "4");
+    assert_eq!(expand("#{l:#{session_name}}"), "#{session_name}");
+    assert_eq!(expand("#{t:session_created}"), "Fri Jan  1 13:05:07 1971");
+    assert_eq!(
+        expand("#{t/f/%Y-%m-%d %H#:%M:session_created}"),
+        "1971-01-01 13:05"
+    );
+}
+
+#[test]
+fn format_context_expand_loop() {
+    let session = session("main");
+    let context = FormatContext::new()
+        .session(&session)
+        .value("@name", "#{session_name}")
+        .value("@a", "#{E:@a}")
+        .value("@b", "#{E:@c}")
+        .value("@c", "#{E:@b}");
+
+    assert_eq!(context.expand("#{E:@name}").unwrap(), "main");
+    // self-referential values stop at the loop limit
+    assert_eq!(context.expand("#{E:@a}").unwrap(), "");
+    assert_eq!(context.expand("#{E:@b}|#{T:@c}").unwrap(), "|");
+}
+
+#[test]
+fn format_context_arithmetic() {
+    let pane = pane(3, "/");
+    let context = FormatContext::new().pane(&pane);
+    let expand = |s: &str| context.expand(s).unwrap();
+
+    assert_eq!(expand("#{e|+|:#{pane_width},2}"), "82");
+    assert_eq!(expand("#{e|/|:7,2}"), "3");
+    assert_eq!(expand("#{e|/|f|2:7,2}"), "3.50");
+    assert_eq!(expand("#{e|m|:7,4}"), "3");
+    assert_eq!(expand("#{e|<|:#{pane_width},100}"), "1");
+    assert_eq!(expand("#{e|/|:1,0}"), "");
+    assert_eq!(expand("#{e|+|:a,1}"), "");
+}
+
+#[test]
+fn format_context_loops() {
+    let sessions = [session("main"), session("work")];
+    let windows = [window(0, "editor"), window(1, "shell")];
+    let context = FormatContext::new()
+        .session(&sessions[1])
+        .window(&windows[0])
+        .sessions(&sessions)
+        .windows(&windows);
+    let expand = |s: &str| context.expand(s).unwrap();
+
+    assert_eq!(expand("#{S:#{session_name} }"), "main work ");
+    assert_eq!(
+        expand("#{S:#{session_name} ,[#{session_name}] }"),
+        "main [work] "
+    );
+    assert_eq!(
+        expand("#{W:#{window_index}:#{window_name} ,*#{window_name} }"),
+        "*editor 1:shell "
+    );
+    assert_eq!(expand("#{P:#{pane_id}}"), "");
+}
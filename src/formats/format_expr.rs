@@ -0,0 +1,601 @@
+use super::Variable;
+use std::fmt;
+
+/// Comparison operator (`#{==:a,b}`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatComparison {
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `>`
+    Greater,
+    /// `<=`
+    LessOrEqual,
+    /// `>=`
+    GreaterOrEqual,
+}
+
+impl fmt::Display for FormatComparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = match self {
+            Self::Equal => "==",
+            Self::NotEqual => "!=",
+            Self::Less => "<",
+            Self::Greater => ">",
+            Self::LessOrEqual => "<=",
+            Self::GreaterOrEqual => ">=",
+        };
+        write!(f, "{}", output)
+    }
+}
+
+/// Logical operator (`#{||:a,b}`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatLogical {
+    /// `||`
+    Or,
+    /// `&&`
+    And,
+}
+
+impl fmt::Display for FormatLogical {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = match self {
+            Self::Or => "||",
+            Self::And => "&&",
+        };
+        write!(f, "{}", output)
+    }
+}
+
+/// Arithmetic operator (`#{e|+|:a,b}`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatArithmetic {
+    /// `+`
+    Add,
+    /// `-`
+    Subtract,
+    /// `*`
+    Multiply,
+    /// `/`
+    Divide,
+    /// `m`
+    Modulus,
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `>`
+    Greater,
+    /// `<=`
+    LessOrEqual,
+    /// `>=`
+    GreaterOrEqual,
+}
+
+impl fmt::Display for FormatArithmetic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = match self {
+            Self::Add => "+",
+            Self::Subtract => "-",
+            Self::Multiply => "*",
+            Self::Divide => "/",
+            Self::Modulus => "m",
+            Self::Equal => "==",
+            Self::NotEqual => "!=",
+            Self::Less => "<",
+            Self::Greater => ">",
+            Self::LessOrEqual => "<=",
+            Self::GreaterOrEqual => ">=",
+        };
+        write!(f, "{}", output)
+    }
+}
+
+/// Loop over sessions, windows, panes or clients (`#{S:format}`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatLoop {
+    /// `S`
+    Sessions,
+    /// `W`
+    Windows,
+    /// `P`
+    Panes,
+    /// `L`
+    Clients,
+}
+
+impl fmt::Display for FormatLoop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = match self {
+            Self::Sessions => "S",
+            Self::Windows => "W",
+            Self::Panes => "P",
+            Self::Clients => "L",
+        };
+        write!(f, "{}", output)
+    }
+}
+
+/// Modifier applied to the value of an expression (`#{=10:pane_title}`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatModifier {
+    /// `=N` - limit to `N` characters, negative from the end, `=/N/marker` appends a marker
+    Truncate(isize, Option<String>),
+    /// `pN` - pad to `N` characters, positive left aligned, negative right aligned
+    Pad(isize),
+    /// `s/pattern/replacement/` - regular expression substitution (`i` flag - ignore case)
+    Substitute {
+        pattern: String,
+        replacement: String,
+        ignore_case: bool,
+    },
+    /// `t` - time as a string
+    Time,
+    /// `t/p` - time in a shorter, pretty form
+    TimePretty,
+    /// `t/f/format` - time in a custom `strftime(3)` format
+    TimeFormat(String),
+    /// `b` - basename
+    Basename,
+    /// `d` - dirname
+    Dirname,
+    /// `l` - literal, not expanded
+    Literal,
+    /// `E` - expand twice
+    Expand,
+    /// `T` - expand twice, including `strftime(3)` specifiers
+    ExpandTime,
+    /// `q` - escape shell special characters
+    Quote,
+    /// `n` - length
+    Length,
+    /// `w` - width
+    Width,
+}
+
+impl fmt::Display for FormatModifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Truncate(n, None) => write!(f, "={}", n),
+            Self::Truncate(n, Some(marker)) => write!(f, "=/{}/{}", n, escape_arg(marker)),
+            Self::Pad(n) => write!(f, "p{}", n),
+            Self::Substitute {
+                pattern,
+                replacement,
+                ignore_case,
+            } => write!(
+                f,
+                "s/{}/{}/{}",
+                escape_arg(pattern),
+                escape_arg(replacement),
+                if *ignore_case { "i" } else { "" }
+            ),
+            Self::Time => write!(f, "t"),
+            Self::TimePretty => write!(f, "t/p"),
+            Self::TimeFormat(format) => write!(f, "t/f/{}", escape_arg(format)),
+            Self::Basename => write!(f, "b"),
+            Self::Dirname => write!(f, "d"),
+            Self::Literal => write!(f, "l"),
+            Self::Expand => write!(f, "E"),
+            Self::ExpandTime => write!(f, "T"),
+            Self::Quote => write!(f, "q"),
+            Self::Length => write!(f, "n"),
+            Self::Width => write!(f, "w"),
+        }
+    }
+}
+
+// `:` ends the modifiers, inside of arguments it is escaped as `#:`
+fn escape_arg(arg: &str) -> String {
+    arg.replace(':', "#:")
+}
+
+/// Name used in `#{name}`
+///
+/// Known names are stored as [`Variable`], anything else (options, user options `@name`,
+/// variables not available in the enabled tmux version) as a string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatVariable {
+    Variable(Variable),
+    Other(String),
+}
+
+impl FormatVariable {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Variable(variable) => variable.name(),
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for FormatVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl From<Variable> for FormatVariable {
+    fn from(variable: Variable) -> Self {
+        match variable {
+            Variable::Custom(name) => Self::Other(name),
+            variable => Self::Variable(variable),
+        }
+    }
+}
+
+impl From<&str> for FormatVariable {
+    fn from(name: &str) -> Self {
+        match name.parse() {
+            Ok(variable) => Self::Variable(variable),
+            Err(_) => Self::Other(name.to_string()),
+        }
+    }
+}
+
+impl From<String> for FormatVariable {
+    fn from(name: String) -> Self {
+        match name.parse() {
+            Ok(variable) => Self::Variable(variable),
+            Err(_) => Self::Other(name),
+        }
+    }
+}
+
+/// Composable tmux format expression, rendered as a format string using [`fmt::Display`]
+///
+/// # Example
+///
+/// ```
+/// use tmux_interface::{FormatExpr, FormatModifier, Variable};
+///
+/// let expr = FormatExpr::conditional(
+///     Variable::WindowActive,
+///     FormatExpr::from(Variable::WindowName).modify(FormatModifier::Truncate(10, None)),
+///     "-",
+/// );
+/// assert_eq!(expr.to_string(), "#{?window_active,#{=10:window_name},-}");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatExpr {
+    /// Plain text, `#` (and `,`, `}` inside expressions) are escaped
+    Text(String),
+    /// Format string inserted as is
+    Raw(String),
+    /// Variable or option name (`#{name}`)
+    Variable(FormatVariable),
+    /// Style (`#[fg=red]`)
+    Style(String),
+    /// Shell command output (`#(uptime)`)
+    Shell(String),
+    /// Sequence of expressions
+    Concat(Vec<FormatExpr>),
+    /// `#{?condition,then,else}`
+    Conditional(Box<FormatExpr>, Box<FormatExpr>, Box<FormatExpr>),
+    /// `#{==:a,b}`
+    Comparison(FormatComparison, Box<FormatExpr>, Box<FormatExpr>),
+    /// `#{||:a,b}`
+    Logical(FormatLogical, Box<FormatExpr>, Box<FormatExpr>),
+    /// `#{m/ri:pattern,string}`
+    Match {
+        regex: bool,
+        ignore_case: bool,
+        pattern: Box<FormatExpr>,
+        string: Box<FormatExpr>,
+    },
+    /// `#{e|+|f|N:a,b}`
+    Arithmetic {
+        op: FormatArithmetic,
+        float: bool,
+        decimals: Option<usize>,
+        left: Box<FormatExpr>,
+        right: Box<FormatExpr>,
+    },
+    /// `#{S:format,current}`
+    Loop(FormatLoop, Box<FormatExpr>, Option<Box<FormatExpr>>),
+    /// `#{m1;m2:expr}`
+    Modified(Vec<FormatModifier>, Box<FormatExpr>),
+}
+
+impl FormatExpr {
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        Self::Text(text.into())
+    }
+
+    pub fn raw<S: Into<String>>(format: S) -> Self {
+        Self::Raw(format.into())
+    }
+
+    /// Variable or option by name (`pane_id`, `@user_option`)
+    pub fn var<S: Into<FormatVariable>>(name: S) -> Self {
+        Self::Variable(name.into())
+    }
+
+    pub fn concat<I: IntoIterator<Item = T>, T: Into<FormatExpr>>(exprs: I) -> Self {
+        Self::Concat(exprs.into_iter().map(Into::into).collect())
+    }
+
+    pub fn conditional<C, A, B>(condition: C, then: A, otherwise: B) -> Self
+    where
+        C: Into<FormatExpr>,
+        A: Into<FormatExpr>,
+        B: Into<FormatExpr>,
+    {
+        Self::Conditional(
+            Box::new(condition.into()),
+            Box::new(then.into()),
+            Box::new(otherwise.into()),
+        )
+    }
+
+    pub fn compare<A: Into<FormatExpr>, B: Into<FormatExpr>>(
+        op: FormatComparison,
+        a: A,
+        b: B,
+    ) -> Self {
+        Self::Comparison(op, Box::new(a.into()), Box::new(b.into()))
+    }
+
+    pub fn or<A: Into<FormatExpr>, B: Into<FormatExpr>>(a: A, b: B) -> Self {
+        Self::Logical(FormatLogical::Or, Box::new(a.into()), Box::new(b.into()))
+    }
+
+    pub fn and<A: Into<FormatExpr>, B: Into<FormatExpr>>(a: A, b: B) -> Self {
+        Self::Logical(FormatLogical::And, Box::new(a.into()), Box::new(b.into()))
+    }
+
+    /// `fnmatch(3)` pattern match (`#{m:pattern,string}`)
+    pub fn matches<P: Into<FormatExpr>, S: Into<FormatExpr>>(pattern: P, string: S) -> Self {
+        Self::Match {
+            regex: false,
+            ignore_case: false,
+            pattern: Box::new(pattern.into()),
+            string: Box::new(string.into()),
+        }
+    }
+
+    /// Integer arithmetic (`#{e|+|:a,b}`)
+    pub fn arithmetic<A: Into<FormatExpr>, B: Into<FormatExpr>>(
+        op: FormatArithmetic,
+        left: A,
+        right: B,
+    ) -> Self {
+        Self::Arithmetic {
+            op,
+            float: false,
+            decimals: None,
+            left: Box::new(left.into()),
+            right: Box::new(right.into()),
+        }
+    }
+
+    /// Loop over sessions, windows, panes or clients (`#{W:format}`)
+    pub fn each<F: Into<FormatExpr>>(kind: FormatLoop, format: F) -> Self {
+        Self::Loop(kind, Box::new(format.into()), None)
+    }
+
+    /// Loop with a separate format for the current item (`#{W:format,current}`)
+    pub fn each_current<F: Into<FormatExpr>, C: Into<FormatExpr>>(
+        kind: FormatLoop,
+        format: F,
+        current: C,
+    ) -> Self {
+        Self::Loop(
+            kind,
+            Box::new(format.into()),
+            Some(Box::new(current.into())),
+        )
+    }
+
+    /// Apply a modifier, modifiers of an already modified expression are combined
+    pub fn modify(self, modifier: FormatModifier) -> Self {
+        match self {
+            Self::Modified(mut modifiers, expr) => {
+                modifiers.push(modifier);
+                Self::Modified(modifiers, expr)
+            }
+            expr => Self::Modified(vec![modifier], Box::new(expr)),
+        }
+    }
+
+    /// All variables used in the expression, in order of appearance
+    pub fn variables(&self) -> Vec<&FormatVariable> {
+        let mut variables = Vec::new();
+        self.collect_variables(&mut variables);
+        variables
+    }
+
+    fn collect_variables<'a>(&'a self, variables: &mut Vec<&'a FormatVariable>) {
+        match self {
+            Self::Text(_) | Self::Raw(_) | Self::Style(_) | Self::Shell(_) => {}
+            Self::Variable(variable) => variables.push(variable),
+            Self::Concat(exprs) => exprs.iter().for_each(|e| e.collect_variables(variables)),
+            Self::Conditional(condition, then, otherwise) => {
+                condition.collect_variables(variables);
+                then.collect_variables(variables);
+                otherwise.collect_variables(variables);
+            }
+            Self::Comparison(_, a, b) | Self::Logical(_, a, b) => {
+                a.collect_variables(variables);
+                b.collect_variables(variables);
+            }
+            Self::Match {
+                pattern, string, ..
+            } => {
+                pattern.collect_variables(variables);
+                string.collect_variables(variables);
+            }
+            Self::Arithmetic { left, right, .. } => {
+                left.collect_variables(variables);
+                right.collect_variables(variables);
+            }
+            Self::Loop(_, format, current) => {
+                format.collect_variables(variables);
+                if let Some(current) = current {
+                    current.collect_variables(variables);
+                }
+            }
+            Self::Modified(_, expr) => expr.collect_variables(variables),
+        }
+    }
+
+    fn write_text(f: &mut fmt::Formatter, text: &str, nested: bool) -> fmt::Result {
+        for c in text.chars() {
+            match c {
+                '#' => write!(f, "##")?,
+                ',' | '}' if nested => write!(f, "#{}", c)?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        Ok(())
+    }
+
+    // `nested` - inside an expression argument, where `,` and `}` are separators
+    fn write(&self, f: &mut fmt::Formatter, nested: bool) -> fmt::Result {
+        match self {
+            Self::Text(text) => Self::write_text(f, text, nested),
+            Self::Raw(format) => write!(f, "{}", format),
+            Self::Variable(name) => write!(f, "#{{{}}}", name),
+            Self::Style(style) => write!(f, "#[{}]", style),
+            Self::Shell(command) => write!(f, "#({})", command),
+            Self::Concat(exprs) => {
+                for expr in exprs {
+                    expr.write(f, nested)?;
+                }
+                Ok(())
+            }
+            Self::Conditional(condition, then, otherwise) => {
+                write!(f, "#{{?")?;
+                condition.write_key(f)?;
+                write!(f, ",")?;
+                then.write(f, true)?;
+                write!(f, ",")?;
+                otherwise.write(f, true)?;
+                write!(f, "}}")
+            }
+            Self::Comparison(op, a, b) => Self::write_binary(f, op, a, b),
+            Self::Logical(op, a, b) => Self::write_binary(f, op, a, b),
+            Self::Match {
+                regex,
+                ignore_case,
+                pattern,
+                string,
+            } => {
+                write!(f, "#{{m")?;
+                if *regex || *ignore_case {
+                    write!(f, "/")?;
+                    if *regex {
+                        write!(f, "r")?;
+                    }
+                    if *ignore_case {
+                        write!(f, "i")?;
+                    }
+                }
+                write!(f, ":")?;
+                pattern.write(f, true)?;
+                write!(f, ",")?;
+                string.write(f, true)?;
+                write!(f, "}}")
+            }
+            Self::Arithmetic {
+                op,
+                float,
+                decimals,
+                left,
+                right,
+            } => {
+                write!(f, "#{{e|{}|", op)?;
+                if *float {
+                    write!(f, "f")?;
+                    if let Some(decimals) = decimals {
+                        write!(f, "|{}", decimals)?;
+                    }
+                }
+                write!(f, ":")?;
+                left.write(f, true)?;
+                write!(f, ",")?;
+                right.write(f, true)?;
+                write!(f, "}}")
+            }
+            Self::Loop(kind, format, current) => {
+                write!(f, "#{{{}:", kind)?;
+                format.write(f, true)?;
+                if let Some(current) = current {
+                    write!(f, ",")?;
+                    current.write(f, true)?;
+                }
+                write!(f, "}}")
+            }
+            Self::Modified(modifiers, expr) => {
+                let literal = modifiers.contains(&FormatModifier::Literal);
+                let modifiers = modifiers
+                    .iter()
+                    .map(|m| m.to_string())
+                    .collect::<Vec<String>>()
+                    .join(";");
+                write!(f, "#{{{}:", modifiers)?;
+                match expr.as_ref() {
+                    // literal value is not expanded, so not unescaped either
+                    Self::Text(text) if literal => write!(f, "{}", text)?,
+                    expr => expr.write_key(f)?,
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+
+    fn write_binary<O: fmt::Display>(
+        f: &mut fmt::Formatter,
+        op: O,
+        a: &FormatExpr,
+        b: &FormatExpr,
+    ) -> fmt::Result {
+        write!(f, "#{{{}:", op)?;
+        a.write(f, true)?;
+        write!(f, ",")?;
+        b.write(f, true)?;
+        write!(f, "}}")
+    }
+
+    // position where tmux expects a variable name (condition, modified value)
+    fn write_key(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Variable(name) => write!(f, "{}", name),
+            Self::Text(text) => write!(f, "#{{l:{}}}", text),
+            expr => expr.write(f, true),
+        }
+    }
+}
+
+impl fmt::Display for FormatExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, false)
+    }
+}
+
+impl From<Variable> for FormatExpr {
+    fn from(variable: Variable) -> Self {
+        match variable {
+            Variable::Custom(expr) if expr.contains("#{") => Self::Raw(expr),
+            variable => Self::Variable(variable.into()),
+        }
+    }
+}
+
+impl From<&str> for FormatExpr {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for FormatExpr {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
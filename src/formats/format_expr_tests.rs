@@ -0,0 +1,149 @@
+#[test]
+fn format_expr_text() {
+    use crate::FormatExpr;
+
+    assert_eq!(FormatExpr::text("a#b,c}").to_string(), "a##b,c}");
+    assert_eq!(FormatExpr::raw("#{pane_id}").to_string(), "#{pane_id}");
+    assert_eq!(FormatExpr::var("@user").to_string(), "#{@user}");
+    assert_eq!(
+        FormatExpr::concat(vec![FormatExpr::text("id: "), FormatExpr::var("pane_id")]).to_string(),
+        "id: #{pane_id}"
+    );
+}
+
+#[test]
+fn format_expr_conditional() {
+    use crate::{FormatComparison, FormatExpr, FormatLogical};
+
+    let expr = FormatExpr::conditional(FormatExpr::var("pane_active"), "a,b", "#}");
+    assert_eq!(expr.to_string(), "#{?pane_active,a#,b,###}}");
+
+    let expr = FormatExpr::conditional("1", "yes", "no");
+    assert_eq!(expr.to_string(), "#{?#{l:1},yes,no}");
+
+    let expr = FormatExpr::compare(
+        FormatComparison::LessOrEqual,
+        FormatExpr::var("pane_width"),
+        "80",
+    );
+    assert_eq!(expr.to_string(), "#{<=:#{pane_width},80}");
+
+    let expr = FormatExpr::conditional(
+        FormatExpr::and(
+            FormatExpr::var("pane_active"),
+            FormatExpr::var("window_active"),
+        ),
+        "*",
+        "",
+    );
+    assert_eq!(
+        expr.to_string(),
+        "#{?#{&&:#{pane_active},#{window_active}},*,}"
+    );
+    assert_eq!(
+        FormatExpr::Logical(
+            FormatLogical::Or,
+            Box::new("0".into()),
+            Box::new("1".into())
+        )
+        .to_string(),
+        "#{||:0,1}"
+    );
+}
+
+#[test]
+fn format_expr_match() {
+    use crate::FormatExpr;
+
+    let expr = FormatExpr::matches("*vim*", FormatExpr::var("pane_current_command"));
+    assert_eq!(expr.to_string(), "#{m:*vim*,#{pane_current_command}}");
+
+    let expr = FormatExpr::Match {
+        regex: true,
+        ignore_case: true,
+        pattern: Box::new("^v".into()),
+        string: Box::new(FormatExpr::var("pane_current_command")),
+    };
+    assert_eq!(expr.to_string(), "#{m/ri:^v,#{pane_current_command}}");
+}
+
+#[test]
+fn format_expr_arithmetic() {
+    use crate::{FormatArithmetic, FormatExpr};
+
+    let expr = FormatExpr::arithmetic(FormatArithmetic::Add, FormatExpr::var("pane_index"), "1");
+    assert_eq!(expr.to_string(), "#{e|+|:#{pane_index},1}");
+
+    let expr = FormatExpr::Arithmetic {
+        op: FormatArithmetic::Multiply,
+        float: true,
+        decimals: Some(4),
+        left: Box::new("5.5".into()),
+        right: Box::new("3".into()),
+    };
+    assert_eq!(expr.to_string(), "#{e|*|f|4:5.5,3}");
+}
+
+#[test]
+fn format_expr_loop() {
+    use crate::{FormatExpr, FormatLoop};
+
+    let expr = FormatExpr::each(FormatLoop::Windows, FormatExpr::var("window_name"));
+    assert_eq!(expr.to_string(), "#{W:#{window_name}}");
+
+    let expr = FormatExpr::each_current(
+        FormatLoop::Sessions,
+        FormatExpr::concat(vec![FormatExpr::var("session_name"), " ".into()]),
+        "*, ",
+    );
+    assert_eq!(expr.to_string(), "#{S:#{session_name} ,*#, }");
+}
+
+#[test]
+fn format_expr_modified() {
+    use crate::{FormatExpr, FormatModifier};
+
+    let expr = FormatExpr::var("pane_title")
+        .modify(FormatModifier::Truncate(-10, Some("...".to_string())))
+        .modify(FormatModifier::Pad(12));
+    assert_eq!(expr.to_string(), "#{=/-10/...;p12:pane_title}");
+
+    let expr = FormatExpr::var("pane_current_path").modify(FormatModifier::Substitute {
+        pattern: "foo".to_string(),
+        replacement: "bar".to_string(),
+        ignore_case: true,
+    });
+    assert_eq!(expr.to_string(), "#{s/foo/bar/i:pane_current_path}");
+
+    let expr =
+        FormatExpr::var("session_created").modify(FormatModifier::TimeFormat("%H:%M".into()));
+    assert_eq!(expr.to_string(), "#{t/f/%H#:%M:session_created}");
+
+    let expr = FormatExpr::text("#{x}").modify(FormatModifier::Literal);
+    assert_eq!(expr.to_string(), "#{l:#{x}}");
+
+    let expr = FormatExpr::text("abc").modify(FormatModifier::Length);
+    assert_eq!(expr.to_string(), "#{n:#{l:abc}}");
+
+    let modifiers = [
+        (FormatModifier::Time, "t"),
+        (FormatModifier::TimePretty, "t/p"),
+        (FormatModifier::Basename, "b"),
+        (FormatModifier::Dirname, "d"),
+        (FormatModifier::Expand, "E"),
+        (FormatModifier::ExpandTime, "T"),
+        (FormatModifier::Quote, "q"),
+        (FormatModifier::Width, "w"),
+    ];
+    for (modifier, s) in modifiers.iter() {
+        assert_eq!(modifier.to_string(), *s);
+    }
+}
+
+#[test]
+fn format_expr_variable() {
+    use crate::{FormatExpr, Variable};
+
+    let expr = FormatExpr::from(Variable::PaneTitle);
+    assert_eq!(expr.to_string(), "#{pane_title}");
+}
@@ -0,0 +1,409 @@
+use super::{
+    FormatArithmetic, FormatComparison, FormatExpr, FormatLogical, FormatLoop, FormatModifier,
+};
+use crate::Error;
+use std::str::FromStr;
+
+// `#S`, `#W`, ... single character aliases
+const FORMAT_ALIASES: [(char, &str); 9] = [
+    ('D', "pane_id"),
+    ('F', "window_flags"),
+    ('H', "host"),
+    ('I', "window_index"),
+    ('P', "pane_index"),
+    ('S', "session_name"),
+    ('T', "pane_title"),
+    ('W', "window_name"),
+    ('h', "host_short"),
+];
+
+// characters escaped by `#` (`##`, `#,`, `#}`, `#:`)
+const FORMAT_ESCAPED: &[u8] = b",#{}:";
+
+/// Parse a tmux format string (`status-left`, `window-status-format`, ...)
+///
+/// Unknown variable names are kept as [`FormatVariable::Other`](super::FormatVariable::Other),
+/// expressions with unsupported modifiers as [`FormatExpr::Raw`]
+///
+/// # Example
+///
+/// ```
+/// use tmux_interface::{FormatExpr, Variable};
+///
+/// let expr: FormatExpr = "#[bold]#S#[default] #{?window_zoomed_flag,Z,}".parse().unwrap();
+/// let names: Vec<&str> = expr.variables().iter().map(|v| v.name()).collect();
+/// assert_eq!(names, ["session_name", "window_zoomed_flag"]);
+/// ```
+impl FromStr for FormatExpr {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_format(s)
+    }
+}
+
+fn parse_format(s: &str) -> Result<FormatExpr, Error> {
+    let b = s.as_bytes();
+    let mut exprs = Vec::new();
+    let mut text = String::new();
+    let mut i = 0;
+
+    while i < b.len() {
+        if b[i] != b'#' {
+            let end = s[i..].find('#').map_or(s.len(), |n| i + n);
+            text.push_str(&s[i..end]);
+            i = end;
+            continue;
+        }
+
+        let expr = match b.get(i + 1) {
+            None => {
+                text.push('#');
+                i += 1;
+                continue;
+            }
+            Some(c) if FORMAT_ESCAPED.contains(c) && *c != b'{' => {
+                text.push(*c as char);
+                i += 2;
+                continue;
+            }
+            Some(b'{') => {
+                let end = skip(s, i, b"}").ok_or(Error::ParseFormat)?;
+                let expr = parse_expr(&s[i + 2..end])?;
+                i = end + 1;
+                expr
+            }
+            Some(b'[') => {
+                let end = s[i..].find(']').ok_or(Error::ParseFormat)? + i;
+                let expr = FormatExpr::Style(s[i + 2..end].to_string());
+                i = end + 1;
+                expr
+            }
+            Some(b'(') => {
+                let end = skip_parens(s, i + 1).ok_or(Error::ParseFormat)?;
+                let expr = FormatExpr::Shell(s[i + 2..end].to_string());
+                i = end + 1;
+                expr
+            }
+            Some(_) => {
+                let c = s[i + 1..].chars().next().ok_or(Error::ParseFormat)?;
+                i += 1 + c.len_utf8();
+                match FORMAT_ALIASES.iter().find(|(alias, _)| *alias == c) {
+                    Some((_, name)) => FormatExpr::var(*name),
+                    // tmux outputs unknown `#c` as is
+                    None => {
+                        text.push('#');
+                        text.push(c);
+                        continue;
+                    }
+                }
+            }
+        };
+
+        if !text.is_empty() {
+            exprs.push(FormatExpr::Text(text.split_off(0)));
+        }
+        exprs.push(expr);
+    }
+    if !text.is_empty() || exprs.is_empty() {
+        exprs.push(FormatExpr::Text(text));
+    }
+
+    Ok(match exprs.len() {
+        1 => exprs.remove(0),
+        _ => FormatExpr::Concat(exprs),
+    })
+}
+
+// find the first of `end` characters outside of nested `#{}`, same as tmux `format_skip()`
+fn skip(s: &str, start: usize, end: &[u8]) -> Option<usize> {
+    let b = s.as_bytes();
+    let mut brackets = 0;
+    let mut i = start;
+    while i < b.len() {
+        if b[i] == b'#' && b.get(i + 1) == Some(&b'{') {
+            brackets += 1;
+        }
+        if b[i] == b'#' && matches!(b.get(i + 1), Some(c) if FORMAT_ESCAPED.contains(c)) {
+            i += 2;
+            continue;
+        }
+        if b[i] == b'}' {
+            brackets -= 1;
+        }
+        if end.contains(&b[i]) && brackets <= 0 {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+// matching `)` of the `(` at `start`
+fn skip_parens(s: &str, start: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.bytes().enumerate().skip(start) {
+        match c {
+            b'(' => depth += 1,
+            b')' if depth == 1 => return Some(i),
+            b')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+// split expression arguments on `,` outside of nested `#{}`
+fn split_args(s: &str) -> Vec<&str> {
+    let mut args = Vec::new();
+    let mut start = 0;
+    while let Some(end) = skip(s, start, b",") {
+        args.push(&s[start..end]);
+        start = end + 1;
+    }
+    args.push(&s[start..]);
+    args
+}
+
+fn unescape_arg(arg: &str) -> String {
+    arg.replace("#:", ":")
+}
+
+// name position (condition, modified value) holds a variable name or a format
+fn parse_key(s: &str) -> Result<FormatExpr, Error> {
+    if s.contains('#') {
+        parse_format(s)
+    } else {
+        Ok(FormatExpr::var(s))
+    }
+}
+
+// modifier which turns the expression into an operator with arguments
+enum Operator {
+    Comparison(FormatComparison),
+    Logical(FormatLogical),
+    Match(bool, bool),
+    Arithmetic(FormatArithmetic, bool, Option<usize>),
+    Loop(FormatLoop),
+}
+
+enum Parsed {
+    Modifier(FormatModifier),
+    Operator(Operator),
+}
+
+impl Parsed {
+    fn comparison(op: FormatComparison) -> Self {
+        Self::Operator(Operator::Comparison(op))
+    }
+}
+
+// content of `#{...}`
+fn parse_expr(s: &str) -> Result<FormatExpr, Error> {
+    if let Some(s) = s.strip_prefix('?') {
+        return parse_conditional(&split_args(s));
+    }
+
+    let (operator, modifiers, rest) = match parse_modifiers(s) {
+        Some(parsed) => parsed,
+        // unsupported modifiers are kept as is
+        None if s.contains(':') => return Ok(FormatExpr::Raw(format!("#{{{}}}", s))),
+        None => return Ok(FormatExpr::var(s)),
+    };
+
+    let args = split_args(rest);
+    let expr = match operator {
+        None if modifiers.contains(&FormatModifier::Literal) => FormatExpr::Text(rest.to_string()),
+        None => parse_key(rest)?,
+        Some(Operator::Loop(kind)) => match args.as_slice() {
+            [format] => FormatExpr::each(kind, parse_format(format)?),
+            [format, current] => {
+                FormatExpr::each_current(kind, parse_format(format)?, parse_format(current)?)
+            }
+            _ => return Err(Error::ParseFormat),
+        },
+        Some(operator) => {
+            let (a, b) = match args.as_slice() {
+                [a, b] => (Box::new(parse_format(a)?), Box::new(parse_format(b)?)),
+                _ => return Err(Error::ParseFormat),
+            };
+            match operator {
+                Operator::Comparison(op) => FormatExpr::Comparison(op, a, b),
+                Operator::Logical(op) => FormatExpr::Logical(op, a, b),
+                Operator::Match(regex, ignore_case) => FormatExpr::Match {
+                    regex,
+                    ignore_case,
+                    pattern: a,
+                    string: b,
+                },
+                Operator::Arithmetic(op, float, decimals) => FormatExpr::Arithmetic {
+                    op,
+                    float,
+                    decimals,
+                    left: a,
+                    right: b,
+                },
+                Operator::Loop(_) => unreachable!(),
+            }
+        }
+    };
+
+    Ok(match modifiers.is_empty() {
+        true => expr,
+        false => FormatExpr::Modified(modifiers, Box::new(expr)),
+    })
+}
+
+// `#{?cond,a,b}` or `#{?cond1,a,cond2,b,c}`
+fn parse_conditional(args: &[&str]) -> Result<FormatExpr, Error> {
+    let otherwise = match args.len() {
+        0 | 1 => return Err(Error::ParseFormat),
+        2 => FormatExpr::Text(String::new()),
+        3 => parse_format(args[2])?,
+        _ => parse_conditional(&args[2..])?,
+    };
+    Ok(FormatExpr::Conditional(
+        Box::new(parse_key(args[0])?),
+        Box::new(parse_format(args[1])?),
+        Box::new(otherwise),
+    ))
+}
+
+// `m1;m2:rest`, `None` if `s` doesn't start with supported modifiers
+fn parse_modifiers(s: &str) -> Option<(Option<Operator>, Vec<FormatModifier>, &str)> {
+    let mut operator = None;
+    let mut modifiers = Vec::new();
+    let mut i = 0;
+
+    loop {
+        let m = &s[i..];
+        let (len, parsed) = match m.get(..2) {
+            Some("||") => (2, Parsed::Operator(Operator::Logical(FormatLogical::Or))),
+            Some("&&") => (2, Parsed::Operator(Operator::Logical(FormatLogical::And))),
+            Some("==") => (2, Parsed::comparison(FormatComparison::Equal)),
+            Some("!=") => (2, Parsed::comparison(FormatComparison::NotEqual)),
+            Some("<=") => (2, Parsed::comparison(FormatComparison::LessOrEqual)),
+            Some(">=") => (2, Parsed::comparison(FormatComparison::GreaterOrEqual)),
+            _ => {
+                let (len, args) = parse_modifier_args(s, i)?;
+                (len, parse_modifier(&m[..1], &args)?)
+            }
+        };
+        match parsed {
+            Parsed::Modifier(modifier) => modifiers.push(modifier),
+            // only one operator per expression
+            Parsed::Operator(_) if operator.is_some() => return None,
+            Parsed::Operator(op) => operator = Some(op),
+        }
+
+        i += len;
+        match s.as_bytes().get(i) {
+            Some(b';') => i += 1,
+            Some(b':') => return Some((operator, modifiers, &s[i + 1..])),
+            _ => return None,
+        }
+    }
+}
+
+// single character modifier `name` with its arguments
+fn parse_modifier(name: &str, args: &[&str]) -> Option<Parsed> {
+    let modifier = match (name, args) {
+        ("<", []) => return Some(Parsed::comparison(FormatComparison::Less)),
+        (">", []) => return Some(Parsed::comparison(FormatComparison::Greater)),
+        ("S", []) => return Some(Parsed::Operator(Operator::Loop(FormatLoop::Sessions))),
+        ("W", []) => return Some(Parsed::Operator(Operator::Loop(FormatLoop::Windows))),
+        ("P", []) => return Some(Parsed::Operator(Operator::Loop(FormatLoop::Panes))),
+        ("L", []) => return Some(Parsed::Operator(Operator::Loop(FormatLoop::Clients))),
+        ("m", []) => return Some(Parsed::Operator(Operator::Match(false, false))),
+        ("m", [flags]) if flags.chars().all(|c| c == 'r' || c == 'i') => {
+            let op = Operator::Match(flags.contains('r'), flags.contains('i'));
+            return Some(Parsed::Operator(op));
+        }
+        ("e", [op, flags @ ..]) => {
+            let op = parse_arithmetic(op)?;
+            let op = match flags {
+                [] => Operator::Arithmetic(op, false, None),
+                [f] if *f == "f" => Operator::Arithmetic(op, true, None),
+                [f, n] if *f == "f" => Operator::Arithmetic(op, true, Some(n.parse().ok()?)),
+                _ => return None,
+            };
+            return Some(Parsed::Operator(op));
+        }
+        ("=", [n]) => FormatModifier::Truncate(n.parse().ok()?, None),
+        ("=", [n, marker]) => FormatModifier::Truncate(n.parse().ok()?, Some(unescape_arg(marker))),
+        ("p", [n]) => FormatModifier::Pad(n.parse().ok()?),
+        ("s", [pattern, replacement]) => FormatModifier::Substitute {
+            pattern: unescape_arg(pattern),
+            replacement: unescape_arg(replacement),
+            ignore_case: false,
+        },
+        ("s", [pattern, replacement, flags]) if *flags == "i" => FormatModifier::Substitute {
+            pattern: unescape_arg(pattern),
+            replacement: unescape_arg(replacement),
+            ignore_case: true,
+        },
+        ("t", []) => FormatModifier::Time,
+        ("t", [p]) if *p == "p" => FormatModifier::TimePretty,
+        ("t", [f, format]) if *f == "f" => FormatModifier::TimeFormat(unescape_arg(format)),
+        ("b", []) => FormatModifier::Basename,
+        ("d", []) => FormatModifier::Dirname,
+        ("l", []) => FormatModifier::Literal,
+        ("E", []) => FormatModifier::Expand,
+        ("T", []) => FormatModifier::ExpandTime,
+        ("q", []) => FormatModifier::Quote,
+        ("n", []) => FormatModifier::Length,
+        ("w", []) => FormatModifier::Width,
+        _ => return None,
+    };
+    Some(Parsed::Modifier(modifier))
+}
+
+// length of the modifier at `start` and its arguments (`=10`, `s/a/b/`, `e|+|f|2`)
+fn parse_modifier_args(s: &str, start: usize) -> Option<(usize, Vec<&str>)> {
+    let b = s.as_bytes();
+    let next = *b.get(start + 1)?;
+    if next == b':' || next == b';' {
+        return Some((1, Vec::new()));
+    }
+    if !b"mst=pe".contains(&b[start]) {
+        return None;
+    }
+
+    // single argument (`=10`, `p-5`)
+    if !next.is_ascii_punctuation() || next == b'-' {
+        let end = skip(s, start + 1, b":;")?;
+        return Some((end - start, vec![&s[start + 1..end]]));
+    }
+
+    // arguments separated by a punctuation character (`s/a/b/`)
+    let mut args = Vec::new();
+    let mut i = start + 2;
+    loop {
+        let end = skip(s, i, &[next, b':', b';'])?;
+        if b[end] != next {
+            if end > i {
+                args.push(&s[i..end]);
+            }
+            return Some((end - start, args));
+        }
+        args.push(&s[i..end]);
+        i = end + 1;
+    }
+}
+
+fn parse_arithmetic(op: &str) -> Option<FormatArithmetic> {
+    Some(match op {
+        "+" => FormatArithmetic::Add,
+        "-" => FormatArithmetic::Subtract,
+        "*" => FormatArithmetic::Multiply,
+        "/" => FormatArithmetic::Divide,
+        "m" | "%" => FormatArithmetic::Modulus,
+        "==" => FormatArithmetic::Equal,
+        "!=" => FormatArithmetic::NotEqual,
+        "<" => FormatArithmetic::Less,
+        ">" => FormatArithmetic::Greater,
+        "<=" => FormatArithmetic::LessOrEqual,
+        ">=" => FormatArithmetic::GreaterOrEqual,
+        _ => return None,
+    })
+}
@@ -0,0 +1,192 @@
+#[test]
+fn parse_format_text() {
+    use crate::{FormatExpr, FormatVariable, Variable};
+
+    let expr: FormatExpr = "a ## b #, #}".parse().unwrap();
+    assert_eq!(expr, FormatExpr::text("a # b , }"));
+
+    let expr: FormatExpr = "#[fg=red,bold]#S:#I #(uptime | cut -d(,) -f1)#x"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::Concat(vec![
+            FormatExpr::Style("fg=red,bold".to_string()),
+            FormatExpr::Variable(FormatVariable::Variable(Variable::SessionName)),
+            FormatExpr::text(":"),
+            FormatExpr::Variable(FormatVariable::Variable(Variable::WindowIndex)),
+            FormatExpr::text(" "),
+            FormatExpr::Shell("uptime | cut -d(,) -f1".to_string()),
+            FormatExpr::text("#x"),
+        ])
+    );
+    assert_eq!(
+        expr.to_string(),
+        "#[fg=red,bold]#{session_name}:#{window_index} #(uptime | cut -d(,) -f1)##x"
+    );
+
+    assert!("#{pane_id".parse::<FormatExpr>().is_err());
+    assert!("#[bold".parse::<FormatExpr>().is_err());
+}
+
+#[test]
+fn parse_format_variables() {
+    use crate::{FormatExpr, FormatVariable, Variable};
+
+    let expr: FormatExpr = "#{pane_id} #{@user} #{no_such_variable}".parse().unwrap();
+    assert_eq!(
+        expr.variables(),
+        vec![
+            &FormatVariable::Variable(Variable::PaneId),
+            &FormatVariable::Other("@user".to_string()),
+            &FormatVariable::Other("no_such_variable".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn parse_format_conditional() {
+    use crate::{FormatComparison, FormatExpr, Variable};
+
+    let expr: FormatExpr = "#{?window_active,#[bold]a#,b,}".parse().unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::conditional(
+            Variable::WindowActive,
+            FormatExpr::concat(vec![FormatExpr::Style("bold".to_string()), "a,b".into()]),
+            "",
+        )
+    );
+
+    let expr: FormatExpr = "#{?#{==:#{pane_current_command},vim},editor,#{?pane_dead,dead,other}}"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::conditional(
+            FormatExpr::compare(FormatComparison::Equal, Variable::PaneCurrentCommand, "vim"),
+            "editor",
+            FormatExpr::conditional(Variable::PaneDead, "dead", "other"),
+        )
+    );
+
+    // multiple conditions
+    let expr: FormatExpr = "#{?pane_dead,dead,pane_active,active,other}"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::conditional(
+            Variable::PaneDead,
+            "dead",
+            FormatExpr::conditional(Variable::PaneActive, "active", "other"),
+        )
+    );
+}
+
+#[test]
+fn parse_format_operators() {
+    use crate::{FormatArithmetic, FormatExpr, FormatLoop, Variable};
+
+    let expr: FormatExpr = "#{||:#{pane_dead},#{&&:1,0}}".parse().unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::or(Variable::PaneDead, FormatExpr::and("1", "0"))
+    );
+
+    let expr: FormatExpr = "#{m/ri:^v,#{pane_current_command}}".parse().unwrap();
+    assert_eq!(expr.to_string(), "#{m/ri:^v,#{pane_current_command}}");
+    let expr: FormatExpr = "#{m:*vim*,#{pane_current_command}}".parse().unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::matches("*vim*", Variable::PaneCurrentCommand)
+    );
+
+    let expr: FormatExpr = "#{e|+|:#{pane_index},1}".parse().unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::arithmetic(FormatArithmetic::Add, Variable::PaneIndex, "1")
+    );
+    let expr: FormatExpr = "#{e|*|f|4:5.5,3}".parse().unwrap();
+    assert_eq!(expr.to_string(), "#{e|*|f|4:5.5,3}");
+
+    let expr: FormatExpr = "#{W:#{window_name} ,#[bold]#{window_name} }"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        expr.to_string(),
+        "#{W:#{window_name} ,#[bold]#{window_name} }"
+    );
+    assert!(matches!(
+        expr,
+        FormatExpr::Loop(FormatLoop::Windows, _, Some(_))
+    ));
+
+    assert!("#{==:a}".parse::<FormatExpr>().is_err());
+}
+
+#[test]
+fn parse_format_modifiers() {
+    use crate::{FormatExpr, FormatModifier, Variable};
+
+    let expr: FormatExpr = "#{=/-10/...;p12:pane_title}".parse().unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::from(Variable::PaneTitle)
+            .modify(FormatModifier::Truncate(-10, Some("...".to_string())))
+            .modify(FormatModifier::Pad(12))
+    );
+
+    let expr: FormatExpr = "#{=-5:pane_title}".parse().unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::from(Variable::PaneTitle).modify(FormatModifier::Truncate(-5, None))
+    );
+
+    let expr: FormatExpr = "#{s/foo/bar/i:pane_current_path}".parse().unwrap();
+    assert_eq!(expr.to_string(), "#{s/foo/bar/i:pane_current_path}");
+
+    let expr: FormatExpr = "#{t/f/%H#:%M:session_created}".parse().unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::from(Variable::SessionCreated)
+            .modify(FormatModifier::TimeFormat("%H:%M".to_string()))
+    );
+
+    let expr: FormatExpr = "#{l:#{pane_id}}".parse().unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::text("#{pane_id}").modify(FormatModifier::Literal)
+    );
+    assert_eq!(expr.to_string(), "#{l:#{pane_id}}");
+
+    let expr: FormatExpr = "#{b;q:#{pane_current_path}}".parse().unwrap();
+    assert_eq!(
+        expr,
+        FormatExpr::from(Variable::PaneCurrentPath)
+            .modify(FormatModifier::Basename)
+            .modify(FormatModifier::Quote)
+    );
+    assert_eq!(expr.to_string(), "#{b;q:pane_current_path}");
+
+    // unsupported modifiers are kept as is
+    let expr: FormatExpr = "#{a:65}".parse().unwrap();
+    assert_eq!(expr, FormatExpr::raw("#{a:65}"));
+}
+
+#[test]
+fn parse_format_round_trip() {
+    use crate::FormatExpr;
+
+    let formats = [
+        "#{?client_prefix,#[reverse],}#S#[default] #{=21:pane_title}",
+        "#{?window_zoomed_flag,#[fg=red]Z,}#{window_index}:#{window_name}#{window_flags}",
+        "#{?#{&&:#{pane_active},#{window_active}},*,-} #{p-10:#{e|-|:#{pane_width},2}}",
+        "#{S:#{?session_attached,+,-}#{session_name} }",
+    ];
+    for format in formats.iter() {
+        let expr: FormatExpr = format.parse().unwrap();
+        let reparsed: FormatExpr = expr.to_string().parse().unwrap();
+        assert_eq!(expr, reparsed);
+    }
+}
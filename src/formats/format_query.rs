@@ -0,0 +1,113 @@
+use super::FORMATS_DELIMITED_SEPARATOR;
+use crate::{ClientName, Error, FormatsOutput, PaneId, SessionId, WindowId};
+#[cfg(feature = "tmux_1_6")]
+use crate::{Layout, PaneTabs, SessionStack, WindowFlags};
+
+/// Struct queried from tmux using one format, a field per value
+///
+/// Usually derived using `#[derive(TmuxFormat)]` (`derive` feature), see
+/// [`QueryCtl`](crate::QueryCtl) for fetching it
+pub trait FormatQuery: Sized {
+    /// Formats of the fields (e.g. `#{pane_id}`, `#{@user_option}`, `#{pane_width}x#{pane_height}`)
+    fn formats() -> Vec<String>;
+
+    /// Struct from the values of the fields, in the order of [`FormatQuery::formats`]
+    fn from_values(values: &[&str]) -> Result<Self, Error>;
+
+    /// Format querying all fields at once, see [`Formats::delimited`](crate::Formats::delimited)
+    fn format() -> String {
+        Self::formats()
+            .iter()
+            .map(|f| match cfg!(feature = "tmux_3_2") {
+                // `n:` expands nested formats only
+                true if f.contains("#{") => format!("#{{n:{}}}:{}", f, f),
+                true => format!("#{{n:#{{l:{}}}}}:{}", f, f),
+                false => f.to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join(&FORMATS_DELIMITED_SEPARATOR.to_string())
+    }
+
+    /// Parse one record of a [`FormatQuery::format`] query
+    fn from_record(record: &str) -> Result<Self, Error> {
+        Self::from_values(&FormatsOutput::delimited_values(record)?)
+    }
+
+    /// Parse all records of a [`FormatQuery::format`] query (e.g. `list-panes -F` output)
+    fn from_output(output: &str) -> Result<Vec<Self>, Error> {
+        FormatsOutput::delimited_records(output)?
+            .into_iter()
+            .map(Self::from_record)
+            .collect()
+    }
+}
+
+/// Field types of a [`FormatQuery`] struct
+///
+/// `Option<T>` is `None` if the value is empty or can not be parsed (e.g. variable not
+/// supported by the running tmux), `Vec<T>` is parsed from a comma separated list
+pub trait FromFormatValue: Sized {
+    fn from_format_value(s: &str) -> Result<Self, Error>;
+}
+
+impl FromFormatValue for String {
+    fn from_format_value(s: &str) -> Result<Self, Error> {
+        Ok(s.to_string())
+    }
+}
+
+impl FromFormatValue for bool {
+    fn from_format_value(s: &str) -> Result<Self, Error> {
+        Ok(s.parse::<usize>()? == 1)
+    }
+}
+
+impl<T: FromFormatValue> FromFormatValue for Option<T> {
+    fn from_format_value(s: &str) -> Result<Self, Error> {
+        match s.is_empty() {
+            true => Ok(None),
+            false => Ok(T::from_format_value(s).ok()),
+        }
+    }
+}
+
+impl<T: FromFormatValue> FromFormatValue for Vec<T> {
+    fn from_format_value(s: &str) -> Result<Self, Error> {
+        match s.is_empty() {
+            true => Ok(Vec::new()),
+            false => s.split(',').map(T::from_format_value).collect(),
+        }
+    }
+}
+
+// types parsed by `FromStr` with errors convertible into `Error`
+macro_rules! impl_from_format_value {
+    ($($t:ty),*) => {
+        $(
+            impl FromFormatValue for $t {
+                fn from_format_value(s: &str) -> Result<Self, Error> {
+                    s.parse::<$t>().map_err(Error::from)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_format_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_from_format_value!(SessionId, WindowId, PaneId, ClientName);
+#[cfg(feature = "tmux_1_6")]
+impl_from_format_value!(Layout, PaneTabs, SessionStack, WindowFlags);
+
+macro_rules! impl_from_format_value_other {
+    ($($t:ty),*) => {
+        $(
+            impl FromFormatValue for $t {
+                fn from_format_value(s: &str) -> Result<Self, Error> {
+                    s.parse::<$t>().map_err(|_| Error::ParseFormatValue)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_format_value_other!(f32, f64, char);
@@ -0,0 +1,133 @@
+use crate::{Error, FormatQuery, FromFormatValue, PaneId};
+
+#[derive(Debug, PartialEq)]
+struct MyPane {
+    id: PaneId,
+    active: bool,
+    project: Option<String>,
+    size: String,
+}
+
+impl FormatQuery for MyPane {
+    fn formats() -> Vec<String> {
+        vec![
+            "#{pane_id}".to_string(),
+            "#{pane_active}".to_string(),
+            "#{@project}".to_string(),
+            "#{pane_width}x#{pane_height}".to_string(),
+        ]
+    }
+
+    fn from_values(values: &[&str]) -> Result<Self, Error> {
+        if values.len() != 4 {
+            return Err(Error::ParseFormatsOutput);
+        }
+        Ok(MyPane {
+            id: PaneId::from_format_value(values[0])?,
+            active: bool::from_format_value(values[1])?,
+            project: Option::from_format_value(values[2])?,
+            size: String::from_format_value(values[3])?,
+        })
+    }
+}
+
+#[test]
+fn format() {
+    use crate::FORMATS_DELIMITED_SEPARATOR;
+
+    #[cfg(feature = "tmux_3_2")]
+    let format_orig = [
+        "#{n:#{pane_id}}:#{pane_id}",
+        "#{n:#{pane_active}}:#{pane_active}",
+        "#{n:#{@project}}:#{@project}",
+        "#{n:#{pane_width}x#{pane_height}}:#{pane_width}x#{pane_height}",
+    ];
+    #[cfg(not(feature = "tmux_3_2"))]
+    let format_orig = [
+        "#{pane_id}",
+        "#{pane_active}",
+        "#{@project}",
+        "#{pane_width}x#{pane_height}",
+    ];
+    assert_eq!(
+        MyPane::format(),
+        format_orig.join(&FORMATS_DELIMITED_SEPARATOR.to_string())
+    );
+}
+
+#[test]
+fn from_output() {
+    use crate::FormatsOutput;
+
+    let pane0 = FormatsOutput::delimited_record(&["%1", "1", "", "80x24"]);
+    let pane1 = FormatsOutput::delimited_record(&["%2", "0", "foo", "40x12"]);
+    let output = format!("{}\n{}\n", pane0, pane1);
+
+    let panes = MyPane::from_output(&output).unwrap();
+    assert_eq!(
+        panes,
+        vec![
+            MyPane {
+                id: PaneId(1),
+                active: true,
+                project: None,
+                size: "80x24".to_string(),
+            },
+            MyPane {
+                id: PaneId(2),
+                active: false,
+                project: Some("foo".to_string()),
+                size: "40x12".to_string(),
+            },
+        ]
+    );
+}
+
+#[cfg(feature = "tmux_3_2")]
+#[test]
+fn from_record_delimiters() {
+    use crate::FormatsOutput;
+
+    let record = FormatsOutput::delimited_record(&["%1", "1", "a:b\u{1f}c\nd", "80x24"]);
+    let pane = MyPane::from_record(&record).unwrap();
+    assert_eq!(pane.project, Some("a:b\u{1f}c\nd".to_string()));
+}
+
+#[test]
+fn from_record_count_mismatch() {
+    use crate::FormatsOutput;
+
+    let record = FormatsOutput::delimited_record(&["%1", "1", ""]);
+    assert!(matches!(
+        MyPane::from_record(&record),
+        Err(Error::ParseFormatsOutput)
+    ));
+}
+
+#[test]
+fn from_format_value() {
+    use crate::{SessionId, WindowId};
+
+    assert!(bool::from_format_value("1").unwrap());
+    assert!(!bool::from_format_value("0").unwrap());
+    assert!(bool::from_format_value("").is_err());
+    assert_eq!(usize::from_format_value("42").unwrap(), 42);
+    assert!(usize::from_format_value("-1").is_err());
+    assert_eq!(f64::from_format_value("0.5").unwrap(), 0.5);
+    assert_eq!(SessionId::from_format_value("$3").unwrap(), SessionId(3));
+    assert_eq!(
+        Option::<WindowId>::from_format_value("@2").unwrap(),
+        Some(WindowId(2))
+    );
+    // empty or unparsable
+    assert_eq!(Option::<usize>::from_format_value("").unwrap(), None);
+    assert_eq!(Option::<usize>::from_format_value("x").unwrap(), None);
+    assert_eq!(
+        Vec::<usize>::from_format_value("3,2,1").unwrap(),
+        vec![3, 2, 1]
+    );
+    assert_eq!(
+        Vec::<String>::from_format_value("").unwrap(),
+        Vec::<String>::new()
+    );
+}
@@ -0,0 +1,32 @@
+use crate::{ClientName, PaneId, SessionId, WindowId};
+#[cfg(feature = "tmux_1_6")]
+use crate::{Layout, PaneTabs, SessionStack, WindowFlags};
+
+/// Field value of a [`Session`](crate::Session), [`Window`](crate::Window), ... as expanded by
+/// tmux
+pub(crate) trait FormatValue {
+    fn format_value(&self) -> String;
+}
+
+impl FormatValue for bool {
+    fn format_value(&self) -> String {
+        String::from(if *self { "1" } else { "0" })
+    }
+}
+
+macro_rules! impl_format_value {
+    ($($type:ty),*) => {
+        $(
+            impl FormatValue for $type {
+                fn format_value(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_format_value!(usize, u128, String, SessionId, WindowId, PaneId, ClientName);
+
+#[cfg(feature = "tmux_1_6")]
+impl_format_value!(Layout, PaneTabs, SessionStack, WindowFlags);
@@ -1,10 +1,15 @@
 use super::Variable;
 use std::fmt;
 
+/// Separator of [`Formats::delimited`] queries (ASCII unit separator)
+pub const FORMATS_DELIMITED_SEPARATOR: char = '\u{1f}';
+
 #[derive(Debug)]
 pub struct Formats {
     // XXX: string or char, join(), split() ?
     pub separator: char,
+    /// Prefix every value with its length in bytes (`#{n:variable}:#{variable}`)
+    pub length_prefix: bool,
     pub variables: Vec<Variable>,
 }
 
@@ -12,6 +17,7 @@ impl Default for Formats {
     fn default() -> Self {
         Formats {
             separator: '\'',
+            length_prefix: false,
             variables: Vec::new(),
         }
     }
@@ -22,7 +28,10 @@ impl fmt::Display for Formats {
         let output = self
             .variables
             .iter()
-            .map(|v| v.to_string())
+            .map(|v| match self.length_prefix {
+                true => format!("#{{n:{}}}:{}", v.name(), v),
+                false => v.to_string(),
+            })
             .collect::<Vec<String>>()
             .join(&self.separator.to_string());
         write!(f, "{}", output)
@@ -34,12 +43,32 @@ impl Formats {
         Default::default()
     }
 
+    /// Formats for querying multiple variables at once, parsed by [`FormatsOutput::delimited`]
+    ///
+    /// Values are separated by [`FORMATS_DELIMITED_SEPARATOR`] and, since tmux 3.2, prefixed
+    /// with their length, so values containing the separator or newlines are split correctly
+    ///
+    /// [`FormatsOutput::delimited`]: crate::FormatsOutput::delimited
+    pub fn delimited() -> Self {
+        Formats {
+            separator: FORMATS_DELIMITED_SEPARATOR,
+            length_prefix: cfg!(feature = "tmux_3_2"),
+            variables: Vec::new(),
+        }
+    }
+
     /// set separator character
     pub fn separator(&mut self, c: char) -> &mut Self {
         self.separator = c;
         self
     }
 
+    /// prefix values with their length (`#{n:}`, tmux 3.2)
+    pub fn length_prefix(&mut self, length_prefix: bool) -> &mut Self {
+        self.length_prefix = length_prefix;
+        self
+    }
+
     /// append with variable
     pub fn push(&mut self, variable: Variable) {
         self.variables.push(variable)
@@ -47,7 +76,12 @@ impl Formats {
 
     // TODO: check vec same size, return type?
     // XXX: mb from_string for default format too?
-    // pub fn custom_string(String) pub fn custom_usize(String)
+
+    /// append with [`Variable::Custom`] (e.g. user option `@my_state`, format expression)
+    pub fn custom<S: Into<String>>(&mut self, name: S) -> &mut Self {
+        self.push(Variable::Custom(name.into()));
+        self
+    }
 
     // tmux variables
 
@@ -1,12 +1,24 @@
-use super::VariableOutput;
+use super::{VariableOutput, FORMATS_DELIMITED_SEPARATOR};
+#[cfg(feature = "tmux_2_4")]
+use crate::ClientName;
+use crate::Error;
+use crate::PaneId;
+#[cfg(feature = "tmux_1_8")]
+use crate::SessionId;
 #[cfg(feature = "tmux_2_5")]
 use crate::SessionStack;
+#[cfg(feature = "tmux_1_7")]
+use crate::WindowId;
 #[cfg(feature = "tmux_1_6")]
 use crate::{Layout, PaneTabs, WindowFlags};
+use std::fmt;
+use std::str::FromStr;
 
 #[derive(Debug)]
 pub struct FormatsOutput<'a> {
     pub separator: char,
+    /// Values are prefixed with their length in bytes (`5:value`)
+    pub length_prefix: bool,
     pub variables: Vec<VariableOutput<'a>>,
 }
 
@@ -14,6 +26,7 @@ impl<'a> Default for FormatsOutput<'a> {
     fn default() -> Self {
         FormatsOutput {
             separator: '\'',
+            length_prefix: false,
             variables: Vec::new(),
         }
     }
@@ -24,27 +37,124 @@ impl<'a> FormatsOutput<'a> {
         Default::default()
     }
 
-    /// set separator character
+    /// Output of a [`Formats::delimited`](crate::Formats::delimited) query
+    pub fn delimited() -> Self {
+        FormatsOutput {
+            separator: FORMATS_DELIMITED_SEPARATOR,
+            length_prefix: cfg!(feature = "tmux_3_2"),
+            variables: Vec::new(),
+        }
+    }
+
     pub fn separator(&mut self, c: char) -> &mut Self {
         self.separator = c;
         self
     }
 
-    /// append with variable
+    pub fn length_prefix(&mut self, length_prefix: bool) -> &mut Self {
+        self.length_prefix = length_prefix;
+        self
+    }
+
     pub fn push(&mut self, variable: VariableOutput<'a>) {
         self.variables.push(variable)
     }
 
-    // TODO: check vec same size, return type?
     // XXX: mb from_string for default format too?
-    pub fn from_string_ext(s: &str, format: &'a mut FormatsOutput<'a>) {
-        let v: Vec<&str> = s.split(format.separator).collect();
-        for (i, variable) in v.iter().enumerate() {
-            VariableOutput::from_string_ext(variable, &mut format.variables[i]);
+    /// Parse one record, number of values must match number of variables
+    pub fn from_string_ext(s: &str, format: &'a mut FormatsOutput<'a>) -> Result<(), Error> {
+        let values = match format.length_prefix {
+            true => match split_prefixed(s, format.separator)? {
+                (values, "") => values,
+                _ => return Err(Error::ParseFormatsOutput),
+            },
+            false => s.split(format.separator).collect(),
+        };
+        Self::from_values_ext(&values, format)
+    }
+
+    /// Parse already split values of one record
+    pub fn from_values_ext(
+        values: &[&str],
+        format: &'a mut FormatsOutput<'a>,
+    ) -> Result<(), Error> {
+        if values.len() != format.variables.len() {
+            return Err(Error::ParseFormatsOutput);
+        }
+        for (value, variable) in values.iter().zip(format.variables.iter_mut()) {
+            VariableOutput::from_string_ext(value, variable);
+        }
+        Ok(())
+    }
+
+    /// Split output of a [`Formats::delimited`](crate::Formats::delimited) query into records
+    /// (one per session, window, ...), records can contain newlines
+    pub fn delimited_records(s: &str) -> Result<Vec<&str>, Error> {
+        if !cfg!(feature = "tmux_3_2") {
+            return Ok(s.lines().collect());
+        }
+        let mut records = Vec::new();
+        let mut rest = s;
+        while !rest.is_empty() {
+            let (_, next) = split_prefixed(rest, FORMATS_DELIMITED_SEPARATOR)?;
+            let record = &rest[..rest.len() - next.len()];
+            records.push(record.strip_suffix('\n').unwrap_or(record));
+            rest = next;
         }
+        Ok(records)
     }
 
-    // pub fn custom_string(String) pub fn custom_usize(String)
+    /// Split one record of a [`Formats::delimited`](crate::Formats::delimited) query into values
+    pub fn delimited_values(record: &str) -> Result<Vec<&str>, Error> {
+        match cfg!(feature = "tmux_3_2") {
+            true => match split_prefixed(record, FORMATS_DELIMITED_SEPARATOR)? {
+                (values, "") => Ok(values),
+                _ => Err(Error::ParseFormatsOutput),
+            },
+            false => Ok(record.split(FORMATS_DELIMITED_SEPARATOR).collect()),
+        }
+    }
+
+    // records of a query with `count` additional variables appended, parsed by `parse` (`len`
+    // values) and values of the additional variables
+    pub(crate) fn parse_records_with<T>(
+        output: &str,
+        len: usize,
+        count: usize,
+        parse: impl Fn(&[&str]) -> Result<T, Error>,
+    ) -> Result<Vec<(T, Vec<String>)>, Error> {
+        let mut records = Vec::new();
+        for record in Self::delimited_records(output)? {
+            let values = Self::delimited_values(record)?;
+            if values.len() != len + count {
+                return Err(Error::ParseFormatsOutput);
+            }
+            let (values, custom) = values.split_at(len);
+            let custom = custom.iter().map(|value| value.to_string()).collect();
+            records.push((parse(values)?, custom));
+        }
+        Ok(records)
+    }
+
+    /// Encode values as one record of a [`Formats::delimited`](crate::Formats::delimited)
+    /// query, as tmux would output them (e.g. for custom invokers)
+    pub fn delimited_record(values: &[&str]) -> String {
+        let separator = FORMATS_DELIMITED_SEPARATOR.to_string();
+        match cfg!(feature = "tmux_3_2") {
+            true => values
+                .iter()
+                .map(|value| format!("{}:{}", value.len(), value))
+                .collect::<Vec<String>>()
+                .join(&separator),
+            false => values.join(&separator),
+        }
+    }
+
+    /// Value of a [`Variable::Custom`](crate::Variable::Custom), parsed using `FromStr`
+    pub fn custom<T: FromStr + fmt::Debug>(&mut self, v: &'a mut Option<T>) -> &mut Self {
+        self.push(VariableOutput::Custom(v));
+        self
+    }
 
     // tmux variables
 
@@ -186,7 +296,7 @@ impl<'a> FormatsOutput<'a> {
 
     /// `client_name` - Name of client
     #[cfg(feature = "tmux_2_4")]
-    pub fn client_name(&mut self, v: &'a mut Option<String>) -> &mut Self {
+    pub fn client_name(&mut self, v: &'a mut Option<ClientName>) -> &mut Self {
         self.push(VariableOutput::ClientName(v));
         self
     }
@@ -404,14 +514,14 @@ impl<'a> FormatsOutput<'a> {
 
     /// `hook_pane` - ID of pane where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
-    pub fn hook_pane(&mut self, v: &'a mut Option<usize>) -> &mut Self {
+    pub fn hook_pane(&mut self, v: &'a mut Option<PaneId>) -> &mut Self {
         self.push(VariableOutput::HookPane(v));
         self
     }
 
     /// `hook_session` - ID of session where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
-    pub fn hook_session(&mut self, v: &'a mut Option<usize>) -> &mut Self {
+    pub fn hook_session(&mut self, v: &'a mut Option<SessionId>) -> &mut Self {
         self.push(VariableOutput::HookSession(v));
         self
     }
@@ -425,7 +535,7 @@ impl<'a> FormatsOutput<'a> {
 
     /// `hook_window` - ID of window where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
-    pub fn hook_window(&mut self, v: &'a mut Option<usize>) -> &mut Self {
+    pub fn hook_window(&mut self, v: &'a mut Option<WindowId>) -> &mut Self {
         self.push(VariableOutput::HookWindow(v));
         self
     }
@@ -667,7 +777,7 @@ impl<'a> FormatsOutput<'a> {
 
     /// `pane_id` - #D Unique pane ID
     #[cfg(feature = "tmux_1_6")]
-    pub fn pane_id(&mut self, v: &'a mut Option<usize>) -> &mut Self {
+    pub fn pane_id(&mut self, v: &'a mut Option<PaneId>) -> &mut Self {
         self.push(VariableOutput::PaneId(v));
         self
     }
@@ -1032,7 +1142,7 @@ impl<'a> FormatsOutput<'a> {
 
     /// `session_id` - Unique session ID
     #[cfg(feature = "tmux_1_8")]
-    pub fn session_id(&mut self, v: &'a mut Option<usize>) -> &mut Self {
+    pub fn session_id(&mut self, v: &'a mut Option<SessionId>) -> &mut Self {
         self.push(VariableOutput::SessionId(v));
         self
     }
@@ -1241,7 +1351,7 @@ impl<'a> FormatsOutput<'a> {
 
     /// `window_id` - Unique window ID
     #[cfg(feature = "tmux_1_7")]
-    pub fn window_id(&mut self, v: &'a mut Option<usize>) -> &mut Self {
+    pub fn window_id(&mut self, v: &'a mut Option<WindowId>) -> &mut Self {
         self.push(VariableOutput::WindowId(v));
         self
     }
@@ -1372,3 +1482,22 @@ impl<'a> FormatsOutput<'a> {
         self
     }
 }
+
+// values of one `len:value` record, and the rest of the string after the record newline
+fn split_prefixed(s: &str, separator: char) -> Result<(Vec<&str>, &str), Error> {
+    let mut values = Vec::new();
+    let mut rest = s;
+    loop {
+        match rest.chars().next() {
+            None => return Ok((values, rest)),
+            Some('\n') => return Ok((values, &rest[1..])),
+            Some(c) if c == separator && !values.is_empty() => rest = &rest[c.len_utf8()..],
+            Some(_) if values.is_empty() => {}
+            Some(_) => return Err(Error::ParseFormatsOutput),
+        }
+        let (len, value) = rest.split_once(':').ok_or(Error::ParseFormatsOutput)?;
+        let len = len.parse::<usize>()?;
+        values.push(value.get(..len).ok_or(Error::ParseFormatsOutput)?);
+        rest = &value[len..];
+    }
+}
@@ -11,7 +11,7 @@ fn multiple_from_string() {
     f.window_active(&mut d);
 
     let f_str = "1\'1";
-    FormatsOutput::from_string_ext(f_str, &mut f);
+    FormatsOutput::from_string_ext(f_str, &mut f).unwrap();
     assert_eq!((c, d), (Some(true), Some(true)));
 }
 
@@ -33,7 +33,7 @@ fn struct_from_string() {
     f.window_active(&mut format_struct.field2);
 
     let f_str = "1\'1";
-    FormatsOutput::from_string_ext(f_str, &mut f);
+    FormatsOutput::from_string_ext(f_str, &mut f).unwrap();
     assert_eq!(
         format_struct,
         FormatStruct {
@@ -42,3 +42,128 @@ fn struct_from_string() {
         }
     );
 }
+
+#[test]
+fn from_string_count_mismatch() {
+    use crate::Error;
+    use crate::FormatsOutput;
+
+    let mut c: Option<bool> = None;
+
+    let mut f = FormatsOutput::new();
+    f.window_active(&mut c);
+
+    let f_str = "1\'1";
+    assert!(matches!(
+        FormatsOutput::from_string_ext(f_str, &mut f),
+        Err(Error::ParseFormatsOutput)
+    ));
+}
+
+#[cfg(feature = "tmux_3_2")]
+#[test]
+fn delimited_from_string() {
+    use crate::FormatsOutput;
+
+    let mut name: Option<String> = None;
+    let mut active: Option<bool> = None;
+
+    let mut f = FormatsOutput::delimited();
+    f.session_name(&mut name);
+    f.window_active(&mut active);
+
+    let name_orig = "a:b\u{1f}c\nd";
+    let f_str = FormatsOutput::delimited_record(&[name_orig, "1"]);
+    assert_eq!(f_str, "7:a:b\u{1f}c\nd\u{1f}1:1");
+    FormatsOutput::from_string_ext(&f_str, &mut f).unwrap();
+    assert_eq!((name.as_deref(), active), (Some(name_orig), Some(true)));
+}
+
+#[cfg(feature = "tmux_3_2")]
+#[test]
+fn delimited_records() {
+    use crate::FormatsOutput;
+
+    let record0 = FormatsOutput::delimited_record(&["foo\nbar", "1"]);
+    let record1 = FormatsOutput::delimited_record(&["", "0"]);
+    let s = format!("{}\n{}\n", record0, record1);
+    assert_eq!(
+        FormatsOutput::delimited_records(&s).unwrap(),
+        vec![record0.as_str(), record1.as_str()]
+    );
+    assert_eq!(FormatsOutput::delimited_records("").unwrap().len(), 0);
+}
+
+#[cfg(feature = "tmux_3_2")]
+#[test]
+fn delimited_malformed() {
+    use crate::Error;
+    use crate::FormatsOutput;
+
+    let mut c: Option<bool> = None;
+    let mut f = FormatsOutput::delimited();
+    f.window_active(&mut c);
+
+    // length exceeds value
+    assert!(matches!(
+        FormatsOutput::from_string_ext("5:1", &mut f),
+        Err(Error::ParseFormatsOutput)
+    ));
+    // trailing data after value
+    assert!(matches!(
+        FormatsOutput::delimited_records("1:1x"),
+        Err(Error::ParseFormatsOutput)
+    ));
+}
+
+#[test]
+fn custom_from_string() {
+    use crate::FormatsOutput;
+
+    let mut active: Option<bool> = None;
+    let mut state: Option<String> = None;
+    let mut count: Option<usize> = None;
+    let mut empty: Option<String> = Some("x".to_string());
+    let mut invalid: Option<usize> = Some(1);
+
+    let mut f = FormatsOutput::new();
+    f.window_active(&mut active);
+    f.custom(&mut state)
+        .custom(&mut count)
+        .custom(&mut empty)
+        .custom(&mut invalid);
+
+    FormatsOutput::from_string_ext("1'busy'3''x", &mut f).unwrap();
+    assert_eq!(active, Some(true));
+    assert_eq!(state, Some("busy".to_string()));
+    assert_eq!(count, Some(3));
+    assert_eq!(empty, None);
+    assert_eq!(invalid, None);
+}
+
+#[test]
+fn parse_records_with() {
+    use crate::{Error, FormatsOutput};
+
+    let record0 = FormatsOutput::delimited_record(&["1", "0", "foo", "bar"]);
+    let record1 = FormatsOutput::delimited_record(&["2", "1", "", "baz"]);
+    let output = format!("{}\n{}\n", record0, record1);
+
+    let parse = |values: &[&str]| -> Result<String, Error> { Ok(values.join("+")) };
+    let records = FormatsOutput::parse_records_with(&output, 2, 2, parse).unwrap();
+    assert_eq!(
+        records,
+        vec![
+            (
+                "1+0".to_string(),
+                vec!["foo".to_string(), "bar".to_string()]
+            ),
+            ("2+1".to_string(), vec!["".to_string(), "baz".to_string()]),
+        ]
+    );
+
+    assert!(matches!(
+        FormatsOutput::parse_records_with(&output, 2, 1, parse),
+        Err(Error::ParseFormatsOutput)
+    ));
+}
@@ -7,3 +7,52 @@ fn to_string() {
     f.push(Variable::WindowActive);
     assert_eq!(f.to_string(), "#{window_active}")
 }
+
+#[test]
+fn delimited_to_string() {
+    use crate::{Formats, Variable, FORMATS_DELIMITED_SEPARATOR};
+
+    let mut f = Formats::delimited();
+    f.push(Variable::WindowActive);
+    f.push(Variable::WindowName);
+    #[cfg(feature = "tmux_3_2")]
+    assert_eq!(
+        f.to_string(),
+        format!(
+            "#{{n:window_active}}:#{{window_active}}{}#{{n:window_name}}:#{{window_name}}",
+            FORMATS_DELIMITED_SEPARATOR
+        )
+    );
+    #[cfg(not(feature = "tmux_3_2"))]
+    assert_eq!(
+        f.to_string(),
+        format!(
+            "#{{window_active}}{}#{{window_name}}",
+            FORMATS_DELIMITED_SEPARATOR
+        )
+    );
+}
+
+#[test]
+fn custom_to_string() {
+    use crate::{Formats, Variable, FORMATS_DELIMITED_SEPARATOR};
+
+    let mut f = Formats::new();
+    f.push(Variable::WindowActive);
+    f.custom("@my_state").custom("#{pane_width}x#{pane_height}");
+    assert_eq!(
+        f.to_string(),
+        "#{window_active}'#{@my_state}'#{pane_width}x#{pane_height}"
+    );
+
+    let mut f = Formats::delimited();
+    f.custom("@my_state").custom("#{pane_width}x#{pane_height}");
+    #[cfg(feature = "tmux_3_2")]
+    assert_eq!(
+        f.to_string(),
+        format!(
+            "#{{n:@my_state}}:#{{@my_state}}{}#{{n:#{{pane_width}}x#{{pane_height}}}}:#{{pane_width}}x#{{pane_height}}",
+            FORMATS_DELIMITED_SEPARATOR
+        )
+    );
+}
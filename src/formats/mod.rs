@@ -23,6 +23,75 @@
 //! f.to_string();
 //! ```
 //!
+//! ## Custom
+//!
+//! Options, user options and variables not covered by [`Variable`] are queried using
+//! [`Variable::Custom`], their values are parsed using `FromStr`
+//!
+//! ```
+//! use tmux_interface::{Formats, FormatsOutput, Variable};
+//!
+//! let mut f = Formats::new();
+//! f.push(Variable::WindowActive);
+//! f.custom("@my_state");
+//! assert_eq!(f.to_string(), "#{window_active}'#{@my_state}");
+//!
+//! let (mut active, mut state): (Option<bool>, Option<String>) = (None, None);
+//! let mut output = FormatsOutput::new();
+//! output.window_active(&mut active).custom(&mut state);
+//! FormatsOutput::from_string_ext("1'busy", &mut output).unwrap();
+//! assert_eq!(state, Some("busy".to_string()));
+//! ```
+//!
+//! ## Expressions
+//!
+//! Conditionals, comparisons, modifiers and loops can be composed using [`FormatExpr`]
+//!
+//! ```
+//! use tmux_interface::{FormatComparison, FormatExpr, Variable};
+//!
+//! let expr = FormatExpr::conditional(
+//!     FormatExpr::compare(FormatComparison::Equal, Variable::PaneCurrentCommand, "vim"),
+//!     "editor",
+//!     Variable::PaneCurrentCommand,
+//! );
+//! assert_eq!(
+//!     expr.to_string(),
+//!     "#{?#{==:#{pane_current_command},vim},editor,#{pane_current_command}}"
+//! );
+//! ```
+//!
+//! ## Evaluate
+//!
+//! Formats can be evaluated locally against already fetched values using [`FormatContext`]
+//!
+//! ## Query
+//!
+//! User defined structs implementing [`FormatQuery`] are fetched using `QueryCtl`, a field per
+//! variable, user option or format expression, `#[derive(TmuxFormat)]` implements it (`derive`
+//! feature)
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use tmux_interface::{FormatQuery, PaneId, TmuxFormat};
+//!
+//! #[derive(TmuxFormat)]
+//! struct MyPane {
+//!     pane_id: PaneId,
+//!     #[tmux(option = "@project")]
+//!     project: Option<String>,
+//!     #[tmux(format = "#{pane_width}x#{pane_height}")]
+//!     size: String,
+//! }
+//!
+//! assert_eq!(
+//!     MyPane::formats(),
+//!     ["#{pane_id}", "#{@project}", "#{pane_width}x#{pane_height}"]
+//! );
+//! # }
+//! ```
+//!
 //! # Parse
 //!
 //! VariableOutput
@@ -34,19 +103,39 @@
 //! # See Also
 //! * [Tmux Manual -> Formats](https://man7.org/linux/man-pages/man1/tmux.1.html#FORMATS)
 //!
+#[cfg(feature = "tmux_1_7")]
+pub mod format_context;
+pub mod format_expr;
+pub mod format_parser;
+pub mod format_query;
+pub mod format_value;
 pub mod formats;
 pub mod formats_output;
 pub mod variable;
 pub mod variable_output;
 
-pub use formats::Formats;
+#[cfg(feature = "tmux_1_7")]
+pub use format_context::FormatContext;
+pub use format_expr::{
+    FormatArithmetic, FormatComparison, FormatExpr, FormatLogical, FormatLoop, FormatModifier,
+    FormatVariable,
+};
+pub use format_query::{FormatQuery, FromFormatValue};
+#[cfg(feature = "derive")]
+pub use tmux_interface_derive::TmuxFormat;
+pub use formats::{Formats, FORMATS_DELIMITED_SEPARATOR};
 pub use formats_output::FormatsOutput;
 pub use variable::Variable;
-pub use variable_output::VariableOutput;
+pub use variable_output::{CustomVariableOutput, VariableOutput};
 
 #[cfg(test)]
 #[path = "."]
 mod formats_tests {
+    #[cfg(feature = "tmux_1_7")]
+    mod format_context_tests;
+    mod format_expr_tests;
+    mod format_parser_tests;
+    mod format_query_tests;
     mod formats_output_tests;
     mod formats_tests;
     mod variable_output_tests;
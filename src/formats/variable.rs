@@ -1,10 +1,15 @@
+use crate::Error;
 use std::fmt;
+use std::str::FromStr;
 
 // XXX: ? + - etc refactor in structure in future? split in enum and struct add fields
 // XXX: options allowed too
-#[derive(Debug)]
+/// tmux format variable
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Variable {
-    //Custom(String)
+    /// Variable or option not covered by the crate (e.g. `@my_state`, `pane_new_variable`), or
+    /// a format expression containing `#{` (e.g. `#{pane_width}x#{pane_height}`)
+    Custom(String),
     /// `alternate_on` - if pane is in alternate screen
     #[cfg(feature = "tmux_1_8")]
     AlternateOn,
@@ -433,7 +438,7 @@ pub enum Variable {
     /// `session_group_many_attached` - 1 if multiple clients attached to sessions in gro
     #[cfg(feature = "tmux_3_1")]
     SessionGroupManyAttached,
-    /// `session_size` - Size of session group
+    /// `session_group_size` - Size of session group
     #[cfg(feature = "tmux_2_7")]
     SessionGroupSize,
     /// `session_grouped` - 1 if session in a group
@@ -601,9 +606,11 @@ pub enum Variable {
     WrapFlag,
 }
 
-impl fmt::Display for Variable {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let output = match self {
+impl Variable {
+    /// Name of the variable (without `#{}`)
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Custom(name) => name,
             // alternate_on - if pane is in alternate screen
             #[cfg(feature = "tmux_1_8")]
             Self::AlternateOn => "alternate_on",
@@ -932,10 +939,10 @@ impl fmt::Display for Variable {
             Self::PaneSynchronized => "pane_synchronized",
             // pane_tabs - Pane tab positions
             #[cfg(feature = "tmux_1_8")]
-            Self::PaneTabs => "pane_tabspane_tabs",
+            Self::PaneTabs => "pane_tabs",
             // pane_title - #T Title of pane (can be set by application)
             #[cfg(feature = "tmux_1_6")]
-            Self::PaneTitle => "pane_titlepane_title",
+            Self::PaneTitle => "pane_title",
             // pane_top - Top of pane
             #[cfg(feature = "tmux_2_0")]
             Self::PaneTop => "pane_top",
@@ -1032,9 +1039,9 @@ impl fmt::Display for Variable {
             // session_group_many_attached - 1 if multiple clients attached to sessions in gro
             #[cfg(feature = "tmux_3_1")]
             Self::SessionGroupManyAttached => "session_group_many_attached",
-            // session_size - Size of session group
+            // session_group_size - Size of session group
             #[cfg(feature = "tmux_2_7")]
-            Self::SessionGroupSize => "session_size",
+            Self::SessionGroupSize => "session_group_size",
             // session_grouped - 1 if session in a group
             #[cfg(feature = "tmux_1_6")]
             Self::SessionGrouped => "session_grouped",
@@ -1197,8 +1204,398 @@ impl fmt::Display for Variable {
             // wrap_flag - Pane wrap flag
             #[cfg(feature = "tmux_1_8")]
             Self::WrapFlag => "wrap_flag",
-        };
+        }
+    }
+}
 
-        write!(f, "#{{{}}}", output)
+impl fmt::Display for Variable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Custom(expr) if expr.contains("#{") => write!(f, "{}", expr),
+            _ => write!(f, "#{{{}}}", self.name()),
+        }
+    }
+}
+
+impl FromStr for Variable {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            #[cfg(feature = "tmux_1_8")]
+            "alternate_on" => Ok(Self::AlternateOn),
+            #[cfg(feature = "tmux_1_8")]
+            "alternate_saved_x" => Ok(Self::AlternateSavedX),
+            #[cfg(feature = "tmux_1_8")]
+            "alternate_saved_y" => Ok(Self::AlternateSavedY),
+            #[cfg(feature = "tmux_2_6")]
+            "buffer_created" => Ok(Self::BufferCreated),
+            #[cfg(feature = "tmux_2_3")]
+            "buffer_name" => Ok(Self::BufferName),
+            #[cfg(feature = "tmux_1_7")]
+            "buffer_sample" => Ok(Self::BufferSample),
+            #[cfg(feature = "tmux_1_7")]
+            "buffer_size" => Ok(Self::BufferSize),
+            #[cfg(feature = "tmux_1_6")]
+            "client_activity" => Ok(Self::ClientActivity),
+            #[cfg(feature = "tmux_3_1")]
+            "client_cell_height" => Ok(Self::ClientCellHeight),
+            #[cfg(feature = "tmux_3_1")]
+            "client_cell_width" => Ok(Self::ClientCellWidth),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_2")))]
+            "client_activity_string" => Ok(Self::ClientActivityString),
+            #[cfg(feature = "tmux_1_6")]
+            "client_created" => Ok(Self::ClientCreated),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_2")))]
+            "client_created_string" => Ok(Self::ClientCreatedString),
+            #[cfg(feature = "tmux_2_1")]
+            "client_control_mode" => Ok(Self::ClientControlMode),
+            #[cfg(feature = "tmux_2_1")]
+            "client_discarded" => Ok(Self::ClientDiscarded),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            "client_cwd" => Ok(Self::ClientCwd),
+            #[cfg(feature = "tmux_1_6")]
+            "client_height" => Ok(Self::ClientHeight),
+            #[cfg(feature = "tmux_2_2")]
+            "client_key_table" => Ok(Self::ClientKeyTable),
+            #[cfg(feature = "tmux_1_8")]
+            "client_last_session" => Ok(Self::ClientLastSession),
+            #[cfg(feature = "tmux_2_4")]
+            "client_name" => Ok(Self::ClientName),
+            #[cfg(feature = "tmux_2_1")]
+            "client_pid" => Ok(Self::ClientPid),
+            #[cfg(feature = "tmux_1_8")]
+            "client_prefix" => Ok(Self::ClientPrefix),
+            #[cfg(feature = "tmux_1_6")]
+            "client_readonly" => Ok(Self::ClientReadonly),
+            #[cfg(feature = "tmux_1_8")]
+            "client_session" => Ok(Self::ClientSession),
+            #[cfg(feature = "tmux_1_6")]
+            "client_termname" => Ok(Self::ClientTermname),
+            #[cfg(all(feature = "tmux_2_4", not(feature = "tmux_3_1")))]
+            "client_termtype" => Ok(Self::ClientTermtype),
+            #[cfg(feature = "tmux_1_6")]
+            "client_tty" => Ok(Self::ClientTty),
+            #[cfg(feature = "tmux_1_6")]
+            "client_utf8" => Ok(Self::ClientUtf8),
+            #[cfg(feature = "tmux_1_6")]
+            "client_width" => Ok(Self::ClientWidth),
+            #[cfg(feature = "tmux_2_4")]
+            "client_written" => Ok(Self::ClientWritten),
+            #[cfg(feature = "tmux_2_3")]
+            "command_hooked" => Ok(Self::CommandHooked),
+            #[cfg(all(feature = "tmux_2_2", not(feature = "tmux_2_4")))]
+            "command_name" => Ok(Self::CommandName),
+            #[cfg(feature = "tmux_2_4")]
+            "command" => Ok(Self::Command),
+            #[cfg(feature = "tmux_2_3")]
+            "command_list_name" => Ok(Self::CommandListName),
+            #[cfg(feature = "tmux_2_3")]
+            "command_list_alias" => Ok(Self::CommandListAlias),
+            #[cfg(feature = "tmux_2_3")]
+            "command_list_usage" => Ok(Self::CommandListUsage),
+            #[cfg(feature = "tmux_1_8")]
+            "cursor_flag" => Ok(Self::CursorFlag),
+            #[cfg(feature = "tmux_2_9")]
+            "cursor_character" => Ok(Self::CursorCharacter),
+            #[cfg(feature = "tmux_1_8")]
+            "cursor_x" => Ok(Self::CursorX),
+            #[cfg(feature = "tmux_1_8")]
+            "cursor_y" => Ok(Self::CursorY),
+            #[cfg(feature = "tmux_3_1")]
+            "copy_cursor_line" => Ok(Self::CopyCursorLine),
+            #[cfg(feature = "tmux_3_1")]
+            "copy_cursor_word" => Ok(Self::CopyCursorWord),
+            #[cfg(feature = "tmux_3_1")]
+            "copy_cursor_x" => Ok(Self::CopyCursorX),
+            #[cfg(feature = "tmux_3_1")]
+            "copy_cursor_y" => Ok(Self::CopyCursorY),
+            #[cfg(feature = "tmux_3_2")]
+            "current_file" => Ok(Self::CurrentFile),
+            #[cfg(feature = "tmux_1_7")]
+            "history_bytes" => Ok(Self::HistotyBytes),
+            #[cfg(feature = "tmux_1_7")]
+            "history_limit" => Ok(Self::HistotyLimit),
+            #[cfg(feature = "tmux_1_7")]
+            "history_size" => Ok(Self::HistorySize),
+            #[cfg(feature = "tmux_2_4")]
+            "hook" => Ok(Self::Hook),
+            #[cfg(feature = "tmux_2_4")]
+            "hook_pane" => Ok(Self::HookPane),
+            #[cfg(feature = "tmux_2_4")]
+            "hook_session" => Ok(Self::HookSession),
+            #[cfg(feature = "tmux_2_4")]
+            "hook_session_name" => Ok(Self::HookSessionName),
+            #[cfg(feature = "tmux_2_4")]
+            "hook_window" => Ok(Self::HookWindow),
+            #[cfg(feature = "tmux_2_4")]
+            "hook_window_name" => Ok(Self::HookWindowName),
+            #[cfg(feature = "tmux_1_6")]
+            "host" => Ok(Self::Host),
+            #[cfg(feature = "tmux_1_9")]
+            "host_short" => Ok(Self::HostShort),
+            #[cfg(feature = "tmux_1_8")]
+            "insert_flag" => Ok(Self::InsertFlag),
+            #[cfg(feature = "tmux_1_8")]
+            "keypad_cursor_flag" => Ok(Self::KeypadCursorFlag),
+            #[cfg(feature = "tmux_1_8")]
+            "keypad_flag" => Ok(Self::KeypadFlag),
+            #[cfg(feature = "tmux_1_6")]
+            "line" => Ok(Self::Line),
+            #[cfg(feature = "tmux_1_8")]
+            "mouse_any_flag" => Ok(Self::MouseAnyFlag),
+            #[cfg(feature = "tmux_1_8")]
+            "mouse_button_flag" => Ok(Self::MouseButtonFlag),
+            #[cfg(feature = "tmux_3_0")]
+            "mouse_line" => Ok(Self::MouseLine),
+            #[cfg(feature = "tmux_3_0")]
+            "sgr_flag" => Ok(Self::MouseSgrFlag),
+            #[cfg(feature = "tmux_1_8")]
+            "mouse_standard_flag" => Ok(Self::MouseStandardFlag),
+            #[cfg(feature = "tmux_3_4")]
+            "mouse_status_line" => Ok(Self::MouseStatusLine),
+            #[cfg(feature = "tmux_3_4")]
+            "mouse_status_range" => Ok(Self::MouseStatusRange),
+            #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_2_2"), feature = "tmux_3_0"))]
+            "mouse_utf8_flag" => Ok(Self::MouseUtf8Flag),
+            #[cfg(feature = "tmux_2_4")]
+            "mouse_all_flag" => Ok(Self::MouseAllFlag),
+            #[cfg(feature = "tmux_3_0")]
+            "mouse_word" => Ok(Self::MouseWord),
+            #[cfg(feature = "tmux_3_0")]
+            "mouse_x" => Ok(Self::MouseX),
+            #[cfg(feature = "tmux_3_0")]
+            "mouse_y" => Ok(Self::MouseY),
+            #[cfg(feature = "tmux_3_0")]
+            "origin_flag" => Ok(Self::OriginFlag),
+            #[cfg(feature = "tmux_1_6")]
+            "pane_active" => Ok(Self::PaneActive),
+            #[cfg(feature = "tmux_2_6")]
+            "pane_at_bottom" => Ok(Self::PaneAtBottom),
+            #[cfg(feature = "tmux_2_6")]
+            "pane_at_left" => Ok(Self::PaneAtLeft),
+            #[cfg(feature = "tmux_2_6")]
+            "pane_at_right" => Ok(Self::PaneAtRight),
+            #[cfg(feature = "tmux_2_6")]
+            "pane_at_top" => Ok(Self::PaneAtTop),
+            #[cfg(feature = "tmux_2_0")]
+            "pane_bottom" => Ok(Self::PaneBottom),
+            #[cfg(feature = "tmux_1_8")]
+            "pane_current_command" => Ok(Self::PaneCurrentCommand),
+            #[cfg(feature = "tmux_1_7")]
+            "pane_current_path" => Ok(Self::PaneCurrentPath),
+            #[cfg(feature = "tmux_1_6")]
+            "pane_dead" => Ok(Self::PaneDead),
+            #[cfg(feature = "tmux_2_0")]
+            "pane_dead_status" => Ok(Self::PaneDeadStatus),
+            #[cfg(feature = "tmux_2_6")]
+            "pane_format" => Ok(Self::PaneFormat),
+            #[cfg(feature = "tmux_1_6")]
+            "pane_height" => Ok(Self::PaneHeight),
+            #[cfg(feature = "tmux_1_6")]
+            "pane_id" => Ok(Self::PaneId),
+            #[cfg(feature = "tmux_1_8")]
+            "pane_in_mode" => Ok(Self::PaneInMode),
+            #[cfg(feature = "tmux_1_7")]
+            "pane_index" => Ok(Self::PaneIndex),
+            #[cfg(feature = "tmux_2_0")]
+            "pane_input_off" => Ok(Self::PaneInputOff),
+            #[cfg(feature = "tmux_2_0")]
+            "pane_left" => Ok(Self::PaneLeft),
+            #[cfg(feature = "tmux_3_0")]
+            "pane_marked" => Ok(Self::PaneMarked),
+            #[cfg(feature = "tmux_3_0")]
+            "pane_marked_set" => Ok(Self::PaneMarkedSet),
+            #[cfg(feature = "tmux_2_5")]
+            "pane_mode" => Ok(Self::PaneMode),
+            #[cfg(feature = "tmux_3_1")]
+            "pane_path" => Ok(Self::PanePath),
+            #[cfg(feature = "tmux_1_6")]
+            "pane_pid" => Ok(Self::PanePid),
+            #[cfg(feature = "tmux_2_6")]
+            "pane_pipe" => Ok(Self::PanePipe),
+            #[cfg(feature = "tmux_2_0")]
+            "pane_right" => Ok(Self::PaneRight),
+            #[cfg(feature = "tmux_2_5")]
+            "pane_search_string" => Ok(Self::PaneSearchString),
+            #[cfg(feature = "tmux_1_6")]
+            "pane_start_command" => Ok(Self::PaneStartCommand),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_0")))]
+            "pane_start_path" => Ok(Self::PaneStartPath),
+            #[cfg(feature = "tmux_1_9")]
+            "pane_synchronized" => Ok(Self::PaneSynchronized),
+            #[cfg(feature = "tmux_1_8")]
+            "pane_tabs" => Ok(Self::PaneTabs),
+            #[cfg(feature = "tmux_1_6")]
+            "pane_title" => Ok(Self::PaneTitle),
+            #[cfg(feature = "tmux_2_0")]
+            "pane_top" => Ok(Self::PaneTop),
+            #[cfg(feature = "tmux_1_6")]
+            "pane_tty" => Ok(Self::PaneTty),
+            #[cfg(feature = "tmux_3_4")]
+            "pane_unseen_changes" => Ok(Self::PaneUnseenChanges),
+            #[cfg(feature = "tmux_1_6")]
+            "pane_width" => Ok(Self::PaneWidth),
+            #[cfg(any(feature = "tmux_1_8", not(feature = "tmux_2_1")))]
+            "saved_cursor_x" => Ok(Self::SavedCursorX),
+            #[cfg(any(feature = "tmux_1_8", not(feature = "tmux_2_1")))]
+            "saved_cursor_y" => Ok(Self::SavedCursorY),
+            #[cfg(feature = "tmux_2_1")]
+            "pid" => Ok(Self::Pid),
+            #[cfg(feature = "tmux_2_7")]
+            "rectangle_toggle" => Ok(Self::RectangleToggle),
+            #[cfg(feature = "tmux_2_2")]
+            "scroll_position" => Ok(Self::ScrollPosition),
+            #[cfg(feature = "tmux_1_8")]
+            "scroll_region_lower" => Ok(Self::ScrollRegionLower),
+            #[cfg(feature = "tmux_1_8")]
+            "scroll_region_upper" => Ok(Self::ScrollRegionUpper),
+            #[cfg(feature = "tmux_3_1")]
+            "selection_active" => Ok(Self::SelectionActive),
+            #[cfg(feature = "tmux_3_1")]
+            "selection_end_x" => Ok(Self::SelectionEndX),
+            #[cfg(feature = "tmux_3_1")]
+            "selection_end_y" => Ok(Self::SelectionEndY),
+            #[cfg(feature = "tmux_2_6")]
+            "selection_present" => Ok(Self::SelectionPresent),
+            #[cfg(feature = "tmux_3_1")]
+            "selection_start_x" => Ok(Self::SelectionStartX),
+            #[cfg(feature = "tmux_3_1")]
+            "selection_start_y" => Ok(Self::SelectionStartY),
+            #[cfg(feature = "tmux_2_1")]
+            "session_activity" => Ok(Self::SessionActivity),
+            #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_2_2")))]
+            "session_activity_string" => Ok(Self::SessionActivityString),
+            #[cfg(feature = "tmux_2_1")]
+            "session_alerts" => Ok(Self::SessionAlerts),
+            #[cfg(feature = "tmux_1_6")]
+            "session_attached" => Ok(Self::SessionAttached),
+            #[cfg(feature = "tmux_3_1")]
+            "session_attached_list" => Ok(Self::SessionAttachedList),
+            #[cfg(feature = "tmux_1_6")]
+            "session_created" => Ok(Self::SessionCreated),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_2")))]
+            "session_created_string" => Ok(Self::SessionCreatedString),
+            #[cfg(feature = "tmux_2_6")]
+            "session_format" => Ok(Self::SessionFormat),
+            #[cfg(feature = "tmux_1_6")]
+            "session_group" => Ok(Self::SessionGroup),
+            #[cfg(feature = "tmux_3_1")]
+            "session_group_attached" => Ok(Self::SessionGroupAttached),
+            #[cfg(feature = "tmux_3_1")]
+            "session_group_attached_list" => Ok(Self::SessionGroupAttachedList),
+            #[cfg(feature = "tmux_2_7")]
+            "session_group_list" => Ok(Self::SessionGroupList),
+            #[cfg(feature = "tmux_3_1")]
+            "session_group_many_attached" => Ok(Self::SessionGroupManyAttached),
+            #[cfg(feature = "tmux_2_7")]
+            "session_group_size" => Ok(Self::SessionGroupSize),
+            #[cfg(feature = "tmux_1_6")]
+            "session_grouped" => Ok(Self::SessionGrouped),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_9")))]
+            "session_height" => Ok(Self::SessionHeight),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_9")))]
+            "session_width" => Ok(Self::SessionWidth),
+            #[cfg(feature = "tmux_1_8")]
+            "session_id" => Ok(Self::SessionId),
+            #[cfg(feature = "tmux_2_1")]
+            "session_last_attached" => Ok(Self::SessionLastAttached),
+            #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_2_2")))]
+            "session_last_attached_string" => Ok(Self::SessionLastAttachedString),
+            #[cfg(feature = "tmux_2_0")]
+            "session_many_attached" => Ok(Self::SessionManyAttached),
+            #[cfg(feature = "tmux_1_6")]
+            "session_name" => Ok(Self::SessionName),
+            #[cfg(feature = "tmux_2_5")]
+            "session_stack" => Ok(Self::SessionStack),
+            #[cfg(feature = "tmux_1_6")]
+            "session_windows" => Ok(Self::SessionWindows),
+            #[cfg(feature = "tmux_2_2")]
+            "socket_path" => Ok(Self::SocketPath),
+            #[cfg(feature = "tmux_2_2")]
+            "start_time" => Ok(Self::StartTime),
+            #[cfg(feature = "tmux_2_4")]
+            "version" => Ok(Self::Version),
+            #[cfg(feature = "tmux_1_6")]
+            "window_active" => Ok(Self::WindowActive),
+            #[cfg(feature = "tmux_3_1")]
+            "window_active_clients" => Ok(Self::WindowActiveClients),
+            #[cfg(feature = "tmux_3_1")]
+            "window_active_clients_list" => Ok(Self::WindowActiveClientsList),
+            #[cfg(feature = "tmux_3_1")]
+            "window_active_sessions" => Ok(Self::WindowActiveSessions),
+            #[cfg(feature = "tmux_3_1")]
+            "window_active_sessions_list" => Ok(Self::WindowActiveSessionsList),
+            #[cfg(feature = "tmux_2_1")]
+            "window_activity" => Ok(Self::WindowActivity),
+            #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_2_2")))]
+            "window_activity_string" => Ok(Self::WindowActivityString),
+            #[cfg(any(
+                all(feature = "tmux_1_9", not(feature = "tmux_2_2")),
+                feature = "tmux_2_3"
+            ))]
+            "window_activity_flag" => Ok(Self::WindowActivityFlag),
+            #[cfg(feature = "tmux_1_9")]
+            "window_bell_flag" => Ok(Self::WindowBellFlag),
+            #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
+            "window_content_flag" => Ok(Self::WindowContentFlag),
+            #[cfg(feature = "tmux_2_9")]
+            "window_bigger" => Ok(Self::WindowBigger),
+            #[cfg(feature = "tmux_3_1")]
+            "window_cell_height" => Ok(Self::WindowCellHeight),
+            #[cfg(feature = "tmux_3_1")]
+            "window_cell_width" => Ok(Self::WindowCellWidth),
+            #[cfg(feature = "tmux_2_9")]
+            "window_end_flag" => Ok(Self::WindowEndFlag),
+            #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_2_6")))]
+            "window_find_matches" => Ok(Self::WindowFindMatches),
+            #[cfg(feature = "tmux_1_6")]
+            "window_flags" => Ok(Self::WindowFlags),
+            #[cfg(feature = "tmux_3_2")]
+            "window_raw_flags" => Ok(Self::WindowRawFlags),
+            #[cfg(feature = "tmux_2_6")]
+            "window_format" => Ok(Self::WindowFormat),
+            #[cfg(feature = "tmux_1_6")]
+            "window_height" => Ok(Self::WindowHeight),
+            #[cfg(feature = "tmux_1_7")]
+            "window_id" => Ok(Self::WindowId),
+            #[cfg(feature = "tmux_1_6")]
+            "window_index" => Ok(Self::WindowIndex),
+            #[cfg(feature = "tmux_2_0")]
+            "window_last_flag" => Ok(Self::WindowLastFlag),
+            #[cfg(feature = "tmux_1_6")]
+            "window_layout" => Ok(Self::WindowLayout),
+            #[cfg(feature = "tmux_2_1")]
+            "window_linked" => Ok(Self::WindowLinked),
+            #[cfg(feature = "tmux_3_1")]
+            "window_linked_sessions" => Ok(Self::WindowLinkedSessions),
+            #[cfg(feature = "tmux_3_1")]
+            "window_linked_sessions_list" => Ok(Self::WindowLinkedSessionsList),
+            #[cfg(feature = "tmux_3_1")]
+            "window_marked_flag" => Ok(Self::WindowMarkedFlag),
+            #[cfg(feature = "tmux_1_6")]
+            "window_name" => Ok(Self::WindowName),
+            #[cfg(feature = "tmux_2_9")]
+            "window_offset_x" => Ok(Self::WindowOffsetX),
+            #[cfg(feature = "tmux_2_9")]
+            "window_offset_y" => Ok(Self::WindowOffsetY),
+            #[cfg(feature = "tmux_1_7")]
+            "window_panes" => Ok(Self::WindowPanes),
+            #[cfg(feature = "tmux_1_9")]
+            "window_silence_flag" => Ok(Self::WindowSilenceFlag),
+            #[cfg(feature = "tmux_2_5")]
+            "window_stack_index" => Ok(Self::WindowStackIndex),
+            #[cfg(feature = "tmux_2_9")]
+            "window_start_flag" => Ok(Self::WindowStartFlag),
+            #[cfg(feature = "tmux_2_2")]
+            "window_visible_layout" => Ok(Self::WindowVisibleLayout),
+            #[cfg(feature = "tmux_1_6")]
+            "window_width" => Ok(Self::WindowWidth),
+            #[cfg(feature = "tmux_2_0")]
+            "window_zoomed_flag" => Ok(Self::WindowZoomedFlag),
+            #[cfg(feature = "tmux_1_8")]
+            "wrap_flag" => Ok(Self::WrapFlag),
+            _ => Err(Error::ParseVariable),
+        }
     }
 }
@@ -1,13 +1,38 @@
+#[cfg(feature = "tmux_2_4")]
+use crate::ClientName;
+use crate::PaneId;
+#[cfg(feature = "tmux_1_8")]
+use crate::SessionId;
 #[cfg(feature = "tmux_2_5")]
 use crate::SessionStack;
+#[cfg(feature = "tmux_1_7")]
+use crate::WindowId;
 #[cfg(feature = "tmux_1_6")]
 use crate::{Layout, PaneTabs, WindowFlags};
+use std::fmt;
+use std::str::FromStr;
+
+/// Output of a [`VariableOutput::Custom`], implemented for `Option<T>` parsed using `FromStr`
+/// (`None` if the value is empty or can not be parsed)
+pub trait CustomVariableOutput: fmt::Debug {
+    fn set_value(&mut self, s: &str);
+}
+
+impl<T: FromStr + fmt::Debug> CustomVariableOutput for Option<T> {
+    fn set_value(&mut self, s: &str) {
+        *self = match s.is_empty() {
+            true => None,
+            false => s.parse().ok(),
+        };
+    }
+}
 
 // XXX: ? + - etc refactor in structure in future? split in enum and struct add fields
 // XXX: options allowed too
 #[derive(Debug)]
 pub enum VariableOutput<'a> {
-    //Custom(String)
+    /// Value of a [`Variable::Custom`](crate::Variable::Custom)
+    Custom(&'a mut dyn CustomVariableOutput),
     /// `alternate_on` - if pane is in alternate screen
     #[cfg(feature = "tmux_1_8")]
     AlternateOn(&'a mut Option<usize>),
@@ -71,7 +96,7 @@ pub enum VariableOutput<'a> {
     ClientLastSession(&'a mut Option<String>),
     /// `client_name` - Name of client
     #[cfg(feature = "tmux_2_4")]
-    ClientName(&'a mut Option<String>),
+    ClientName(&'a mut Option<ClientName>),
     /// `client_pid` - PID of client process
     #[cfg(feature = "tmux_2_1")]
     ClientPid(&'a mut Option<usize>),
@@ -170,16 +195,16 @@ pub enum VariableOutput<'a> {
     Hook(&'a mut Option<String>),
     /// `hook_pane` - ID of pane where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
-    HookPane(&'a mut Option<usize>),
+    HookPane(&'a mut Option<PaneId>),
     /// `hook_session` - ID of session where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
-    HookSession(&'a mut Option<usize>),
+    HookSession(&'a mut Option<SessionId>),
     /// `hook_session_name` - Name of session where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
     HookSessionName(&'a mut Option<String>),
     /// `hook_window` - ID of window where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
-    HookWindow(&'a mut Option<usize>),
+    HookWindow(&'a mut Option<WindowId>),
     /// `hook_window_name` - Name of window where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
     HookWindowName(&'a mut Option<String>),
@@ -288,7 +313,7 @@ pub enum VariableOutput<'a> {
     PaneHeight(&'a mut Option<usize>),
     /// `pane_id` - #D Unique pane ID
     #[cfg(feature = "tmux_1_6")]
-    PaneId(&'a mut Option<usize>),
+    PaneId(&'a mut Option<PaneId>),
     /// `pane_in_mode` - 1 if pane is in a mode
     #[cfg(feature = "tmux_1_8")]
     PaneInMode(&'a mut Option<bool>),
@@ -436,7 +461,7 @@ pub enum VariableOutput<'a> {
     /// `session_group_many_attached` - 1 if multiple clients attached to sessions in gro
     #[cfg(feature = "tmux_3_1")]
     SessionGroupManyAttached(&'a mut Option<bool>),
-    /// `session_size` - Size of session group
+    /// `session_group_size` - Size of session group
     #[cfg(feature = "tmux_2_7")]
     SessionGroupSize(&'a mut Option<String>),
     /// `session_grouped` - 1 if session in a group
@@ -450,7 +475,7 @@ pub enum VariableOutput<'a> {
     SessionWidth(&'a mut Option<usize>),
     /// `session_id` - Unique session ID
     #[cfg(feature = "tmux_1_8")]
-    SessionId(&'a mut Option<usize>),
+    SessionId(&'a mut Option<SessionId>),
     /// `session_last_attached` - Time session last attached
     #[cfg(feature = "tmux_2_1")]
     SessionLastAttached(&'a mut Option<usize>),
@@ -546,7 +571,7 @@ pub enum VariableOutput<'a> {
     WindowHeight(&'a mut Option<usize>),
     /// `window_id` - Unique window ID
     #[cfg(feature = "tmux_1_7")]
-    WindowId(&'a mut Option<usize>),
+    WindowId(&'a mut Option<WindowId>),
     /// `window_index` - #I Index of window
     #[cfg(feature = "tmux_1_6")]
     WindowIndex(&'a mut Option<usize>),
@@ -627,6 +652,8 @@ impl<'a> VariableOutput<'a> {
 
     pub fn from_string_ext(s: &str, variable: &mut VariableOutput<'a>) {
         match variable {
+            Self::Custom(v) => v.set_value(s),
+
             // alternate_on - if pane is in alternate screen
             #[cfg(feature = "tmux_1_8")]
             Self::AlternateOn(v) => **v = s.parse::<usize>().ok(),
@@ -691,7 +718,7 @@ impl<'a> VariableOutput<'a> {
             Self::ClientLastSession(v) => **v = Self::parse_option_string(s),
             // client_name - Name of client
             #[cfg(feature = "tmux_2_4")]
-            Self::ClientName(v) => **v = Self::parse_option_string(s),
+            Self::ClientName(v) => **v = Self::parse_option_string(s).map(ClientName),
             // client_pid - PID of client process
             #[cfg(feature = "tmux_2_1")]
             Self::ClientPid(v) => **v = s.parse::<usize>().ok(),
@@ -790,16 +817,16 @@ impl<'a> VariableOutput<'a> {
             Self::Hook(v) => **v = Self::parse_option_string(s),
             // hook_pane - ID of pane where hook was run, if any
             #[cfg(feature = "tmux_2_4")]
-            Self::HookPane(v) => **v = s.parse::<usize>().ok(),
+            Self::HookPane(v) => **v = s.parse().ok(),
             // hook_session - ID of session where hook was run, if any
             #[cfg(feature = "tmux_2_4")]
-            Self::HookSession(v) => **v = s.parse::<usize>().ok(),
+            Self::HookSession(v) => **v = s.parse().ok(),
             // hook_session_name - Name of session where hook was run, if any
             #[cfg(feature = "tmux_2_4")]
             Self::HookSessionName(v) => **v = Self::parse_option_string(s),
             // hook_window - ID of window where hook was run, if any
             #[cfg(feature = "tmux_2_4")]
-            Self::HookWindow(v) => **v = s.parse::<usize>().ok(),
+            Self::HookWindow(v) => **v = s.parse().ok(),
             // hook_window_name - Name of window where hook was run, if any
             #[cfg(feature = "tmux_2_4")]
             Self::HookWindowName(v) => **v = Self::parse_option_string(s),
@@ -908,7 +935,7 @@ impl<'a> VariableOutput<'a> {
             Self::PaneHeight(v) => **v = s.parse::<usize>().ok(),
             // pane_id - #D Unique pane ID
             #[cfg(feature = "tmux_1_6")]
-            Self::PaneId(v) => **v = s[1..].parse::<usize>().ok(),
+            Self::PaneId(v) => **v = s.parse().ok(),
             // pane_in_mode - 1 if pane is in a mode
             #[cfg(feature = "tmux_1_8")]
             Self::PaneInMode(v) => **v = Self::parse_option_bool(s),
@@ -1056,7 +1083,7 @@ impl<'a> VariableOutput<'a> {
             // session_group_many_attached - 1 if multiple clients attached to sessions in gro
             #[cfg(feature = "tmux_3_1")]
             Self::SessionGroupManyAttached(v) => **v = Self::parse_option_bool(s),
-            // session_size - Size of session group
+            // session_group_size - Size of session group
             #[cfg(feature = "tmux_2_7")]
             Self::SessionGroupSize(v) => **v = Self::parse_option_string(s),
             // session_grouped - 1 if session in a group
@@ -1070,7 +1097,7 @@ impl<'a> VariableOutput<'a> {
             Self::SessionWidth(v) => **v = s.parse::<usize>().ok(),
             // session_id - Unique session ID
             #[cfg(feature = "tmux_1_8")]
-            Self::SessionId(v) => **v = s[1..].parse::<usize>().ok(), // skip $ char
+            Self::SessionId(v) => **v = s.parse().ok(),
             // session_last_attached - Time session last attached
             #[cfg(feature = "tmux_2_1")]
             Self::SessionLastAttached(v) => **v = Self::parse_option_usize(s),
@@ -1165,7 +1192,7 @@ impl<'a> VariableOutput<'a> {
             Self::WindowHeight(v) => **v = s.parse::<usize>().ok(),
             // window_id - Unique window ID
             #[cfg(feature = "tmux_1_7")]
-            Self::WindowId(v) => **v = s[1..].parse::<usize>().ok(),
+            Self::WindowId(v) => **v = s.parse().ok(),
             // window_index - #I Index of window
             #[cfg(feature = "tmux_1_6")]
             Self::WindowIndex(v) => **v = s.parse::<usize>().ok(),
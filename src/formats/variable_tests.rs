@@ -5,3 +5,15 @@ fn to_string() {
     let v = Variable::WindowActive;
     assert_eq!(v.to_string(), "#{window_active}");
 }
+
+#[test]
+fn custom_to_string() {
+    use crate::Variable;
+
+    let v = Variable::Custom("@my_state".to_string());
+    assert_eq!(v.name(), "@my_state");
+    assert_eq!(v.to_string(), "#{@my_state}");
+
+    let v = Variable::Custom("#{pane_width}x#{pane_height}".to_string());
+    assert_eq!(v.to_string(), "#{pane_width}x#{pane_height}");
+}
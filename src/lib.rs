@@ -10,10 +10,12 @@
 //! * 3. [Package Compilation Features](#3-package-compilation-features)
 //!     * 3.1. [Tmux Version](#31-tmux-version)
 //!     * 3.2. [Tmux Command Alias](#32-tmux-command-alias)
-//!     * 3.3. [Repository](#3-3-repository)
-//!         * 3.3.1 [Using Crates Repository](#331-using-crates-repository)
-//!         * 3.3.2 [Using Local Repository](#332-using-local-repository)
-//!         * 3.3.3 [Using Remote Repository](#333-using-remote-repository)
+//!     * 3.3. [Serde](#33-serde)
+//!     * 3.4. [Regex](#34-regex)
+//!     * 3.5. [Repository](#3-5-repository)
+//!         * 3.5.1 [Using Crates Repository](#351-using-crates-repository)
+//!         * 3.5.2 [Using Local Repository](#352-using-local-repository)
+//!         * 3.5.3 [Using Remote Repository](#353-using-remote-repository)
 //! * 4. [Modules Overview](#4-modules-overview)
 //! * 5. [Modules and Levels Hierarchy](#5-modules-and-levels-hierarchy)
 //!     * 5.1. [Level Explanations and Examples](#51-level-explanations-and-examples)
@@ -156,9 +158,21 @@
 //!
 //! `cmd_alias` use alias instead of full tmux command name (e.g. `list-sessions` -> `ls`). Enabled by default.
 //!
-//! ## 3.3. Repository
+//! ## 3.3. Serde
 //!
-//! ### 3.3.1. Using Crates Repository
+//! `serde` implements `Serialize` and `Deserialize` for variables ([`Session`], [`Window`],
+//! [`Pane`], [`Client`], [`Buffer`], [`Layout`], ...) and options ([`SessionOptions`],
+//! [`WindowOptions`], [`ServerOptions`], [`PaneOptions`]) using their field names. IDs, flags and
+//! option values are stored as tmux strings (see `serde_str` module). Disabled by default.
+//!
+//! ## 3.4. Regex
+//!
+//! `regex` implements [`Pattern`] for `regex::Regex`, so regular expressions can be used in
+//! [`PaneDriver::expect`]. Disabled by default.
+//!
+//! ## 3.5. Repository
+//!
+//! ### 3.5.1. Using Crates Repository
 //!
 //! ```text
 //! [dependencies]
@@ -167,7 +181,7 @@
 //! }
 //! ```
 //!
-//! ### 3.3.2. Using Local Repository
+//! ### 3.5.2. Using Local Repository
 //!
 //! ```text
 //! [dependencies]
@@ -177,7 +191,7 @@
 //! }
 //! ```
 //!
-//! ### 3.3.3. Using Remote Repository
+//! ### 3.5.3. Using Remote Repository
 //!
 //! ```text
 //! tmux_interface = {
@@ -498,6 +512,8 @@ pub mod copy_mode;
 pub mod error;
 pub mod formats;
 pub mod options;
+#[cfg(feature = "serde")]
+pub mod serde_str;
 pub mod styles;
 pub mod target;
 #[cfg(feature = "tmux_1_6")]
@@ -51,9 +51,9 @@ pub trait GetOptionTr {
         let cmd = ShowOptions::new().option(name);
         let cmd = match target {
             #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_session(target),
+            Some(target) => cmd.target_session::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         cmd.build()
@@ -77,9 +77,9 @@ pub trait GetOptionTr {
         let cmd = ShowOptions::new();
         let cmd = match target {
             #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_session(target),
+            Some(target) => cmd.target_session::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         cmd.build()
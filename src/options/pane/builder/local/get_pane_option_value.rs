@@ -10,7 +10,7 @@ impl GetOptionTr for GetPaneOptionValue {
     ) -> TmuxCommand<'a> {
         let cmd = ShowOptions::new().pane().value().option(name);
         let cmd = match target {
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         cmd.build()
@@ -20,7 +20,7 @@ impl SetOptionTr for SetPaneOption {
             #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_3_0")))]
             Some(target) => cmd.target(target),
             #[cfg(feature = "tmux_3_0")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         let cmd = match value {
@@ -39,7 +39,7 @@ impl SetOptionTr for SetPaneOption {
             #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_3_0")))]
             Some(target) => cmd.target(target),
             #[cfg(feature = "tmux_3_0")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         cmd.build()
@@ -11,6 +11,8 @@ use crate::RemainOnExit;
 
 // TODO: check types
 #[derive(PartialEq, Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct PaneOptions<'a> {
     /// tmux ^3.0:
     /// ```text
@@ -21,6 +21,8 @@ use std::str::FromStr;
 // TODO: check types
 // TODO: command_alias and terminal_overrides both as String and as Vec<String> see tmux versions
 #[derive(PartialEq, Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ServerOptions<'a> {
     /// `backspace key`
     #[cfg(feature = "tmux_3_1")]
@@ -18,9 +18,9 @@ impl GetOptionTr for GetGlobalSessionOption {
         let cmd = ShowOptions::new().global().option(name);
         let cmd = match target {
             #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_session(target),
+            Some(target) => cmd.target_session::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         cmd.build()
@@ -30,9 +30,9 @@ impl GetOptionTr for GetGlobalSessionOption {
         let cmd = ShowOptions::new().global();
         let cmd = match target {
             #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_session(target),
+            Some(target) => cmd.target_session::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         cmd.build()
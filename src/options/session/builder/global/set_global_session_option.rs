@@ -16,9 +16,9 @@ impl SetOptionTr for SetGlobalSessionOption {
         let cmd = SetOption::new().global().option(name);
         let cmd = match target {
             #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_window(target),
+            Some(target) => cmd.target_window::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         let cmd = match value {
@@ -35,9 +35,9 @@ impl SetOptionTr for SetGlobalSessionOption {
         let cmd = SetOption::new().global().option(name).unset();
         let cmd = match target {
             #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_window(target),
+            Some(target) => cmd.target_window::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         cmd.build()
@@ -12,9 +12,9 @@ impl GetOptionTr for GetLocalSessionOption {
         let cmd = ShowOptions::new().option(name);
         let cmd = match target {
             #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_session(target),
+            Some(target) => cmd.target_session::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         cmd.build()
@@ -24,9 +24,9 @@ impl GetOptionTr for GetLocalSessionOption {
         let cmd = ShowOptions::new();
         let cmd = match target {
             #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_session(target),
+            Some(target) => cmd.target_session::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         cmd.build()
@@ -15,6 +15,8 @@ use std::str::FromStr;
 // TODO: check types
 // 45 Available session options are:
 #[derive(PartialEq, Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct SessionOptions<'a> {
     //activity-action [any | none | current | other]
     #[cfg(feature = "tmux_2_6")]
@@ -45,9 +45,9 @@ pub trait SetOptionTr {
         let cmd = SetOption::new().option(name);
         let cmd = match target {
             #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_window(target),
+            Some(target) => cmd.target_window::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         let cmd = match value {
@@ -77,9 +77,9 @@ pub trait SetOptionTr {
         let cmd = SetOption::new().option(name).unset();
         let cmd = match target {
             #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_window(target),
+            Some(target) => cmd.target_window::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         cmd.build()
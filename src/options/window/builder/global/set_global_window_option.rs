@@ -15,9 +15,9 @@ impl SetOptionTr for SetGlobalWindowOption {
         let cmd = SetOption::new().window().global().option(name);
         let cmd = match target {
             #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_window(target),
+            Some(target) => cmd.target_window::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         let cmd = match value {
@@ -34,9 +34,9 @@ impl SetOptionTr for SetGlobalWindowOption {
         let cmd = SetOption::new().window().global().option(name).unset();
         let cmd = match target {
             #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_3_0a")))]
-            Some(target) => cmd.target_window(target),
+            Some(target) => cmd.target_window::<Cow<'a, str>>(target.into()),
             #[cfg(feature = "tmux_3_0a")]
-            Some(target) => cmd.target_pane(target),
+            Some(target) => cmd.target_pane::<Cow<'a, str>>(target.into()),
             None => cmd,
         };
         cmd.build()
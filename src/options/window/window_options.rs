@@ -13,6 +13,8 @@ use crate::ModeMouse;
 
 // TODO: check types
 #[derive(PartialEq, Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct WindowOptions<'a> {
     /// tmux ^1.0:
     /// ```text
@@ -0,0 +1,83 @@
+//! Serde support (`serde` feature) of types having a tmux string form
+//!
+//! Types implementing `FromStr` and `fmt::Display` are serialized as the same string tmux uses
+//! (e.g. [`Status::On`](crate::Status::On) as `"on"`, [`SessionId`](crate::SessionId) as `"$1"`,
+//! [`WindowFlags`](crate::WindowFlags) as `"*Z"`). Structures (variables, layouts, options) derive
+//! `Serialize` and `Deserialize` using their field names.
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+macro_rules! serde_str {
+    ($($(#[$meta:meta])* $t:ty),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl Serialize for $t {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            $(#[$meta])*
+            impl<'de> Deserialize<'de> for $t {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let s = String::deserialize(deserializer)?;
+                    s.parse().map_err(|e: crate::Error| de::Error::custom(e))
+                }
+            }
+        )*
+    };
+}
+
+// target
+serde_str!(
+    crate::SessionId,
+    crate::WindowId,
+    crate::PaneId,
+    crate::ClientName,
+);
+
+// variables
+serde_str!(
+    #[cfg(feature = "tmux_1_6")]
+    crate::WindowFlags,
+);
+
+// options
+serde_str!(
+    crate::Switch,
+    crate::StatusKeys,
+    // server
+    #[cfg(feature = "tmux_1_5")]
+    crate::SetClipboard,
+    #[cfg(feature = "tmux_3_2")]
+    crate::ExtendedKeys,
+    // session
+    #[cfg(feature = "tmux_1_0")]
+    crate::Action,
+    #[cfg(feature = "tmux_1_0")]
+    crate::Activity,
+    #[cfg(feature = "tmux_1_5")]
+    crate::DestroyUnattached,
+    #[cfg(feature = "tmux_1_4")]
+    crate::DetachOnDestroy,
+    #[cfg(feature = "tmux_3_4")]
+    crate::MessageLine,
+    #[cfg(feature = "tmux_1_0")]
+    crate::Status,
+    #[cfg(feature = "tmux_1_0")]
+    crate::StatusJustify,
+    #[cfg(feature = "tmux_1_7")]
+    crate::StatusPosition,
+    // window
+    #[cfg(feature = "tmux_1_2")]
+    crate::ClockModeStyle,
+    #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_2_1")))]
+    crate::ModeMouse,
+    #[cfg(feature = "tmux_2_3")]
+    crate::PaneBorderStatus,
+    #[cfg(feature = "tmux_2_9")]
+    crate::WindowSize,
+    // pane
+    #[cfg(feature = "tmux_3_1")]
+    crate::RemainOnExit,
+);
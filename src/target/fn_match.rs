@@ -0,0 +1,51 @@
+/// fnmatch(3) pattern matching (`*`, `?`, `[...]`, `[!...]`)
+pub fn fn_match(pattern: &[u8], s: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => s.is_empty(),
+        Some((b'*', rest)) => (0..=s.len()).any(|i| fn_match(rest, &s[i..])),
+        Some((b'?', rest)) => !s.is_empty() && fn_match(rest, &s[1..]),
+        Some((b'[', rest)) => match (s.split_first(), bracket(rest)) {
+            (Some((c, s)), Some((set, rest))) => set_match(set, *c) && fn_match(rest, s),
+            // unclosed bracket is a literal `[`
+            (Some((b'[', s)), None) => fn_match(rest, s),
+            _ => false,
+        },
+        Some((b'\\', rest)) if !rest.is_empty() => {
+            s.first() == Some(&rest[0]) && fn_match(&rest[1..], &s[1..])
+        }
+        Some((p, rest)) => s.first() == Some(p) && fn_match(rest, &s[1..]),
+    }
+}
+
+// split `[set]rest`, `]` directly after `[` (or `[!`) is a part of the set
+fn bracket(pattern: &[u8]) -> Option<(&[u8], &[u8])> {
+    let start = match pattern.first() {
+        Some(b'!') | Some(b'^') => 1,
+        _ => 0,
+    };
+    let end = pattern
+        .iter()
+        .skip(start + 1)
+        .position(|c| *c == b']')
+        .map(|i| i + start + 1)?;
+    Some((&pattern[..end], &pattern[end + 1..]))
+}
+
+fn set_match(set: &[u8], c: u8) -> bool {
+    let (negate, set) = match set.first() {
+        Some(b'!') | Some(b'^') => (true, &set[1..]),
+        _ => (false, set),
+    };
+    let mut matched = false;
+    let mut i = 0;
+    while i < set.len() {
+        if i + 2 < set.len() && set[i + 1] == b'-' {
+            matched |= set[i] <= c && c <= set[i + 2];
+            i += 3;
+        } else {
+            matched |= set[i] == c;
+            i += 1;
+        }
+    }
+    matched != negate
+}
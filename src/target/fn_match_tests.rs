@@ -0,0 +1,16 @@
+#[test]
+fn fn_match() {
+    use crate::fn_match;
+
+    assert!(fn_match(b"a*c", b"abbc"));
+    assert!(fn_match(b"a*c", b"ac"));
+    assert!(!fn_match(b"a*c", b"acb"));
+    assert!(fn_match(b"a?c", b"abc"));
+    assert!(!fn_match(b"a?c", b"ac"));
+    assert!(fn_match(b"[a-c]x", b"bx"));
+    assert!(!fn_match(b"[!a-c]x", b"bx"));
+    assert!(fn_match(b"[]]", b"]"));
+    assert!(fn_match(b"\\*", b"*"));
+    assert!(!fn_match(b"\\*", b"a"));
+    assert!(fn_match(b"[a", b"[a"));
+}
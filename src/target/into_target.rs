@@ -0,0 +1,141 @@
+use crate::{
+    ClientName, PaneId, SessionId, TargetPaneExt, TargetSession, TargetWindowExt, WindowId,
+};
+use std::borrow::Cow;
+
+// Kind of target expected by a command builder setter (`-t target-pane`, `-s src-window`, ...)
+//
+// Strings are accepted for every kind (unchecked), typed targets only for their own kind, so a
+// `SessionId` can't be used where a pane is expected.
+
+/// Conversion of a target into the argument of a command
+pub trait IntoTarget<'a> {
+    fn into_target(self) -> Cow<'a, str>;
+}
+
+/// Types usable as `target-session` of a command
+pub trait IntoTargetSession<'a>: IntoTarget<'a> {}
+
+/// Types usable as `target-window` of a command
+pub trait IntoTargetWindow<'a>: IntoTarget<'a> {}
+
+/// Types usable as `target-pane` of a command
+pub trait IntoTargetPane<'a>: IntoTarget<'a> {}
+
+/// Types usable as `target-client` of a command
+pub trait IntoTargetClient<'a>: IntoTarget<'a> {}
+
+impl<'a> IntoTarget<'a> for &'a str {
+    fn into_target(self) -> Cow<'a, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<'a> IntoTarget<'a> for &'a String {
+    fn into_target(self) -> Cow<'a, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<'a> IntoTarget<'a> for String {
+    fn into_target(self) -> Cow<'a, str> {
+        Cow::Owned(self)
+    }
+}
+
+impl<'a> IntoTarget<'a> for Cow<'a, str> {
+    fn into_target(self) -> Cow<'a, str> {
+        self
+    }
+}
+
+macro_rules! impl_into_target_display {
+    ($($type:ty),*) => {
+        $(
+            impl<'a> IntoTarget<'a> for $type {
+                fn into_target(self) -> Cow<'a, str> {
+                    Cow::Owned(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_into_target_display!(SessionId, WindowId, PaneId);
+
+impl<'a, 'b> IntoTarget<'a> for TargetSession<'b> {
+    fn into_target(self) -> Cow<'a, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl<'a, 'b> IntoTarget<'a> for &TargetSession<'b> {
+    fn into_target(self) -> Cow<'a, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl<'a, 'b> IntoTarget<'a> for TargetWindowExt<'b> {
+    fn into_target(self) -> Cow<'a, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl<'a, 'b> IntoTarget<'a> for &TargetWindowExt<'b> {
+    fn into_target(self) -> Cow<'a, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl<'a, 'b> IntoTarget<'a> for TargetPaneExt<'b> {
+    fn into_target(self) -> Cow<'a, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl<'a, 'b> IntoTarget<'a> for &TargetPaneExt<'b> {
+    fn into_target(self) -> Cow<'a, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl<'a> IntoTarget<'a> for ClientName {
+    fn into_target(self) -> Cow<'a, str> {
+        Cow::Owned(self.0)
+    }
+}
+
+impl<'a> IntoTarget<'a> for &'a ClientName {
+    fn into_target(self) -> Cow<'a, str> {
+        Cow::Borrowed(&self.0)
+    }
+}
+
+macro_rules! impl_into_target_for_strings {
+    ($trait:ident) => {
+        impl<'a> $trait<'a> for &'a str {}
+        impl<'a> $trait<'a> for &'a String {}
+        impl<'a> $trait<'a> for String {}
+        impl<'a> $trait<'a> for Cow<'a, str> {}
+    };
+}
+
+impl_into_target_for_strings!(IntoTargetSession);
+impl_into_target_for_strings!(IntoTargetWindow);
+impl_into_target_for_strings!(IntoTargetPane);
+impl_into_target_for_strings!(IntoTargetClient);
+
+impl<'a> IntoTargetSession<'a> for SessionId {}
+impl<'a, 'b> IntoTargetSession<'a> for TargetSession<'b> {}
+impl<'a, 'b> IntoTargetSession<'a> for &TargetSession<'b> {}
+
+impl<'a> IntoTargetWindow<'a> for WindowId {}
+impl<'a, 'b> IntoTargetWindow<'a> for TargetWindowExt<'b> {}
+impl<'a, 'b> IntoTargetWindow<'a> for &TargetWindowExt<'b> {}
+
+impl<'a> IntoTargetPane<'a> for PaneId {}
+impl<'a, 'b> IntoTargetPane<'a> for TargetPaneExt<'b> {}
+impl<'a, 'b> IntoTargetPane<'a> for &TargetPaneExt<'b> {}
+
+impl<'a> IntoTargetClient<'a> for ClientName {}
+impl<'a> IntoTargetClient<'a> for &'a ClientName {}
@@ -0,0 +1,44 @@
+#[test]
+fn into_target() {
+    use crate::{
+        ClientName, DetachClient, PaneId, SelectWindow, SessionId, SplitWindow, SwitchClient,
+        TargetPaneExt, TargetSession, TargetWindowExt, WindowId,
+    };
+    use std::borrow::Cow;
+
+    let cmd = SplitWindow::new().target_pane(PaneId(1)).build().to_vec();
+    assert_eq!(cmd.last(), Some(&Cow::Borrowed("%1")));
+    let target_pane: TargetPaneExt = "main:1.2".parse().unwrap();
+    let cmd = SplitWindow::new()
+        .target_pane(&target_pane)
+        .build()
+        .to_vec();
+    assert_eq!(cmd.last(), Some(&Cow::Borrowed("main:1.2")));
+
+    let cmd = SelectWindow::new()
+        .target_window(WindowId(2))
+        .build()
+        .to_vec();
+    assert_eq!(cmd.last(), Some(&Cow::Borrowed("@2")));
+    let target_session = TargetSession::new("main");
+    let target_window = TargetWindowExt::index(Some(&target_session), 3);
+    let cmd = SelectWindow::new()
+        .target_window(target_window)
+        .build()
+        .to_vec();
+    assert_eq!(cmd.last(), Some(&Cow::Borrowed("main:3")));
+
+    let cmd = SwitchClient::new()
+        .target_session(SessionId(3))
+        .build()
+        .to_vec();
+    assert_eq!(cmd.last(), Some(&Cow::Borrowed("$3")));
+
+    let client = ClientName::from("/dev/pts/1");
+    let cmd = DetachClient::new().target_client(&client).build().to_vec();
+    assert_eq!(cmd.last(), Some(&Cow::Borrowed("/dev/pts/1")));
+
+    // strings are accepted as any kind of target
+    let cmd = SelectWindow::new().target_window("main:4").build().to_vec();
+    assert_eq!(cmd.last(), Some(&Cow::Borrowed("main:4")));
+}
@@ -0,0 +1,67 @@
+//! The [`target`][`crate::target`] module contains functions for building targets for tmux
+//! commands
+//!
+//! * TargetPane
+//!     * token (+, -, {...}) instead of name
+//!     * index instead of name
+//!     * id (%id) instead of name
+//!     * exact name (=name)
+//!     * start of a name
+//!     * fn_match
+//!
+//! * TargetWindow
+//!     * token (^, $, !, +, -) instead of name
+//!     * index instead of name
+//!     * id (@id) instead of name
+//!     * exact name (=name)
+//!     * start of a name
+//!     * fn_match
+//!
+//! TargetSession
+//!     * id ($id) instead of name
+//!     * exact name (=name)
+//!     * start of a name
+//!     * fn_match
+//!
+//! All targets can be parsed from strings (`"session:window.pane".parse::<TargetPaneExt>()`),
+//! parsed targets own their names
+//!
+//! Command builders accept typed targets of the expected kind or strings
+//! ([`IntoTargetPane`], [`IntoTargetWindow`], [`IntoTargetSession`], [`IntoTargetClient`])
+//!
+//! # See Also
+//! [Tmux Manual -> Commands](https://man7.org/linux/man-pages/man1/tmux.1.html#COMMANDS)
+//!
+//!
+pub mod fn_match;
+pub mod into_target;
+pub mod target_id;
+pub mod target_pane;
+#[cfg(feature = "tmux_2_0")]
+pub mod target_resolver;
+pub mod target_session;
+pub mod target_window;
+
+pub use fn_match::fn_match;
+pub use into_target::{
+    IntoTarget, IntoTargetClient, IntoTargetPane, IntoTargetSession, IntoTargetWindow,
+};
+pub use target_id::{ClientName, PaneId, SessionId, WindowId};
+pub use target_pane::{TargetPane, TargetPaneExt, TargetPaneToken};
+#[cfg(feature = "tmux_2_0")]
+pub use target_resolver::TargetResolver;
+pub use target_session::TargetSession;
+pub use target_window::{TargetWindow, TargetWindowExt, TargetWindowToken};
+
+#[cfg(test)]
+#[path = "."]
+mod target_tests {
+    pub mod fn_match_tests;
+    pub mod into_target_tests;
+    pub mod target_id_tests;
+    pub mod target_pane_tests;
+    #[cfg(feature = "tmux_2_0")]
+    pub mod target_resolver_tests;
+    pub mod target_session_tests;
+    pub mod target_window_tests;
+}
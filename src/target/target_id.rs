@@ -0,0 +1,129 @@
+use crate::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// session id prefix (`$1`)
+pub const SESSION_ID_PREFIX: char = '$';
+/// window id prefix (`@1`)
+pub const WINDOW_ID_PREFIX: char = '@';
+/// pane id prefix (`%1`)
+pub const PANE_ID_PREFIX: char = '%';
+
+/// Unique session id (`$id`)
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::SessionId;
+///
+/// let session_id: SessionId = "$1".parse().unwrap();
+/// assert_eq!(session_id, SessionId(1));
+/// assert_eq!(session_id.to_string(), "$1");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SessionId(pub usize);
+
+/// Unique window id (`@id`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WindowId(pub usize);
+
+/// Unique pane id (`%id`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PaneId(pub usize);
+
+impl fmt::Display for SessionId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", SESSION_ID_PREFIX, self.0)
+    }
+}
+
+impl fmt::Display for WindowId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", WINDOW_ID_PREFIX, self.0)
+    }
+}
+
+impl fmt::Display for PaneId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", PANE_ID_PREFIX, self.0)
+    }
+}
+
+impl FromStr for SessionId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s
+            .strip_prefix(SESSION_ID_PREFIX)
+            .ok_or(Error::ParseSessionId)?;
+        Ok(SessionId(id.parse().map_err(|_| Error::ParseSessionId)?))
+    }
+}
+
+impl FromStr for WindowId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s
+            .strip_prefix(WINDOW_ID_PREFIX)
+            .ok_or(Error::ParseWindowId)?;
+        Ok(WindowId(id.parse().map_err(|_| Error::ParseWindowId)?))
+    }
+}
+
+impl FromStr for PaneId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s.strip_prefix(PANE_ID_PREFIX).ok_or(Error::ParsePaneId)?;
+        Ok(PaneId(id.parse().map_err(|_| Error::ParsePaneId)?))
+    }
+}
+
+impl From<usize> for SessionId {
+    fn from(id: usize) -> Self {
+        SessionId(id)
+    }
+}
+
+impl From<usize> for WindowId {
+    fn from(id: usize) -> Self {
+        WindowId(id)
+    }
+}
+
+impl From<usize> for PaneId {
+    fn from(id: usize) -> Self {
+        PaneId(id)
+    }
+}
+
+/// Client name (tty path or name of a control mode client, e.g. `/dev/pts/1`, `client-1234`)
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClientName(pub String);
+
+impl fmt::Display for ClientName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for ClientName {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(ClientName(s.to_string()))
+    }
+}
+
+impl From<&str> for ClientName {
+    fn from(name: &str) -> Self {
+        ClientName(name.to_string())
+    }
+}
+
+impl From<String> for ClientName {
+    fn from(name: String) -> Self {
+        ClientName(name)
+    }
+}
@@ -0,0 +1,17 @@
+#[test]
+fn target_id() {
+    use crate::{PaneId, SessionId, WindowId};
+
+    assert_eq!("$1".parse::<SessionId>().unwrap(), SessionId(1));
+    assert_eq!(SessionId(1).to_string(), "$1");
+    assert!("1".parse::<SessionId>().is_err());
+    assert!("@1".parse::<SessionId>().is_err());
+
+    assert_eq!("@2".parse::<WindowId>().unwrap(), WindowId(2));
+    assert_eq!(WindowId(2).to_string(), "@2");
+    assert!("@".parse::<WindowId>().is_err());
+
+    assert_eq!("%3".parse::<PaneId>().unwrap(), PaneId(3));
+    assert_eq!(PaneId(3).to_string(), "%3");
+    assert!("%a".parse::<PaneId>().is_err());
+}
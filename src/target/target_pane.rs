@@ -0,0 +1,373 @@
+use super::target_id::PANE_ID_PREFIX;
+use super::target_session::{TARGET_EXACT_NAME_PREFIX, TARGET_FN_MATCH_CHARS};
+use super::target_window::{parse_offset, TARGET_WINDOW_SEPARATOR};
+use super::TargetWindowExt;
+use crate::{Error, PaneId};
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+/// separator between `target-window` and `target-pane` (`session:window.pane`)
+pub const TARGET_PANE_SEPARATOR: char = '.';
+
+/// Extended `target-pane` struct, includes `target-window` (may indirect include `target-session`)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TargetPaneExt<'a> {
+    /// `target-window`, borrowed or owned (if parsed)
+    pub target_window: Option<Cow<'a, TargetWindowExt<'a>>>,
+    /// `target-pane`
+    pub target_pane: Option<TargetPane<'a>>,
+}
+
+impl<'a> TargetPaneExt<'a> {
+    /// simple initializing as start of a name
+    pub fn new<S: Into<Cow<'a, str>>>(target_pane: S) -> Self {
+        TargetPaneExt {
+            target_window: None,
+            target_pane: Some(TargetPane::StartName(target_pane.into())),
+        }
+    }
+
+    pub fn token(target_window: Option<&'a TargetWindowExt<'a>>, token: TargetPaneToken) -> Self {
+        TargetPaneExt {
+            target_window: target_window.map(Cow::Borrowed),
+            target_pane: Some(TargetPane::Token(token)),
+        }
+    }
+
+    pub fn index(target_window: Option<&'a TargetWindowExt<'a>>, index: usize) -> Self {
+        TargetPaneExt {
+            target_window: target_window.map(Cow::Borrowed),
+            target_pane: Some(TargetPane::Index(index)),
+        }
+    }
+
+    pub fn id<I: Into<PaneId>>(target_window: Option<&'a TargetWindowExt<'a>>, id: I) -> Self {
+        TargetPaneExt {
+            target_window: target_window.map(Cow::Borrowed),
+            target_pane: Some(TargetPane::Id(id.into())),
+        }
+    }
+
+    pub fn exact_name<S: Into<Cow<'a, str>>>(
+        target_window: Option<&'a TargetWindowExt<'a>>,
+        name: S,
+    ) -> Self {
+        TargetPaneExt {
+            target_window: target_window.map(Cow::Borrowed),
+            target_pane: Some(TargetPane::ExactName(name.into())),
+        }
+    }
+
+    pub fn start_name<S: Into<Cow<'a, str>>>(
+        target_window: Option<&'a TargetWindowExt<'a>>,
+        name: S,
+    ) -> Self {
+        TargetPaneExt {
+            target_window: target_window.map(Cow::Borrowed),
+            target_pane: Some(TargetPane::StartName(name.into())),
+        }
+    }
+
+    pub fn fn_match<S: Into<Cow<'a, str>>>(
+        target_window: Option<&'a TargetWindowExt<'a>>,
+        name: S,
+    ) -> Self {
+        TargetPaneExt {
+            target_window: target_window.map(Cow::Borrowed),
+            target_pane: Some(TargetPane::FnMatch(name.into())),
+        }
+    }
+
+    // XXX: draft $1:@2.raw_name or .raw_name or raw_name:raw_name.raw_name?
+    pub fn raw<S: Into<Cow<'a, str>>>(name: S) -> Self {
+        TargetPaneExt {
+            target_window: None,
+            target_pane: Some(TargetPane::Raw(name.into())),
+        }
+    }
+}
+
+impl<'a> fmt::Display for TargetPaneExt<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut w = String::new();
+        let mut p = String::new();
+        if let Some(ref target_window) = self.target_window {
+            w = target_window.to_string();
+            // id is used without separator if it's the whole target (`%1`, `main:1.%1`)
+            if let Some(TargetPane::Id(_)) = self.target_pane {
+                w.push(TARGET_PANE_SEPARATOR);
+            }
+        }
+        if let Some(ref target_pane) = self.target_pane {
+            p = target_pane.to_string();
+        }
+        write!(f, "{}{}", w, p)
+    }
+}
+
+/// Parse `target-pane` (`session:window.pane`, `%id`, pane token or any `target-window`)
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{PaneId, TargetPane, TargetPaneExt, TargetPaneToken};
+///
+/// let target_pane: TargetPaneExt = "main:2.{bottom-right}".parse().unwrap();
+/// assert_eq!(
+///     target_pane.target_pane,
+///     Some(TargetPane::Token(TargetPaneToken::BottomRight))
+/// );
+/// assert_eq!(target_pane.to_string(), "main:2.{bottom-right}");
+///
+/// let target_pane: TargetPaneExt = "%3".parse().unwrap();
+/// assert_eq!(target_pane.target_pane, Some(TargetPane::Id(PaneId(3))));
+/// ```
+impl FromStr for TargetPaneExt<'static> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut target_pane = TargetPaneExt::default();
+        if s.starts_with(PANE_ID_PREFIX) || s.parse::<TargetPaneToken>().is_ok() {
+            target_pane.target_pane = Some(s.parse()?);
+            return Ok(target_pane);
+        }
+
+        // pane separator is searched after window separator (session names can't contain `.`)
+        let start = s.find(TARGET_WINDOW_SEPARATOR).unwrap_or(0);
+        match s[start..].find(TARGET_PANE_SEPARATOR) {
+            Some(i) => {
+                let i = start + i;
+                if i > 0 {
+                    target_pane.target_window = Some(Cow::Owned(s[..i].parse()?));
+                }
+                if i + 1 < s.len() {
+                    target_pane.target_pane = Some(s[i + 1..].parse()?);
+                }
+                if target_pane.target_window.is_none() && target_pane.target_pane.is_none() {
+                    return Err(Error::ParseTargetPane);
+                }
+            }
+            None => target_pane.target_window = Some(Cow::Owned(s.parse()?)),
+        }
+        Ok(target_pane)
+    }
+}
+
+/// Enum for possible `target-pane` variants
+#[derive(Clone, Debug, PartialEq)]
+pub enum TargetPane<'a> {
+    /// token (+, -, {...}) instead of name
+    Token(TargetPaneToken),
+    /// index instead of name
+    Index(usize),
+    /// id (%id) instead of name
+    Id(PaneId),
+    /// exact name (=name)
+    ExactName(Cow<'a, str>),
+    /// start of a name
+    StartName(Cow<'a, str>),
+    /// fn_match
+    FnMatch(Cow<'a, str>),
+    /// manual define full name (no `.` will be added)
+    Raw(Cow<'a, str>),
+}
+
+impl<'a> Default for TargetPane<'a> {
+    fn default() -> Self {
+        TargetPane::Raw(Cow::Borrowed(""))
+    }
+}
+
+impl<'a> fmt::Display for TargetPane<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TargetPane::Token(token) => write!(f, ".{}", token),
+            TargetPane::Index(i) => write!(f, ".{}", i),
+            TargetPane::Id(id) => write!(f, "{}", id),
+            TargetPane::ExactName(name) => write!(f, ".={}", name),
+            TargetPane::StartName(name) => write!(f, ".{}", name),
+            TargetPane::FnMatch(name) => write!(f, ".{}", name),
+            TargetPane::Raw(raw_str) => write!(f, "{}", raw_str),
+        }
+    }
+}
+
+/// Parse pane part of the `target-pane` (leading `.` is optional)
+impl FromStr for TargetPane<'static> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix(TARGET_PANE_SEPARATOR).unwrap_or(s);
+        if s.is_empty() {
+            Err(Error::ParseTargetPane)
+        } else if s.starts_with(PANE_ID_PREFIX) {
+            Ok(TargetPane::Id(
+                s.parse().map_err(|_| Error::ParseTargetPane)?,
+            ))
+        } else if let Ok(token) = s.parse() {
+            Ok(TargetPane::Token(token))
+        } else if let Ok(i) = s.parse() {
+            Ok(TargetPane::Index(i))
+        } else if let Some(name) = s.strip_prefix(TARGET_EXACT_NAME_PREFIX) {
+            Ok(TargetPane::ExactName(Cow::Owned(name.to_string())))
+        } else if s.contains(TARGET_FN_MATCH_CHARS) {
+            Ok(TargetPane::FnMatch(Cow::Owned(s.to_string())))
+        } else {
+            Ok(TargetPane::StartName(Cow::Owned(s.to_string())))
+        }
+    }
+}
+
+/// Enum for `target-pane` tokens
+#[derive(Clone, Debug, PartialEq)]
+pub enum TargetPaneToken {
+    /// {last} ! The last (previously active) pane
+    Last,
+    /// {next} + The next pane by number
+    Next(Option<usize>),
+    /// {previous} - The previous pane by number
+    Previous(Option<usize>),
+    /// {top} The top pane
+    Top,
+    /// {bottom} The bottom pane
+    Bottom,
+    /// {left} The leftmost pane
+    Left,
+    /// {right} The rightmost pane
+    Right,
+    /// {top-left} The top-left pane
+    TopLeft,
+    /// {top-right} The top-right pane
+    TopRight,
+    /// {bottom-left} The bottom-left pane
+    BottomLeft,
+    /// {bottom-right} The bottom-right pane
+    BottomRight,
+    /// {up-of} The pane above the active pane
+    UpOf,
+    /// {down-of} The pane below the active pane
+    DownOf,
+    /// {left-of} The pane to the left of the active pane
+    LeftOf,
+    /// {right-of} The pane to the right of the active pane
+    RightOf,
+    //// {mouse} = most recent mouse event occurred
+    //Mouse,
+}
+
+// {last}
+const TARGET_PANE_TOKEN_LAST: &str = "!";
+// {next}
+const TARGET_PANE_TOKEN_NEXT: &str = "+";
+// {previous}
+const TARGET_PANE_TOKEN_PREVIOUS: &str = "-";
+// {top}
+const TARGET_PANE_TOKEN_TOP: &str = "{top}";
+// {bottom}
+const TARGET_PANE_TOKEN_BOTTOM: &str = "{bottom}";
+// {left}
+const TARGET_PANE_TOKEN_LEFT: &str = "{left}";
+// {right}
+const TARGET_PANE_TOKEN_RIGHT: &str = "{right}";
+// {top-left}
+const TARGET_PANE_TOKEN_TOP_LEFT: &str = "{top-left}";
+// {top-right}
+const TARGET_PANE_TOKEN_TOP_RIGHT: &str = "{top-right}";
+// {bottom-left}
+const TARGET_PANE_TOKEN_BOTTOM_LEFT: &str = "{bottom-left}";
+// {bottom-right}
+const TARGET_PANE_TOKEN_BOTTOM_RIGHT: &str = "{bottom-right}";
+// {up-of}
+const TARGET_PANE_TOKEN_UP_OF: &str = "{up-of}";
+// {down-of}
+const TARGET_PANE_TOKEN_DOWN_OF: &str = "{down-of}";
+// {left-of}
+const TARGET_PANE_TOKEN_LEFT_OF: &str = "{left-of}";
+// {right-of}
+const TARGET_PANE_TOKEN_RIGHT_OF: &str = "{right-of}";
+
+impl fmt::Display for TargetPaneToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let a;
+        let s = match self {
+            TargetPaneToken::Last => TARGET_PANE_TOKEN_LAST,
+            TargetPaneToken::Next(offset) => {
+                if let Some(n) = offset {
+                    a = format!("{}{}", TARGET_PANE_TOKEN_NEXT, n);
+                    &a
+                } else {
+                    TARGET_PANE_TOKEN_NEXT
+                }
+            }
+            TargetPaneToken::Previous(offset) => {
+                if let Some(n) = offset {
+                    a = format!("{}{}", TARGET_PANE_TOKEN_PREVIOUS, n);
+                    &a
+                } else {
+                    TARGET_PANE_TOKEN_PREVIOUS
+                }
+            }
+            TargetPaneToken::Top => TARGET_PANE_TOKEN_TOP,
+            TargetPaneToken::Bottom => TARGET_PANE_TOKEN_BOTTOM,
+            TargetPaneToken::Left => TARGET_PANE_TOKEN_LEFT,
+            TargetPaneToken::Right => TARGET_PANE_TOKEN_RIGHT,
+            TargetPaneToken::TopLeft => TARGET_PANE_TOKEN_TOP_LEFT,
+            TargetPaneToken::TopRight => TARGET_PANE_TOKEN_TOP_RIGHT,
+            TargetPaneToken::BottomLeft => TARGET_PANE_TOKEN_BOTTOM_LEFT,
+            TargetPaneToken::BottomRight => TARGET_PANE_TOKEN_BOTTOM_RIGHT,
+            TargetPaneToken::UpOf => TARGET_PANE_TOKEN_UP_OF,
+            TargetPaneToken::DownOf => TARGET_PANE_TOKEN_DOWN_OF,
+            TargetPaneToken::LeftOf => TARGET_PANE_TOKEN_LEFT_OF,
+            TargetPaneToken::RightOf => TARGET_PANE_TOKEN_RIGHT_OF,
+        };
+        f.write_str(s)
+    }
+}
+
+// long forms of the short tokens
+const TARGET_PANE_TOKEN_LAST_LONG: &str = "{last}";
+const TARGET_PANE_TOKEN_NEXT_LONG: &str = "{next}";
+const TARGET_PANE_TOKEN_PREVIOUS_LONG: &str = "{previous}";
+
+/// Parse long (`{top}`, `{last}`) or short (`!`, `+2`) form of the token
+impl FromStr for TargetPaneToken {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            TARGET_PANE_TOKEN_LAST | TARGET_PANE_TOKEN_LAST_LONG => Ok(TargetPaneToken::Last),
+            TARGET_PANE_TOKEN_NEXT | TARGET_PANE_TOKEN_NEXT_LONG => Ok(TargetPaneToken::Next(None)),
+            TARGET_PANE_TOKEN_PREVIOUS | TARGET_PANE_TOKEN_PREVIOUS_LONG => {
+                Ok(TargetPaneToken::Previous(None))
+            }
+            TARGET_PANE_TOKEN_TOP => Ok(TargetPaneToken::Top),
+            TARGET_PANE_TOKEN_BOTTOM => Ok(TargetPaneToken::Bottom),
+            TARGET_PANE_TOKEN_LEFT => Ok(TargetPaneToken::Left),
+            TARGET_PANE_TOKEN_RIGHT => Ok(TargetPaneToken::Right),
+            TARGET_PANE_TOKEN_TOP_LEFT => Ok(TargetPaneToken::TopLeft),
+            TARGET_PANE_TOKEN_TOP_RIGHT => Ok(TargetPaneToken::TopRight),
+            TARGET_PANE_TOKEN_BOTTOM_LEFT => Ok(TargetPaneToken::BottomLeft),
+            TARGET_PANE_TOKEN_BOTTOM_RIGHT => Ok(TargetPaneToken::BottomRight),
+            TARGET_PANE_TOKEN_UP_OF => Ok(TargetPaneToken::UpOf),
+            TARGET_PANE_TOKEN_DOWN_OF => Ok(TargetPaneToken::DownOf),
+            TARGET_PANE_TOKEN_LEFT_OF => Ok(TargetPaneToken::LeftOf),
+            TARGET_PANE_TOKEN_RIGHT_OF => Ok(TargetPaneToken::RightOf),
+            _ => {
+                if let Some(n) = s.strip_prefix(TARGET_PANE_TOKEN_NEXT) {
+                    Ok(TargetPaneToken::Next(Some(parse_offset(
+                        n,
+                        Error::ParseTargetPane,
+                    )?)))
+                } else if let Some(n) = s.strip_prefix(TARGET_PANE_TOKEN_PREVIOUS) {
+                    Ok(TargetPaneToken::Previous(Some(parse_offset(
+                        n,
+                        Error::ParseTargetPane,
+                    )?)))
+                } else {
+                    Err(Error::ParseTargetPane)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,203 @@
+#[test]
+fn target_pane_ex() {
+    use crate::{TargetPane, TargetPaneExt, TargetPaneToken};
+
+    let target_pane = TargetPaneExt {
+        target_window: None,
+        target_pane: Some(TargetPane::Token(TargetPaneToken::Next(None))),
+    };
+    assert_eq!(target_pane.to_string(), ".+");
+}
+
+#[test]
+fn target_pane() {
+    use crate::{PaneId, TargetPane, TargetPaneToken};
+
+    let target_pane = TargetPane::Token(TargetPaneToken::Next(None));
+    assert_eq!(target_pane.to_string(), ".+");
+    let target_pane = TargetPane::Index(1);
+    assert_eq!(target_pane.to_string(), ".1");
+    let target_pane = TargetPane::Id(PaneId(1));
+    assert_eq!(target_pane.to_string(), "%1");
+    let target_pane = TargetPane::ExactName("exact_name".into());
+    assert_eq!(target_pane.to_string(), ".=exact_name");
+    let target_pane = TargetPane::StartName("start_name".into());
+    assert_eq!(target_pane.to_string(), ".start_name");
+    let target_pane = TargetPane::FnMatch("fn_match".into());
+    assert_eq!(target_pane.to_string(), ".fn_match");
+    let target_pane = TargetPane::Raw("raw".into());
+    assert_eq!(target_pane.to_string(), "raw");
+}
+
+#[test]
+fn target_pane_token() {
+    use crate::{TargetPane, TargetPaneToken};
+
+    let type_token = TargetPane::Token(TargetPaneToken::Next(Some(1))).to_string();
+    assert_eq!(type_token.to_string(), ".+1");
+    let type_token = TargetPane::Token(TargetPaneToken::Next(None)).to_string();
+    assert_eq!(type_token.to_string(), ".+");
+    let type_token = TargetPane::Token(TargetPaneToken::Previous(Some(1))).to_string();
+    assert_eq!(type_token.to_string(), ".-1");
+    let type_token = TargetPane::Token(TargetPaneToken::Previous(None)).to_string();
+    assert_eq!(type_token.to_string(), ".-");
+    let type_token = TargetPane::Token(TargetPaneToken::Top).to_string();
+    assert_eq!(type_token.to_string(), ".{top}");
+    let type_token = TargetPane::Token(TargetPaneToken::Bottom).to_string();
+    assert_eq!(type_token.to_string(), ".{bottom}");
+    let type_token = TargetPane::Token(TargetPaneToken::Left).to_string();
+    assert_eq!(type_token.to_string(), ".{left}");
+    let type_token = TargetPane::Token(TargetPaneToken::Right).to_string();
+    assert_eq!(type_token.to_string(), ".{right}");
+    let type_token = TargetPane::Token(TargetPaneToken::BottomLeft).to_string();
+    assert_eq!(type_token.to_string(), ".{bottom-left}");
+    let type_token = TargetPane::Token(TargetPaneToken::BottomRight).to_string();
+    assert_eq!(type_token.to_string(), ".{bottom-right}");
+    let type_token = TargetPane::Token(TargetPaneToken::UpOf).to_string();
+    assert_eq!(type_token.to_string(), ".{up-of}");
+    let type_token = TargetPane::Token(TargetPaneToken::DownOf).to_string();
+    assert_eq!(type_token.to_string(), ".{down-of}");
+    let type_token = TargetPane::Token(TargetPaneToken::LeftOf).to_string();
+    assert_eq!(type_token.to_string(), ".{left-of}");
+    let type_token = TargetPane::Token(TargetPaneToken::RightOf).to_string();
+    assert_eq!(type_token.to_string(), ".{right-of}");
+}
+
+#[test]
+fn target_pane_token_parse() {
+    use crate::TargetPaneToken;
+
+    assert_eq!(
+        "!".parse::<TargetPaneToken>().unwrap(),
+        TargetPaneToken::Last
+    );
+    assert_eq!(
+        "{last}".parse::<TargetPaneToken>().unwrap(),
+        TargetPaneToken::Last
+    );
+    assert_eq!(
+        "+".parse::<TargetPaneToken>().unwrap(),
+        TargetPaneToken::Next(None)
+    );
+    assert_eq!(
+        "{next}".parse::<TargetPaneToken>().unwrap(),
+        TargetPaneToken::Next(None)
+    );
+    assert_eq!(
+        "+1".parse::<TargetPaneToken>().unwrap(),
+        TargetPaneToken::Next(Some(1))
+    );
+    assert_eq!(
+        "{previous}".parse::<TargetPaneToken>().unwrap(),
+        TargetPaneToken::Previous(None)
+    );
+    assert_eq!(
+        "-2".parse::<TargetPaneToken>().unwrap(),
+        TargetPaneToken::Previous(Some(2))
+    );
+    assert_eq!(
+        "{top}".parse::<TargetPaneToken>().unwrap(),
+        TargetPaneToken::Top
+    );
+    assert_eq!(
+        "{bottom-right}".parse::<TargetPaneToken>().unwrap(),
+        TargetPaneToken::BottomRight
+    );
+    assert_eq!(
+        "{up-of}".parse::<TargetPaneToken>().unwrap(),
+        TargetPaneToken::UpOf
+    );
+    assert_eq!(
+        "{right-of}".parse::<TargetPaneToken>().unwrap(),
+        TargetPaneToken::RightOf
+    );
+    assert!("{start}".parse::<TargetPaneToken>().is_err());
+    assert!("^".parse::<TargetPaneToken>().is_err());
+}
+
+#[test]
+fn target_pane_parse() {
+    use crate::{
+        PaneId, TargetPane, TargetPaneExt, TargetPaneToken, TargetSession, TargetWindow,
+        TargetWindowExt,
+    };
+    use std::borrow::Cow;
+
+    let target_pane: TargetPaneExt = "session:window.1".parse().unwrap();
+    let target_window = TargetWindowExt {
+        session: Some(Cow::Owned(TargetSession::start_name("session"))),
+        window: Some(TargetWindow::StartName("window".into())),
+    };
+    assert_eq!(target_pane.target_window.as_deref(), Some(&target_window));
+    assert_eq!(target_pane.target_pane, Some(TargetPane::Index(1)));
+    assert_eq!(target_pane.to_string(), "session:window.1");
+
+    let target_pane: TargetPaneExt = "=session:{end}.{top-left}".parse().unwrap();
+    assert_eq!(
+        target_pane.target_pane,
+        Some(TargetPane::Token(TargetPaneToken::TopLeft))
+    );
+    assert_eq!(target_pane.to_string(), "=session:$.{top-left}");
+
+    let target_pane: TargetPaneExt = "%3".parse().unwrap();
+    assert_eq!(target_pane.target_window, None);
+    assert_eq!(target_pane.target_pane, Some(TargetPane::Id(PaneId(3))));
+
+    let target_pane: TargetPaneExt = "{down-of}".parse().unwrap();
+    assert_eq!(
+        target_pane.target_pane,
+        Some(TargetPane::Token(TargetPaneToken::DownOf))
+    );
+
+    let target_pane: TargetPaneExt = ".=exact_name".parse().unwrap();
+    assert_eq!(target_pane.target_window, None);
+    assert_eq!(
+        target_pane.target_pane,
+        Some(TargetPane::ExactName("exact_name".into()))
+    );
+
+    // window only
+    let target_pane: TargetPaneExt = ":2".parse().unwrap();
+    assert_eq!(target_pane.target_pane, None);
+    assert_eq!(target_pane.to_string(), ":2");
+
+    assert!("".parse::<TargetPaneExt>().is_err());
+    assert!(".".parse::<TargetPaneExt>().is_err());
+    assert!("%a".parse::<TargetPaneExt>().is_err());
+}
+
+#[test]
+fn target_pane_parse_display() {
+    use crate::TargetPaneExt;
+
+    let targets = [
+        "session:window.1",
+        "=session:$.{top-left}",
+        "%3",
+        "main:1.%3",
+        "@1.%3",
+        "$1:@2.1",
+        "$1:@2.%3",
+        ".=exact_name",
+        ".fn_*",
+        ".+1",
+        ".2",
+        ":2",
+        "editor",
+        "editor.1",
+    ];
+    for s in targets.iter() {
+        let target_pane: TargetPaneExt = s.parse().unwrap();
+        assert_eq!(target_pane.to_string(), *s);
+        assert_eq!(
+            target_pane.to_string().parse::<TargetPaneExt>().unwrap(),
+            target_pane
+        );
+    }
+
+    // tokens without `.`
+    let target_pane: TargetPaneExt = "{down-of}".parse().unwrap();
+    assert_eq!(target_pane.to_string(), ".{down-of}");
+    let target_pane: TargetPaneExt = "!".parse().unwrap();
+    assert_eq!(target_pane.to_string(), ".!");
+}
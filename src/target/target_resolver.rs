@@ -0,0 +1,528 @@
+use super::target_id::PANE_ID_PREFIX;
+use super::target_window::TARGET_WINDOW_SEPARATOR;
+use crate::{
+    fn_match, Error, Pane, PaneId, Panes, Session, SessionId, Sessions, TargetPane, TargetPaneExt,
+    TargetPaneToken, TargetSession, TargetWindow, TargetWindowExt, TargetWindowToken, Window,
+    WindowId, Windows,
+};
+use std::fmt;
+
+const TARGET_KIND_SESSION: &str = "session";
+const TARGET_KIND_WINDOW: &str = "window";
+const TARGET_KIND_PANE: &str = "pane";
+
+#[derive(Debug)]
+struct SessionNode<'a> {
+    session: &'a Session,
+    windows: Vec<WindowNode<'a>>,
+}
+
+#[derive(Debug)]
+struct WindowNode<'a> {
+    window: &'a Window,
+    panes: Vec<&'a Pane>,
+}
+
+/// Resolve targets locally (without tmux server) against a snapshot of sessions, windows and
+/// panes, using tmux matching rules
+///
+/// Order of matching for names: exact name, start of a name, fnmatch pattern, every step has to
+/// match exactly one object, otherwise [`Error::TargetAmbiguous`] is returned. Targets without
+/// session use current session (set or the only one), targets without window or pane use active
+/// ones. Names without `:` are looked up as windows of the current session first, then as
+/// sessions, panes can't be targeted by names (titles).
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{Session, SessionId, TargetResolver, TargetWindowExt, Window, WindowId};
+///
+/// let mut session = Session::new();
+/// session.id = Some(SessionId(1));
+/// session.name = Some("main".to_string());
+///
+/// let mut window = Window::new();
+/// window.id = Some(WindowId(2));
+/// window.index = Some(0);
+/// window.name = Some("editor".to_string());
+///
+/// let resolver = TargetResolver::new()
+///     .session(&session)
+///     .window(SessionId(1), &window);
+///
+/// let target_window = "ma:ed".parse::<TargetWindowExt>().unwrap();
+/// let found = resolver.resolve_window(&target_window).unwrap();
+/// assert_eq!(found.id, Some(WindowId(2)));
+/// ```
+#[derive(Debug, Default)]
+pub struct TargetResolver<'a> {
+    sessions: Vec<SessionNode<'a>>,
+    current_session: Option<SessionId>,
+}
+
+impl<'a> TargetResolver<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add session
+    pub fn session(mut self, session: &'a Session) -> Self {
+        self.sessions.push(SessionNode {
+            session,
+            windows: Vec::new(),
+        });
+        self
+    }
+
+    /// Add sessions (`list-sessions`)
+    pub fn sessions(self, sessions: &'a Sessions) -> Self {
+        sessions
+            .0
+            .iter()
+            .fold(self, |r, session| r.session(session))
+    }
+
+    /// Add window of the session (linked windows can be added to multiple sessions)
+    pub fn window(mut self, session_id: SessionId, window: &'a Window) -> Self {
+        for node in self.sessions.iter_mut() {
+            if node.session.id == Some(session_id) {
+                node.windows.push(WindowNode {
+                    window,
+                    panes: Vec::new(),
+                });
+            }
+        }
+        self
+    }
+
+    /// Add windows of the session (`list-windows -t $id`)
+    pub fn windows(self, session_id: SessionId, windows: &'a Windows) -> Self {
+        windows
+            .0
+            .iter()
+            .fold(self, |r, window| r.window(session_id, window))
+    }
+
+    /// Add pane of the window (window must be added before)
+    pub fn pane(mut self, window_id: WindowId, pane: &'a Pane) -> Self {
+        for node in self.sessions.iter_mut().flat_map(|s| s.windows.iter_mut()) {
+            if node.window.id == Some(window_id) {
+                node.panes.push(pane);
+            }
+        }
+        self
+    }
+
+    /// Add panes of the window (`list-panes -t @id`)
+    pub fn panes(self, window_id: WindowId, panes: &'a Panes) -> Self {
+        panes.0.iter().fold(self, |r, pane| r.pane(window_id, pane))
+    }
+
+    /// Session used for targets without session (current session of the client)
+    pub fn current_session(mut self, session_id: SessionId) -> Self {
+        self.current_session = Some(session_id);
+        self
+    }
+
+    /// Find session designated by `target-session`
+    pub fn resolve_session(&self, target: &TargetSession) -> Result<&'a Session, Error> {
+        Ok(self.find_session(target)?.session)
+    }
+
+    /// Find window designated by `target-window`
+    pub fn resolve_window(&self, target: &TargetWindowExt) -> Result<&'a Window, Error> {
+        Ok(self.find_window(target)?.window)
+    }
+
+    /// Find pane designated by `target-pane`
+    pub fn resolve_pane(&self, target: &TargetPaneExt) -> Result<&'a Pane, Error> {
+        self.find_pane(target)
+    }
+
+    fn find_session(&self, target: &TargetSession) -> Result<&SessionNode<'a>, Error> {
+        match target {
+            TargetSession::Id(id) => self
+                .sessions
+                .iter()
+                .find(|s| s.session.id == Some(*id))
+                .ok_or_else(|| not_found(TARGET_KIND_SESSION, target)),
+            TargetSession::ExactName(name) => exact_name(
+                &self.sessions,
+                session_name,
+                name,
+                TARGET_KIND_SESSION,
+                target,
+            ),
+            TargetSession::StartName(name) | TargetSession::FnMatch(name) => match_name(
+                &self.sessions,
+                session_name,
+                name,
+                TARGET_KIND_SESSION,
+                target,
+            ),
+            TargetSession::Raw(raw) => self.find_session(&raw.parse()?),
+        }
+    }
+
+    fn find_current_session(&self) -> Result<&SessionNode<'a>, Error> {
+        match self.current_session {
+            Some(id) => self.find_session(&TargetSession::Id(id)),
+            None => match self.sessions.len() {
+                1 => Ok(&self.sessions[0]),
+                0 => Err(Error::TargetNotFound(TARGET_KIND_SESSION, String::new())),
+                n => Err(Error::TargetAmbiguous(
+                    TARGET_KIND_SESSION,
+                    String::new(),
+                    n,
+                )),
+            },
+        }
+    }
+
+    fn find_window(&self, target: &TargetWindowExt) -> Result<&WindowNode<'a>, Error> {
+        match &target.window {
+            // window ids are unique for the server, session is optional
+            Some(TargetWindow::Id(id)) => {
+                let sessions = match &target.session {
+                    Some(session) => std::slice::from_ref(self.find_session(session)?),
+                    None => &self.sessions[..],
+                };
+                sessions
+                    .iter()
+                    .flat_map(|s| s.windows.iter())
+                    .find(|w| w.window.id == Some(*id))
+                    .ok_or_else(|| not_found(TARGET_KIND_WINDOW, target))
+            }
+            // bare name (`editor`, `%1`), not `session:window` written as raw string
+            Some(TargetWindow::Raw(raw))
+                if target.session.is_none() && !raw.contains(TARGET_WINDOW_SEPARATOR) =>
+            {
+                self.find_bare_window(raw, target)
+            }
+            Some(TargetWindow::Raw(_)) => match target.to_string().parse::<TargetWindowExt>()? {
+                TargetWindowExt {
+                    window: Some(TargetWindow::Raw(_)),
+                    ..
+                } => Err(not_found(TARGET_KIND_WINDOW, target)),
+                parsed => self.find_window(&parsed),
+            },
+            window => {
+                let session = match &target.session {
+                    Some(session) => self.find_session(session)?,
+                    None => self.find_current_session()?,
+                };
+                match window {
+                    Some(window) => self.find_session_window(session, window, target),
+                    None => {
+                        active_window(session).ok_or_else(|| not_found(TARGET_KIND_WINDOW, target))
+                    }
+                }
+            }
+        }
+    }
+
+    // window containing the pane (`%id`), window of the current session or active window of the
+    // session (tmux `cmd_find_get_window`)
+    fn find_bare_window(
+        &self,
+        s: &str,
+        target: &TargetWindowExt,
+    ) -> Result<&WindowNode<'a>, Error> {
+        if s.starts_with(PANE_ID_PREFIX) {
+            let id = s.parse::<PaneId>()?;
+            return self
+                .sessions
+                .iter()
+                .flat_map(|s| s.windows.iter())
+                .find(|w| w.panes.iter().any(|p| p.id == Some(id)))
+                .ok_or_else(|| not_found(TARGET_KIND_WINDOW, target));
+        }
+        let window = match (s.parse::<TargetWindow>(), self.find_current_session()) {
+            (Ok(window), Ok(session)) => self.find_session_window(session, &window, target),
+            _ => Err(not_found(TARGET_KIND_WINDOW, target)),
+        };
+        if window.is_ok() {
+            return window;
+        }
+        match s
+            .parse::<TargetSession>()
+            .map(|session| self.find_session(&session))
+        {
+            Ok(Ok(session)) => {
+                active_window(session).ok_or_else(|| not_found(TARGET_KIND_WINDOW, target))
+            }
+            _ => window,
+        }
+    }
+
+    fn find_session_window<'s>(
+        &self,
+        session: &'s SessionNode<'a>,
+        window: &TargetWindow,
+        target: &TargetWindowExt,
+    ) -> Result<&'s WindowNode<'a>, Error> {
+        let windows = &session.windows;
+        match window {
+            TargetWindow::Token(token) => {
+                let mut sorted: Vec<&WindowNode> = windows.iter().collect();
+                sorted.sort_by_key(|w| w.window.index);
+                let current = sorted.iter().position(|w| w.window.active == Some(true));
+                let found = match token {
+                    TargetWindowToken::Start => sorted.first().copied(),
+                    TargetWindowToken::End => sorted.last().copied(),
+                    TargetWindowToken::Last => sorted
+                        .iter()
+                        .find(|w| w.window.last_flag == Some(true))
+                        .copied(),
+                    TargetWindowToken::Next(n) => {
+                        current.map(|i| *offset(&sorted, i, n.unwrap_or(1) as isize))
+                    }
+                    TargetWindowToken::Previous(n) => {
+                        current.map(|i| *offset(&sorted, i, -(n.unwrap_or(1) as isize)))
+                    }
+                };
+                found.ok_or_else(|| not_found(TARGET_KIND_WINDOW, target))
+            }
+            // index, if not found used as a name
+            TargetWindow::Index(i) => match windows.iter().find(|w| w.window.index == Some(*i)) {
+                Some(window) => Ok(window),
+                None => match_name(
+                    windows,
+                    window_name,
+                    &i.to_string(),
+                    TARGET_KIND_WINDOW,
+                    target,
+                ),
+            },
+            TargetWindow::ExactName(s) => {
+                exact_name(windows, window_name, s, TARGET_KIND_WINDOW, target)
+            }
+            TargetWindow::StartName(s) | TargetWindow::FnMatch(s) => {
+                match_name(windows, window_name, s, TARGET_KIND_WINDOW, target)
+            }
+            TargetWindow::Id(id) => windows
+                .iter()
+                .find(|w| w.window.id == Some(*id))
+                .ok_or_else(|| not_found(TARGET_KIND_WINDOW, target)),
+            TargetWindow::Raw(_) => Err(not_found(TARGET_KIND_WINDOW, target)),
+        }
+    }
+
+    fn find_pane(&self, target: &TargetPaneExt) -> Result<&'a Pane, Error> {
+        // pane ids are unique for the server, window is optional
+        if let Some(TargetPane::Id(id)) = &target.target_pane {
+            let mut windows: Vec<&WindowNode> = Vec::new();
+            match &target.target_window {
+                Some(window) => windows.push(self.find_window(window)?),
+                None => windows.extend(self.sessions.iter().flat_map(|s| s.windows.iter())),
+            }
+            return windows
+                .iter()
+                .flat_map(|w| w.panes.iter())
+                .find(|p| p.id == Some(*id))
+                .copied()
+                .ok_or_else(|| not_found(TARGET_KIND_PANE, target));
+        }
+        if let Some(TargetPane::Raw(_)) = &target.target_pane {
+            return self.find_pane(&target.to_string().parse()?);
+        }
+
+        let window = match &target.target_window {
+            Some(window) => self.find_window(window)?,
+            None => self.find_window(&TargetWindowExt::default())?,
+        };
+        let panes = &window.panes;
+        let active = panes.iter().position(|p| p.active == Some(true));
+        let found = match &target.target_pane {
+            None => active.map(|i| panes[i]),
+            Some(TargetPane::Token(token)) => {
+                let mut sorted = panes.clone();
+                sorted.sort_by_key(|p| p.index);
+                let current = sorted.iter().position(|p| p.active == Some(true));
+                match token {
+                    TargetPaneToken::Next(n) => {
+                        current.map(|i| *offset(&sorted, i, n.unwrap_or(1) as isize))
+                    }
+                    TargetPaneToken::Previous(n) => {
+                        current.map(|i| *offset(&sorted, i, -(n.unwrap_or(1) as isize)))
+                    }
+                    // last active pane isn't included in the pane variables
+                    TargetPaneToken::Last => None,
+                    TargetPaneToken::UpOf
+                    | TargetPaneToken::DownOf
+                    | TargetPaneToken::LeftOf
+                    | TargetPaneToken::RightOf => {
+                        active.and_then(|i| pane_next_to(window.window, panes, panes[i], token))
+                    }
+                    _ => pane_at_token(window.window, panes, token),
+                }
+            }
+            Some(TargetPane::Index(i)) => panes.iter().find(|p| p.index == Some(*i)).copied(),
+            // tmux doesn't match panes by names (titles)
+            Some(TargetPane::ExactName(_))
+            | Some(TargetPane::StartName(_))
+            | Some(TargetPane::FnMatch(_))
+            | Some(TargetPane::Id(_))
+            | Some(TargetPane::Raw(_)) => None,
+        };
+        found.ok_or_else(|| not_found(TARGET_KIND_PANE, target))
+    }
+}
+
+fn session_name<'s>(session: &'s SessionNode) -> Option<&'s str> {
+    session.session.name.as_deref()
+}
+
+fn window_name<'s>(window: &'s WindowNode) -> Option<&'s str> {
+    window.window.name.as_deref()
+}
+
+fn not_found(kind: &'static str, target: &dyn fmt::Display) -> Error {
+    Error::TargetNotFound(kind, target.to_string())
+}
+
+fn unique<'s, T>(
+    found: Vec<&'s T>,
+    kind: &'static str,
+    target: &dyn fmt::Display,
+) -> Result<Option<&'s T>, Error> {
+    match found.len() {
+        0 => Ok(None),
+        1 => Ok(Some(found[0])),
+        n => Err(Error::TargetAmbiguous(kind, target.to_string(), n)),
+    }
+}
+
+fn exact_name<'s, T, F>(
+    items: &'s [T],
+    name: F,
+    s: &str,
+    kind: &'static str,
+    target: &dyn fmt::Display,
+) -> Result<&'s T, Error>
+where
+    F: Fn(&T) -> Option<&str>,
+{
+    let found = items.iter().filter(|i| name(i) == Some(s)).collect();
+    unique(found, kind, target)?.ok_or_else(|| not_found(kind, target))
+}
+
+// tmux order: exact name, start of a name, fnmatch pattern
+fn match_name<'s, T, F>(
+    items: &'s [T],
+    name: F,
+    s: &str,
+    kind: &'static str,
+    target: &dyn fmt::Display,
+) -> Result<&'s T, Error>
+where
+    F: Fn(&T) -> Option<&str>,
+{
+    let checks: [&dyn Fn(&str) -> bool; 3] = [&|n| n == s, &|n| n.starts_with(s), &|n| {
+        fn_match(s.as_bytes(), n.as_bytes())
+    }];
+    for check in checks.iter() {
+        let found = items
+            .iter()
+            .filter(|i| matches!(name(i), Some(n) if check(n)))
+            .collect();
+        if let Some(item) = unique(found, kind, target)? {
+            return Ok(item);
+        }
+    }
+    Err(not_found(kind, target))
+}
+
+// relative item (wrapping around)
+fn offset<T>(items: &[T], current: usize, offset: isize) -> &T {
+    let i = (current as isize + offset).rem_euclid(items.len() as isize);
+    &items[i as usize]
+}
+
+fn active_window<'s, 'a>(session: &'s SessionNode<'a>) -> Option<&'s WindowNode<'a>> {
+    session
+        .windows
+        .iter()
+        .find(|w| w.window.active == Some(true))
+}
+
+// pane containing the point (borders to the right and bottom are included)
+fn pane_at<'a>(panes: &[&'a Pane], x: usize, y: usize) -> Option<&'a Pane> {
+    panes
+        .iter()
+        .find(|p| match (p.left, p.top, p.width, p.height) {
+            (Some(left), Some(top), Some(width), Some(height)) => {
+                left <= x && x <= left + width && top <= y && y <= top + height
+            }
+            _ => false,
+        })
+        .copied()
+}
+
+// `{top}`, `{bottom-left}` etc. as in tmux `window_find_string()`
+fn pane_at_token<'a>(
+    window: &Window,
+    panes: &[&'a Pane],
+    token: &TargetPaneToken,
+) -> Option<&'a Pane> {
+    let (width, height) = (window.width?, window.height?);
+    let (left, right, top, bottom) = (0, width.saturating_sub(1), 0, height.saturating_sub(1));
+    let (x, y) = (width / 2, height / 2);
+    let (x, y) = match token {
+        TargetPaneToken::Top => (x, top),
+        TargetPaneToken::Bottom => (x, bottom),
+        TargetPaneToken::Left => (left, y),
+        TargetPaneToken::Right => (right, y),
+        TargetPaneToken::TopLeft => (left, top),
+        TargetPaneToken::TopRight => (right, top),
+        TargetPaneToken::BottomLeft => (left, bottom),
+        TargetPaneToken::BottomRight => (right, bottom),
+        _ => return None,
+    };
+    pane_at(panes, x, y)
+}
+
+// `{up-of}`, `{left-of}` etc. as in tmux `window_pane_find_up()` (wrapping around the window)
+fn pane_next_to<'a>(
+    window: &Window,
+    panes: &[&'a Pane],
+    pane: &Pane,
+    token: &TargetPaneToken,
+) -> Option<&'a Pane> {
+    let (width, height) = (window.width?, window.height?);
+    let (left, top) = (pane.left?, pane.top?);
+    let (right, bottom) = (left + pane.width?, top + pane.height?);
+    let overlap = |start: usize, end: usize, from: usize, to: usize| {
+        (start < from && end > to) || (from <= start && start <= to) || (from <= end && end <= to)
+    };
+    panes
+        .iter()
+        .filter(|p| p.id != pane.id)
+        .find(|p| match (p.left, p.top, p.width, p.height) {
+            (Some(p_left), Some(p_top), Some(p_width), Some(p_height)) => {
+                let horizontal = overlap(p_left, (p_left + p_width).saturating_sub(1), left, right);
+                let vertical = overlap(p_top, (p_top + p_height).saturating_sub(1), top, bottom);
+                match token {
+                    TargetPaneToken::UpOf => {
+                        let edge = if top == 0 { height + 1 } else { top };
+                        horizontal && p_top + p_height + 1 == edge
+                    }
+                    TargetPaneToken::DownOf => {
+                        let edge = if bottom + 1 >= height { 0 } else { bottom + 1 };
+                        horizontal && p_top == edge
+                    }
+                    TargetPaneToken::LeftOf => {
+                        let edge = if left == 0 { width + 1 } else { left };
+                        vertical && p_left + p_width + 1 == edge
+                    }
+                    TargetPaneToken::RightOf => {
+                        let edge = if right + 1 >= width { 0 } else { right + 1 };
+                        vertical && p_left == edge
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        })
+        .copied()
+}
@@ -0,0 +1,208 @@
+use crate::{Pane, PaneId, Session, SessionId, Window, WindowId};
+
+fn session(id: usize, name: &str) -> Session {
+    let mut session = Session::new();
+    session.id = Some(SessionId(id));
+    session.name = Some(name.to_string());
+    session
+}
+
+fn window(id: usize, index: usize, name: &str) -> Window {
+    let mut window = Window::new();
+    window.id = Some(WindowId(id));
+    window.index = Some(index);
+    window.name = Some(name.to_string());
+    window.active = Some(index == 0);
+    window.last_flag = Some(index == 1);
+    window.width = Some(160);
+    window.height = Some(24);
+    window
+}
+
+// left half, top right, bottom right
+fn pane(id: usize, index: usize, (left, top, width, height): (usize, usize, usize, usize)) -> Pane {
+    let mut pane = Pane::new();
+    pane.id = Some(PaneId(id));
+    pane.index = Some(index);
+    pane.active = Some(index == 0);
+    pane.title = Some(format!("title{}", id));
+    pane.left = Some(left);
+    pane.top = Some(top);
+    pane.width = Some(width);
+    pane.height = Some(height);
+    pane
+}
+
+fn fixture() -> (Vec<Session>, Vec<Window>, Vec<Pane>) {
+    let sessions = vec![session(1, "main"), session(2, "misc"), session(3, "work")];
+    let windows = vec![
+        window(1, 0, "editor"),
+        window(2, 1, "shell"),
+        window(3, 2, "shell2"),
+    ];
+    let panes = vec![
+        pane(1, 0, (0, 0, 80, 24)),
+        pane(2, 1, (81, 0, 79, 11)),
+        pane(3, 2, (81, 12, 79, 12)),
+    ];
+    (sessions, windows, panes)
+}
+
+macro_rules! resolver {
+    ($sessions:expr, $windows:expr, $panes:expr) => {{
+        use crate::TargetResolver;
+
+        let mut resolver = TargetResolver::new();
+        for session in $sessions.iter() {
+            resolver = resolver.session(session);
+        }
+        for window in $windows.iter() {
+            resolver = resolver.window(SessionId(1), window);
+        }
+        for pane in $panes.iter() {
+            resolver = resolver.pane(WindowId(1), pane);
+        }
+        resolver
+    }};
+}
+
+#[test]
+fn resolve_session() {
+    use crate::{Error, TargetSession};
+
+    let (sessions, windows, panes) = fixture();
+    let resolver = resolver!(sessions, windows, panes);
+    let resolve = |s: &str| resolver.resolve_session(&s.parse::<TargetSession>().unwrap());
+
+    assert_eq!(resolve("$2").unwrap().id, Some(SessionId(2)));
+    assert_eq!(resolve("main").unwrap().id, Some(SessionId(1)));
+    assert_eq!(resolve("mi").unwrap().id, Some(SessionId(2)));
+    assert_eq!(resolve("w*").unwrap().id, Some(SessionId(3)));
+    assert_eq!(resolve("=work").unwrap().id, Some(SessionId(3)));
+    assert!(matches!(
+        resolve("m"),
+        Err(Error::TargetAmbiguous("session", _, 2))
+    ));
+    assert!(matches!(
+        resolve("=mai"),
+        Err(Error::TargetNotFound("session", _))
+    ));
+    assert!(matches!(
+        resolve("$4"),
+        Err(Error::TargetNotFound("session", _))
+    ));
+}
+
+#[test]
+fn resolve_window() {
+    use crate::{Error, TargetWindowExt};
+
+    let (sessions, windows, panes) = fixture();
+    let resolver = resolver!(sessions, windows, panes);
+    let resolve = |s: &str| {
+        resolver
+            .resolve_window(&s.parse::<TargetWindowExt>().unwrap())
+            .map(|w| w.id.unwrap())
+    };
+
+    assert_eq!(resolve("main:ed").unwrap(), WindowId(1));
+    assert_eq!(resolve("main:shell").unwrap(), WindowId(2));
+    assert_eq!(resolve("main:1").unwrap(), WindowId(2));
+    assert_eq!(resolve("main:*2").unwrap(), WindowId(3));
+    assert_eq!(resolve("main:").unwrap(), WindowId(1));
+    assert_eq!(resolve("@3").unwrap(), WindowId(3));
+    assert!(matches!(
+        resolve("main:sh"),
+        Err(Error::TargetAmbiguous("window", _, 2))
+    ));
+    assert!(matches!(
+        resolve("main:=sh"),
+        Err(Error::TargetNotFound("window", _))
+    ));
+    assert!(matches!(
+        resolve("misc:0"),
+        Err(Error::TargetNotFound("window", _))
+    ));
+
+    // tokens
+    assert_eq!(resolve("main:^").unwrap(), WindowId(1));
+    assert_eq!(resolve("main:{end}").unwrap(), WindowId(3));
+    assert_eq!(resolve("main:!").unwrap(), WindowId(2));
+    assert_eq!(resolve("main:+").unwrap(), WindowId(2));
+    assert_eq!(resolve("main:+2").unwrap(), WindowId(3));
+    assert_eq!(resolve("main:-").unwrap(), WindowId(3));
+
+    // current session
+    assert!(matches!(
+        resolve(":1"),
+        Err(Error::TargetAmbiguous("session", _, 3))
+    ));
+    let resolver = resolver.current_session(SessionId(1));
+    let resolve = |s: &str| {
+        resolver
+            .resolve_window(&s.parse::<TargetWindowExt>().unwrap())
+            .map(|w| w.id.unwrap())
+    };
+    assert_eq!(resolve(":1").unwrap(), WindowId(2));
+
+    // without `:`, window of the current session first, then session
+    assert_eq!(resolve("editor").unwrap(), WindowId(1));
+    assert_eq!(resolve("shell2").unwrap(), WindowId(3));
+    assert_eq!(resolve("main").unwrap(), WindowId(1));
+    assert_eq!(resolve("%2").unwrap(), WindowId(1));
+    assert!(matches!(
+        resolve("misc"),
+        Err(Error::TargetNotFound("window", _))
+    ));
+    assert!(matches!(
+        resolve("%9"),
+        Err(Error::TargetNotFound("window", _))
+    ));
+    assert!(matches!(
+        resolve("nothing"),
+        Err(Error::TargetNotFound("window", _))
+    ));
+}
+
+#[test]
+fn resolve_pane() {
+    use crate::{Error, TargetPaneExt};
+
+    let (sessions, windows, panes) = fixture();
+    let resolver = resolver!(sessions, windows, panes).current_session(SessionId(1));
+    let resolve = |s: &str| {
+        resolver
+            .resolve_pane(&s.parse::<TargetPaneExt>().unwrap())
+            .map(|p| p.id.unwrap())
+    };
+
+    assert_eq!(resolve("%3").unwrap(), PaneId(3));
+    assert_eq!(resolve("main:editor.1").unwrap(), PaneId(2));
+    assert_eq!(resolve("main:0").unwrap(), PaneId(1));
+    assert_eq!(resolve("editor.2").unwrap(), PaneId(3));
+    // no pane names in tmux, titles aren't used
+    for s in [".title3", ".=title3", ".title*"].iter() {
+        assert!(matches!(resolve(s), Err(Error::TargetNotFound("pane", _))));
+    }
+    assert!(matches!(
+        resolve("main:1.0"),
+        Err(Error::TargetNotFound("pane", _))
+    ));
+
+    // tokens
+    assert_eq!(resolve("+").unwrap(), PaneId(2));
+    assert_eq!(resolve("-").unwrap(), PaneId(3));
+    assert_eq!(resolve("{top-right}").unwrap(), PaneId(2));
+    assert_eq!(resolve("{bottom-right}").unwrap(), PaneId(3));
+    assert_eq!(resolve("{left}").unwrap(), PaneId(1));
+    assert_eq!(resolve("{right-of}").unwrap(), PaneId(2));
+    assert_eq!(resolve("{left-of}").unwrap(), PaneId(2));
+    assert!(matches!(
+        resolve("{up-of}"),
+        Err(Error::TargetNotFound("pane", _))
+    ));
+    assert!(matches!(
+        resolve("!"),
+        Err(Error::TargetNotFound("pane", _))
+    ));
+}
@@ -0,0 +1,99 @@
+use super::target_id::SESSION_ID_PREFIX;
+use crate::{Error, SessionId};
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+/// exact name prefix (`=name`)
+pub const TARGET_EXACT_NAME_PREFIX: char = '=';
+/// characters making a name a fnmatch(3) pattern
+pub const TARGET_FN_MATCH_CHARS: &[char] = &['*', '?', '['];
+
+impl<'a> TargetSession<'a> {
+    /// simple initializing as start of a name
+    pub fn new<S: Into<Cow<'a, str>>>(target_name: S) -> Self {
+        TargetSession::StartName(target_name.into())
+    }
+
+    pub fn id<I: Into<SessionId>>(id: I) -> Self {
+        TargetSession::Id(id.into())
+    }
+
+    pub fn exact_name<S: Into<Cow<'a, str>>>(name: S) -> Self {
+        TargetSession::ExactName(name.into())
+    }
+
+    pub fn start_name<S: Into<Cow<'a, str>>>(name: S) -> Self {
+        TargetSession::StartName(name.into())
+    }
+
+    pub fn fn_match<S: Into<Cow<'a, str>>>(name: S) -> Self {
+        TargetSession::FnMatch(name.into())
+    }
+
+    // XXX: draft
+    pub fn raw<S: Into<Cow<'a, str>>>(name: S) -> Self {
+        TargetSession::Raw(name.into())
+    }
+}
+
+// XXX: remove unnecessary fields
+/// Enum for possible `target-session` variants
+#[derive(Clone, Debug, PartialEq)]
+pub enum TargetSession<'a> {
+    /// id ($id) instead of name
+    Id(SessionId),
+    /// exact name (=name)
+    ExactName(Cow<'a, str>),
+    /// start of a name
+    StartName(Cow<'a, str>),
+    /// fn_match
+    FnMatch(Cow<'a, str>),
+    // NOTE: not really needed, just to be analogous to `TargetWindow` `TargetPane`
+    /// manual define full name
+    Raw(Cow<'a, str>),
+}
+
+impl<'a> fmt::Display for TargetSession<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TargetSession::Id(id) => write!(f, "{}", id),
+            TargetSession::ExactName(name) => write!(f, "={}", name),
+            TargetSession::StartName(name) => f.write_str(name),
+            TargetSession::FnMatch(name) => f.write_str(name),
+            TargetSession::Raw(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Parse `target-session` (`$id`, `=name`, fnmatch pattern or start of a name)
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{SessionId, TargetSession};
+///
+/// let target_session: TargetSession = "=main".parse().unwrap();
+/// assert_eq!(target_session, TargetSession::exact_name("main"));
+/// let target_session: TargetSession = "$1".parse().unwrap();
+/// assert_eq!(target_session, TargetSession::Id(SessionId(1)));
+/// ```
+impl FromStr for TargetSession<'static> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            Err(Error::ParseTargetSession)
+        } else if s.starts_with(SESSION_ID_PREFIX) {
+            Ok(TargetSession::Id(
+                s.parse().map_err(|_| Error::ParseTargetSession)?,
+            ))
+        } else if let Some(name) = s.strip_prefix(TARGET_EXACT_NAME_PREFIX) {
+            Ok(TargetSession::ExactName(Cow::Owned(name.to_string())))
+        } else if s.contains(TARGET_FN_MATCH_CHARS) {
+            Ok(TargetSession::FnMatch(Cow::Owned(s.to_string())))
+        } else {
+            Ok(TargetSession::StartName(Cow::Owned(s.to_string())))
+        }
+    }
+}
@@ -0,0 +1,32 @@
+#[test]
+fn target_session() {
+    use crate::{SessionId, TargetSession};
+
+    let session_type = TargetSession::Id(SessionId(1));
+    assert_eq!(session_type.to_string(), "$1");
+    let session_type = TargetSession::ExactName("exact_name".into());
+    assert_eq!(session_type.to_string(), "=exact_name");
+    let session_type = TargetSession::StartName("start_name".into());
+    assert_eq!(session_type.to_string(), "start_name");
+    let session_type = TargetSession::StartName("fn_match".into());
+    assert_eq!(session_type.to_string(), "fn_match");
+    let session_type = TargetSession::StartName("raw".into());
+    assert_eq!(session_type.to_string(), "raw");
+}
+
+#[test]
+fn target_session_parse() {
+    use crate::{SessionId, TargetSession};
+
+    let target_session: TargetSession = "$1".parse().unwrap();
+    assert_eq!(target_session, TargetSession::Id(SessionId(1)));
+    let target_session: TargetSession = "=exact_name".parse().unwrap();
+    assert_eq!(target_session, TargetSession::exact_name("exact_name"));
+    let target_session: TargetSession = "start_name".parse().unwrap();
+    assert_eq!(target_session, TargetSession::start_name("start_name"));
+    let target_session: TargetSession = "fn_*".parse().unwrap();
+    assert_eq!(target_session, TargetSession::fn_match("fn_*"));
+
+    assert!("".parse::<TargetSession>().is_err());
+    assert!("$a".parse::<TargetSession>().is_err());
+}
@@ -0,0 +1,352 @@
+use super::target_id::{PANE_ID_PREFIX, WINDOW_ID_PREFIX};
+use super::target_session::{TARGET_EXACT_NAME_PREFIX, TARGET_FN_MATCH_CHARS};
+use super::TargetSession;
+use crate::{Error, PaneId, SessionId, WindowId};
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+/// separator between `target-session` and `target-window` (`session:window`)
+pub const TARGET_WINDOW_SEPARATOR: char = ':';
+
+/// Extended [`TargetWindow`] struct, includes [`TargetSession`]
+///
+/// [`TargetWindow`]: enum.TargetWindow.html
+/// [`TargetSession`]: enum.TargetSession.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TargetWindowExt<'a> {
+    /// `TargetSession` (tmux analog: `target-session`), borrowed or owned (if parsed)
+    pub session: Option<Cow<'a, TargetSession<'a>>>,
+    /// `TargetWindow`  (tmux analog: `target-window`)
+    pub window: Option<TargetWindow<'a>>, // bc. can't return value referencing local / temp value
+}
+
+impl<'a> TargetWindowExt<'a> {
+    /// simple initializing as start of a name
+    pub fn new<S: Into<Cow<'a, str>>>(target_window: S) -> Self {
+        TargetWindowExt {
+            session: None,
+            window: Some(TargetWindow::StartName(target_window.into())),
+        }
+    }
+
+    /// Create [`TargetWindowExt`] structure using one of [`TargetWindowToken`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::tmux_interface::{TargetWindowExt, TargetWindowToken};
+    ///
+    /// let target_window = TargetWindowExt::token(None, TargetWindowToken::Start);
+    /// ```
+    ///
+    /// [`TargetWindowExt`]: enum.TargetWindowExt.html
+    /// [`TargetWindowToken`]: enum.TargetWindowToken.html
+    pub fn token(session: Option<&'a TargetSession<'a>>, token: TargetWindowToken) -> Self {
+        TargetWindowExt {
+            session: session.map(Cow::Borrowed),
+            window: Some(TargetWindow::Token(token)),
+        }
+    }
+
+    pub fn index(session: Option<&'a TargetSession<'a>>, i: usize) -> Self {
+        TargetWindowExt {
+            session: session.map(Cow::Borrowed),
+            window: Some(TargetWindow::Index(i)),
+        }
+    }
+
+    pub fn id<I: Into<WindowId>>(session: Option<&'a TargetSession<'a>>, id: I) -> Self {
+        TargetWindowExt {
+            session: session.map(Cow::Borrowed),
+            window: Some(TargetWindow::Id(id.into())),
+        }
+    }
+
+    pub fn exact_name<S: Into<Cow<'a, str>>>(
+        session: Option<&'a TargetSession<'a>>,
+        name: S,
+    ) -> Self {
+        TargetWindowExt {
+            session: session.map(Cow::Borrowed),
+            window: Some(TargetWindow::ExactName(name.into())),
+        }
+    }
+
+    pub fn start_name<S: Into<Cow<'a, str>>>(
+        session: Option<&'a TargetSession<'a>>,
+        name: S,
+    ) -> Self {
+        TargetWindowExt {
+            session: session.map(Cow::Borrowed),
+            window: Some(TargetWindow::StartName(name.into())),
+        }
+    }
+
+    pub fn fn_match<S: Into<Cow<'a, str>>>(
+        session: Option<&'a TargetSession<'a>>,
+        name: S,
+    ) -> Self {
+        TargetWindowExt {
+            session: session.map(Cow::Borrowed),
+            window: Some(TargetWindow::FnMatch(name.into())),
+        }
+    }
+
+    // XXX: draft $1:@raw_name or .raw_name or raw_name:raw_name?
+    pub fn raw<S: Into<Cow<'a, str>>>(name: S) -> Self {
+        TargetWindowExt {
+            session: None,
+            window: Some(TargetWindow::Raw(name.into())),
+        }
+    }
+}
+
+impl<'a> fmt::Display for TargetWindowExt<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = String::new();
+        let mut w = String::new();
+        if let Some(ref session) = self.session {
+            s = session.to_string();
+            // id is used without separator if it's the whole target (`@1`, `main:@1`), session
+            // without separator would be taken as a window name (`main:`)
+            if let Some(TargetWindow::Id(_)) | None = self.window {
+                s.push(TARGET_WINDOW_SEPARATOR);
+            }
+        }
+        if let Some(ref window) = self.window {
+            w = window.to_string();
+        }
+        write!(f, "{}{}", s, w)
+    }
+}
+
+/// Parse `target-window` (`session:window`, `@id`, `%id`, `$id`, `:window`, `name`)
+///
+/// String without `:` (except ids, indexes and tokens) is kept as [`TargetWindow::Raw`], tmux
+/// looks for a window of the current session first and for a session if there is no such
+/// window, `%id` is the window containing the pane
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{TargetSession, TargetWindow, TargetWindowExt, TargetWindowToken};
+///
+/// let target_window: TargetWindowExt = "=main:{last}".parse().unwrap();
+/// assert_eq!(
+///     target_window.session.as_deref(),
+///     Some(&TargetSession::exact_name("main"))
+/// );
+/// assert_eq!(
+///     target_window.window,
+///     Some(TargetWindow::Token(TargetWindowToken::Last))
+/// );
+/// assert_eq!(target_window.to_string(), "=main:!");
+/// ```
+impl FromStr for TargetWindowExt<'static> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut target_window = TargetWindowExt::default();
+        match s.find(TARGET_WINDOW_SEPARATOR) {
+            Some(i) => {
+                if i > 0 {
+                    target_window.session = Some(Cow::Owned(s[..i].parse()?));
+                }
+                if i + 1 < s.len() {
+                    target_window.window = Some(s[i + 1..].parse()?);
+                }
+            }
+            None => {
+                if s.is_empty() {
+                    return Err(Error::ParseTargetWindow);
+                } else if s.parse::<SessionId>().is_ok() {
+                    target_window.session = Some(Cow::Owned(s.parse()?));
+                } else if s.starts_with(WINDOW_ID_PREFIX)
+                    || s.parse::<usize>().is_ok()
+                    || s.parse::<TargetWindowToken>().is_ok()
+                {
+                    target_window.window = Some(s.parse()?);
+                } else if s.starts_with(PANE_ID_PREFIX) {
+                    s.parse::<PaneId>()
+                        .map_err(|_| Error::ParseTargetWindow)?;
+                    target_window.window = Some(TargetWindow::Raw(Cow::Owned(s.to_string())));
+                } else {
+                    // window name or session name
+                    target_window.window = Some(TargetWindow::Raw(Cow::Owned(s.to_string())));
+                }
+            }
+        }
+        Ok(target_window)
+    }
+}
+
+/// Enum for possible [`TargetWindow`] variants
+///
+/// [`TargetWindow`]: enum.TargetWindow.html
+#[derive(Clone, Debug, PartialEq)]
+pub enum TargetWindow<'a> {
+    /// token (^, $, !, +, -) instead of name
+    Token(TargetWindowToken),
+    /// index instead of name
+    Index(usize),
+    /// id (@id) instead of name
+    Id(WindowId),
+    /// exact name (=name)
+    ExactName(Cow<'a, str>),
+    /// start of a name
+    StartName(Cow<'a, str>),
+    /// fn_match
+    FnMatch(Cow<'a, str>),
+    /// manual define full name (no `:` will be added)
+    Raw(Cow<'a, str>),
+}
+
+impl<'a> Default for TargetWindow<'a> {
+    fn default() -> Self {
+        TargetWindow::Raw(Cow::Borrowed(""))
+    }
+}
+
+// TODO: extract simple name, simple parent name
+impl<'a> fmt::Display for TargetWindow<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TargetWindow::Token(token) => write!(f, ":{}", token),
+            TargetWindow::Index(i) => write!(f, ":{}", i),
+            TargetWindow::Id(id) => write!(f, "{}", id),
+            TargetWindow::ExactName(name) => write!(f, ":={}", name),
+            TargetWindow::StartName(name) => write!(f, ":{}", name),
+            TargetWindow::FnMatch(name) => write!(f, ":{}", name),
+            TargetWindow::Raw(raw_str) => write!(f, "{}", raw_str),
+        }
+    }
+}
+
+/// Parse window part of the `target-window` (leading `:` is optional)
+impl FromStr for TargetWindow<'static> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix(TARGET_WINDOW_SEPARATOR).unwrap_or(s);
+        if s.is_empty() {
+            Err(Error::ParseTargetWindow)
+        } else if s.starts_with(WINDOW_ID_PREFIX) {
+            Ok(TargetWindow::Id(
+                s.parse().map_err(|_| Error::ParseTargetWindow)?,
+            ))
+        } else if let Ok(token) = s.parse() {
+            Ok(TargetWindow::Token(token))
+        } else if let Ok(i) = s.parse() {
+            Ok(TargetWindow::Index(i))
+        } else if let Some(name) = s.strip_prefix(TARGET_EXACT_NAME_PREFIX) {
+            Ok(TargetWindow::ExactName(Cow::Owned(name.to_string())))
+        } else if s.contains(TARGET_FN_MATCH_CHARS) {
+            Ok(TargetWindow::FnMatch(Cow::Owned(s.to_string())))
+        } else {
+            Ok(TargetWindow::StartName(Cow::Owned(s.to_string())))
+        }
+    }
+}
+
+/// Enum for `target-window` tokens
+#[derive(Clone, Debug, PartialEq)]
+pub enum TargetWindowToken {
+    /// `{start}` (alias: `^`) - The lowest-numbered window
+    Start,
+    /// `{end}` (alias: `$`) - The highest-numbered window
+    End,
+    /// `{last}` (alias: `!`) - The last (previously current) window
+    Last,
+    /// `{next}` (alias: `+`) - The next window by number
+    Next(Option<usize>),
+    /// `{previous}` (alias: `-`) - The previous window by number
+    Previous(Option<usize>),
+    //// {mouse} = most recent mouse event occurred
+    //Mouse,
+}
+
+const TARGET_WINDOW_TOKEN_START: &str = "^"; // {start}
+const TARGET_WINDOW_TOKEN_END: &str = "$"; // {end}
+const TARGET_WINDOW_TOKEN_LAST: &str = "!"; // {last}
+const TARGET_WINDOW_TOKEN_NEXT: &str = "+"; // {next}
+const TARGET_WINDOW_TOKEN_PREVIOUS: &str = "-"; // {previous}
+
+const TARGET_WINDOW_TOKEN_START_LONG: &str = "{start}";
+const TARGET_WINDOW_TOKEN_END_LONG: &str = "{end}";
+const TARGET_WINDOW_TOKEN_LAST_LONG: &str = "{last}";
+const TARGET_WINDOW_TOKEN_NEXT_LONG: &str = "{next}";
+const TARGET_WINDOW_TOKEN_PREVIOUS_LONG: &str = "{previous}";
+
+impl fmt::Display for TargetWindowToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let a;
+        let s = match self {
+            TargetWindowToken::Start => TARGET_WINDOW_TOKEN_START,
+            TargetWindowToken::End => TARGET_WINDOW_TOKEN_END,
+            TargetWindowToken::Last => TARGET_WINDOW_TOKEN_LAST,
+            TargetWindowToken::Next(offset) => {
+                if let Some(n) = offset {
+                    a = format!("{}{}", TARGET_WINDOW_TOKEN_NEXT, n);
+                    &a
+                } else {
+                    TARGET_WINDOW_TOKEN_NEXT
+                }
+            }
+            TargetWindowToken::Previous(offset) => {
+                if let Some(n) = offset {
+                    a = format!("{}{}", TARGET_WINDOW_TOKEN_PREVIOUS, n);
+                    &a
+                } else {
+                    TARGET_WINDOW_TOKEN_PREVIOUS
+                }
+            }
+        };
+        f.write_str(s)
+    }
+}
+
+/// Parse long (`{start}`) or short (`^`, `+2`) form of the token
+impl FromStr for TargetWindowToken {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            TARGET_WINDOW_TOKEN_START | TARGET_WINDOW_TOKEN_START_LONG => {
+                Ok(TargetWindowToken::Start)
+            }
+            TARGET_WINDOW_TOKEN_END | TARGET_WINDOW_TOKEN_END_LONG => Ok(TargetWindowToken::End),
+            TARGET_WINDOW_TOKEN_LAST | TARGET_WINDOW_TOKEN_LAST_LONG => Ok(TargetWindowToken::Last),
+            TARGET_WINDOW_TOKEN_NEXT | TARGET_WINDOW_TOKEN_NEXT_LONG => {
+                Ok(TargetWindowToken::Next(None))
+            }
+            TARGET_WINDOW_TOKEN_PREVIOUS | TARGET_WINDOW_TOKEN_PREVIOUS_LONG => {
+                Ok(TargetWindowToken::Previous(None))
+            }
+            _ => {
+                if let Some(n) = s.strip_prefix(TARGET_WINDOW_TOKEN_NEXT) {
+                    Ok(TargetWindowToken::Next(Some(parse_offset(
+                        n,
+                        Error::ParseTargetWindow,
+                    )?)))
+                } else if let Some(n) = s.strip_prefix(TARGET_WINDOW_TOKEN_PREVIOUS) {
+                    Ok(TargetWindowToken::Previous(Some(parse_offset(
+                        n,
+                        Error::ParseTargetWindow,
+                    )?)))
+                } else {
+                    Err(Error::ParseTargetWindow)
+                }
+            }
+        }
+    }
+}
+
+// offset of `+N`, `-N` tokens (digits only)
+pub(crate) fn parse_offset(s: &str, err: Error) -> Result<usize, Error> {
+    if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().map_err(|_| err)
+    } else {
+        Err(err)
+    }
+}
@@ -0,0 +1,218 @@
+#[test]
+fn target_window_ex() {
+    use crate::{TargetWindow, TargetWindowExt, TargetWindowToken};
+
+    let _target_window = TargetWindowExt {
+        ..Default::default()
+    };
+
+    let target_window = TargetWindowExt {
+        session: None,
+        window: Some(TargetWindow::Token(TargetWindowToken::Start)),
+    };
+    assert_eq!(target_window.to_string(), ":^");
+}
+
+#[test]
+fn target_window() {
+    use crate::{TargetWindow, TargetWindowToken, WindowId};
+
+    let target_window = TargetWindow::Token(TargetWindowToken::Start).to_string();
+    assert_eq!(target_window, ":^");
+    let target_window = TargetWindow::Index(1).to_string();
+    assert_eq!(target_window, ":1");
+    let target_window = TargetWindow::Id(WindowId(1)).to_string();
+    assert_eq!(target_window, "@1");
+    let target_window = TargetWindow::ExactName("exact_name".into()).to_string();
+    assert_eq!(target_window, ":=exact_name");
+    let target_window = TargetWindow::StartName("start_name".into()).to_string();
+    assert_eq!(target_window, ":start_name");
+    let target_window = TargetWindow::FnMatch("fn_match".into()).to_string();
+    assert_eq!(target_window, ":fn_match");
+    let target_window = TargetWindow::Raw("raw".into()).to_string();
+    assert_eq!(target_window, "raw");
+}
+
+#[test]
+fn target_window_type_token() {
+    use crate::{TargetWindow, TargetWindowToken};
+
+    let type_token = TargetWindow::Token(TargetWindowToken::Start).to_string();
+    assert_eq!(type_token, ":^");
+    let type_token = TargetWindow::Token(TargetWindowToken::End).to_string();
+    assert_eq!(type_token, ":$");
+    let type_token = TargetWindow::Token(TargetWindowToken::Last).to_string();
+    assert_eq!(type_token, ":!");
+    let type_token = TargetWindow::Token(TargetWindowToken::Next(Some(1))).to_string();
+    assert_eq!(type_token, ":+1");
+    let type_token = TargetWindow::Token(TargetWindowToken::Previous(Some(1))).to_string();
+    assert_eq!(type_token, ":-1");
+}
+
+#[test]
+fn target_window_token_parse() {
+    use crate::TargetWindowToken;
+
+    assert_eq!(
+        "^".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::Start
+    );
+    assert_eq!(
+        "{start}".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::Start
+    );
+    assert_eq!(
+        "$".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::End
+    );
+    assert_eq!(
+        "{end}".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::End
+    );
+    assert_eq!(
+        "!".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::Last
+    );
+    assert_eq!(
+        "{last}".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::Last
+    );
+    assert_eq!(
+        "+".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::Next(None)
+    );
+    assert_eq!(
+        "{next}".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::Next(None)
+    );
+    assert_eq!(
+        "+2".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::Next(Some(2))
+    );
+    assert_eq!(
+        "-".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::Previous(None)
+    );
+    assert_eq!(
+        "{previous}".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::Previous(None)
+    );
+    assert_eq!(
+        "-3".parse::<TargetWindowToken>().unwrap(),
+        TargetWindowToken::Previous(Some(3))
+    );
+    assert!("{top}".parse::<TargetWindowToken>().is_err());
+    assert!("+a".parse::<TargetWindowToken>().is_err());
+    assert!("+-1".parse::<TargetWindowToken>().is_err());
+}
+
+#[test]
+fn target_window_parse() {
+    use crate::{
+        SessionId, TargetSession, TargetWindow, TargetWindowExt, TargetWindowToken, WindowId,
+    };
+
+    let target_window: TargetWindowExt = "session:2".parse().unwrap();
+    assert_eq!(
+        target_window.session.as_deref(),
+        Some(&TargetSession::start_name("session"))
+    );
+    assert_eq!(target_window.window, Some(TargetWindow::Index(2)));
+    assert_eq!(target_window.to_string(), "session:2");
+
+    let target_window: TargetWindowExt = "$1:=exact_name".parse().unwrap();
+    assert_eq!(
+        target_window.session.as_deref(),
+        Some(&TargetSession::Id(SessionId(1)))
+    );
+    assert_eq!(
+        target_window.window,
+        Some(TargetWindow::ExactName("exact_name".into()))
+    );
+
+    let target_window: TargetWindowExt = ":fn_*".parse().unwrap();
+    assert_eq!(target_window.session, None);
+    assert_eq!(
+        target_window.window,
+        Some(TargetWindow::FnMatch("fn_*".into()))
+    );
+
+    let target_window: TargetWindowExt = "session:".parse().unwrap();
+    assert_eq!(target_window.window, None);
+
+    // without `:`
+    let target_window: TargetWindowExt = "@3".parse().unwrap();
+    assert_eq!(target_window.window, Some(TargetWindow::Id(WindowId(3))));
+    let target_window: TargetWindowExt = "$".parse().unwrap();
+    assert_eq!(
+        target_window.window,
+        Some(TargetWindow::Token(TargetWindowToken::End))
+    );
+    let target_window: TargetWindowExt = "$2".parse().unwrap();
+    assert_eq!(
+        target_window.session.as_deref(),
+        Some(&TargetSession::Id(SessionId(2)))
+    );
+    assert_eq!(target_window.window, None);
+    // window or session name, pane id
+    let target_window: TargetWindowExt = "editor".parse().unwrap();
+    assert_eq!(target_window.session, None);
+    assert_eq!(
+        target_window.window,
+        Some(TargetWindow::Raw("editor".into()))
+    );
+    let target_window: TargetWindowExt = "%1".parse().unwrap();
+    assert_eq!(target_window.window, Some(TargetWindow::Raw("%1".into())));
+
+    let target_window: TargetWindowExt = "main:@1".parse().unwrap();
+    assert_eq!(
+        target_window.session.as_deref(),
+        Some(&TargetSession::start_name("main"))
+    );
+    assert_eq!(target_window.window, Some(TargetWindow::Id(WindowId(1))));
+
+    assert!("".parse::<TargetWindowExt>().is_err());
+    assert!(":@a".parse::<TargetWindowExt>().is_err());
+    assert!("%a".parse::<TargetWindowExt>().is_err());
+}
+
+#[test]
+fn target_window_parse_display() {
+    use crate::TargetWindowExt;
+
+    let targets = [
+        "session:2",
+        "$1:=exact_name",
+        ":fn_*",
+        "session:",
+        "=session:start",
+        "$1:^",
+        "main:@1",
+        "$1:@1",
+        "@3",
+        ":$",
+        ":+2",
+        "$2:",
+        "editor",
+        "%1",
+    ];
+    for s in targets.iter() {
+        let target_window: TargetWindowExt = s.parse().unwrap();
+        assert_eq!(target_window.to_string(), *s);
+        assert_eq!(
+            target_window
+                .to_string()
+                .parse::<TargetWindowExt>()
+                .unwrap(),
+            target_window
+        );
+    }
+
+    // short form of the tokens, optional `:`
+    let target_window: TargetWindowExt = "main:{last}".parse().unwrap();
+    assert_eq!(target_window.to_string(), "main:!");
+    let target_window: TargetWindowExt = "2".parse().unwrap();
+    assert_eq!(target_window.to_string(), ":2");
+    let target_window: TargetWindowExt = "$2".parse().unwrap();
+    assert_eq!(target_window.to_string(), "$2:");
+}
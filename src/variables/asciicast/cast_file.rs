@@ -0,0 +1,425 @@
+use crate::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Lines, Write};
+use std::iter::Peekable;
+use std::path::Path;
+use std::str::{Chars, FromStr};
+
+// asciicast v2 file (`.cast`, newline-delimited JSON)
+//
+// ```text
+// {"version": 2, "width": 80, "height": 24, "timestamp": 1504467315, "title": "Demo"}
+// [0.248848, "o", "\u001b[1;31mHello \u001b[32mWorld!\u001b[0m\n"]
+// [1.001376, "o", "That was ok\rThis is better."]
+// ```
+//
+// ref: https://docs.asciinema.org/manual/asciicast/v2/
+
+const CAST_VERSION: u64 = 2;
+// nesting of JSON arrays and objects, cast lines need 2 (`env` object in the header)
+const JSON_DEPTH_LIMIT: usize = 16;
+
+/// Header (first line) of an asciicast v2 file
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CastHeader {
+    /// terminal width (columns)
+    pub width: usize,
+    /// terminal height (rows)
+    pub height: usize,
+    /// recording start (seconds from epoch)
+    pub timestamp: Option<u64>,
+    pub title: Option<String>,
+}
+
+impl CastHeader {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            ..Default::default()
+        }
+    }
+}
+
+impl fmt::Display for CastHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{{\"version\": {}, \"width\": {}, \"height\": {}",
+            CAST_VERSION, self.width, self.height
+        )?;
+        if let Some(timestamp) = self.timestamp {
+            write!(f, ", \"timestamp\": {}", timestamp)?;
+        }
+        if let Some(title) = &self.title {
+            write!(f, ", \"title\": {}", json_string(title))?;
+        }
+        write!(f, "}}")
+    }
+}
+
+// unknown fields (`env`, `theme`, ...) are ignored
+impl FromStr for CastHeader {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = match Json::parse(s)? {
+            Json::Object(fields) => fields,
+            _ => return Err(Error::ParseAsciicast),
+        };
+        let mut header = CastHeader::new(0, 0);
+        let mut version = None;
+        for (key, value) in fields {
+            match (key.as_str(), value) {
+                ("version", Json::Number(n)) => version = Some(n as u64),
+                ("width", Json::Number(n)) => header.width = n as usize,
+                ("height", Json::Number(n)) => header.height = n as usize,
+                ("timestamp", Json::Number(n)) => header.timestamp = Some(n as u64),
+                ("title", Json::String(s)) => header.title = Some(s),
+                _ => {}
+            }
+        }
+        match version {
+            Some(CAST_VERSION) => Ok(header),
+            _ => Err(Error::ParseAsciicast),
+        }
+    }
+}
+
+/// Type of a [`CastEvent`]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CastEventCode {
+    /// `o` - data written to the terminal
+    Output,
+    /// `i` - data read from the keyboard
+    Input,
+    /// `m` - marker, data is the label
+    Marker,
+    /// `r` - terminal resize, data is `{columns}x{rows}`
+    Resize,
+    /// code not defined in asciicast v2
+    Other(String),
+}
+
+impl fmt::Display for CastEventCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Output => write!(f, "o"),
+            Self::Input => write!(f, "i"),
+            Self::Marker => write!(f, "m"),
+            Self::Resize => write!(f, "r"),
+            Self::Other(code) => write!(f, "{}", code),
+        }
+    }
+}
+
+impl From<&str> for CastEventCode {
+    fn from(s: &str) -> Self {
+        match s {
+            "o" => Self::Output,
+            "i" => Self::Input,
+            "m" => Self::Marker,
+            "r" => Self::Resize,
+            code => Self::Other(code.to_string()),
+        }
+    }
+}
+
+/// Event line of an asciicast v2 file
+#[derive(Clone, PartialEq, Debug)]
+pub struct CastEvent {
+    /// seconds since the start of the recording
+    pub time: f64,
+    pub code: CastEventCode,
+    pub data: String,
+}
+
+impl CastEvent {
+    pub fn output<S: Into<String>>(time: f64, data: S) -> Self {
+        Self {
+            time,
+            code: CastEventCode::Output,
+            data: data.into(),
+        }
+    }
+}
+
+impl fmt::Display for CastEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{:.6}, {}, {}]",
+            self.time,
+            json_string(&self.code.to_string()),
+            json_string(&self.data)
+        )
+    }
+}
+
+impl FromStr for CastEvent {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Json::parse(s)? {
+            Json::Array(values) => match values.as_slice() {
+                [Json::Number(time), Json::String(code), Json::String(data)]
+                    if time.is_finite() =>
+                {
+                    Ok(CastEvent {
+                        time: *time,
+                        code: code.as_str().into(),
+                        data: data.clone(),
+                    })
+                }
+                _ => Err(Error::ParseAsciicast),
+            },
+            _ => Err(Error::ParseAsciicast),
+        }
+    }
+}
+
+/// Reader of asciicast v2 files, iterator of events
+#[derive(Debug)]
+pub struct CastReader<B: BufRead> {
+    pub header: CastHeader,
+    lines: Lines<B>,
+}
+
+impl CastReader<BufReader<File>> {
+    /// Open cast file
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<B: BufRead> CastReader<B> {
+    /// Read header of the cast
+    pub fn new(reader: B) -> Result<Self, Error> {
+        let mut lines = reader.lines();
+        let header = lines.next().ok_or(Error::ParseAsciicast)??.parse()?;
+        Ok(Self { header, lines })
+    }
+}
+
+impl<B: BufRead> Iterator for CastReader<B> {
+    type Item = Result<CastEvent, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            // empty lines are allowed
+            if !line.trim().is_empty() {
+                return Some(line.parse());
+            }
+        }
+    }
+}
+
+/// Writer of asciicast v2 files
+#[derive(Debug)]
+pub struct CastWriter<W: Write> {
+    writer: W,
+}
+
+impl CastWriter<File> {
+    /// Create cast file
+    pub fn create<P: AsRef<Path>>(path: P, header: &CastHeader) -> Result<Self, Error> {
+        Self::new(File::create(path)?, header)
+    }
+}
+
+impl<W: Write> CastWriter<W> {
+    /// Write header of the cast
+    pub fn new(mut writer: W, header: &CastHeader) -> Result<Self, Error> {
+        writeln!(writer, "{}", header)?;
+        Ok(Self { writer })
+    }
+
+    pub fn write_event(&mut self, event: &CastEvent) -> Result<(), Error> {
+        writeln!(self.writer, "{}", event)?;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+// JSON string literal
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 || c == '\x7f' => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+// minimal JSON parser, enough for cast headers and events
+#[derive(Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn parse(s: &str) -> Result<Json, Error> {
+        let mut chars = s.chars().peekable();
+        let value = Self::value(&mut chars, 0)?;
+        Self::whitespace(&mut chars);
+        match chars.next() {
+            None => Ok(value),
+            Some(_) => Err(Error::ParseAsciicast),
+        }
+    }
+
+    fn whitespace(chars: &mut Peekable<Chars>) {
+        while chars.next_if(|c| c.is_ascii_whitespace()).is_some() {}
+    }
+
+    fn value(chars: &mut Peekable<Chars>, depth: usize) -> Result<Json, Error> {
+        Self::whitespace(chars);
+        if depth >= JSON_DEPTH_LIMIT && matches!(chars.peek(), Some('{') | Some('[')) {
+            return Err(Error::ParseAsciicast);
+        }
+        match chars.peek() {
+            Some('{') => {
+                chars.next();
+                let mut fields = Vec::new();
+                Self::whitespace(chars);
+                if chars.next_if_eq(&'}').is_some() {
+                    return Ok(Json::Object(fields));
+                }
+                loop {
+                    Self::whitespace(chars);
+                    let key = Self::string(chars)?;
+                    Self::whitespace(chars);
+                    chars.next_if_eq(&':').ok_or(Error::ParseAsciicast)?;
+                    fields.push((key, Self::value(chars, depth + 1)?));
+                    Self::whitespace(chars);
+                    match chars.next() {
+                        Some(',') => {}
+                        Some('}') => return Ok(Json::Object(fields)),
+                        _ => return Err(Error::ParseAsciicast),
+                    }
+                }
+            }
+            Some('[') => {
+                chars.next();
+                let mut values = Vec::new();
+                Self::whitespace(chars);
+                if chars.next_if_eq(&']').is_some() {
+                    return Ok(Json::Array(values));
+                }
+                loop {
+                    values.push(Self::value(chars, depth + 1)?);
+                    Self::whitespace(chars);
+                    match chars.next() {
+                        Some(',') => {}
+                        Some(']') => return Ok(Json::Array(values)),
+                        _ => return Err(Error::ParseAsciicast),
+                    }
+                }
+            }
+            Some('"') => Ok(Json::String(Self::string(chars)?)),
+            Some('t') => Self::literal(chars, "true", Json::Bool(true)),
+            Some('f') => Self::literal(chars, "false", Json::Bool(false)),
+            Some('n') => Self::literal(chars, "null", Json::Null),
+            Some(_) => {
+                let mut number = String::new();
+                while let Some(c) = chars
+                    .next_if(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+                {
+                    number.push(c);
+                }
+                number
+                    .parse()
+                    .map(Json::Number)
+                    .map_err(|_| Error::ParseAsciicast)
+            }
+            None => Err(Error::ParseAsciicast),
+        }
+    }
+
+    fn literal(chars: &mut Peekable<Chars>, literal: &str, value: Json) -> Result<Json, Error> {
+        for expected in literal.chars() {
+            chars.next_if_eq(&expected).ok_or(Error::ParseAsciicast)?;
+        }
+        Ok(value)
+    }
+
+    fn string(chars: &mut Peekable<Chars>) -> Result<String, Error> {
+        chars.next_if_eq(&'"').ok_or(Error::ParseAsciicast)?;
+        let mut s = String::new();
+        loop {
+            match chars.next().ok_or(Error::ParseAsciicast)? {
+                '"' => return Ok(s),
+                '\\' => match chars.next().ok_or(Error::ParseAsciicast)? {
+                    'n' => s.push('\n'),
+                    'r' => s.push('\r'),
+                    't' => s.push('\t'),
+                    'b' => s.push('\x08'),
+                    'f' => s.push('\x0c'),
+                    'u' => {
+                        let mut code = Self::hex4(chars)?;
+                        if let Some(low) = Self::low_surrogate(chars, code)? {
+                            code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                        }
+                        // unpaired surrogates aren't chars
+                        s.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                    }
+                    c => s.push(c),
+                },
+                c => s.push(c),
+            }
+        }
+    }
+
+    // `\uXXXX` of the low half following the high half `code` of a surrogate pair, an unpaired
+    // half is replaced
+    fn low_surrogate(chars: &mut Peekable<Chars>, code: u32) -> Result<Option<u32>, Error> {
+        if !(0xd800..0xdc00).contains(&code) {
+            return Ok(None);
+        }
+        let mut next = chars.clone();
+        if next.next() != Some('\\') || next.next() != Some('u') {
+            return Ok(None);
+        }
+        let low = Self::hex4(&mut next)?;
+        if (0xdc00..0xe000).contains(&low) {
+            *chars = next;
+            Ok(Some(low))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn hex4(chars: &mut Peekable<Chars>) -> Result<u32, Error> {
+        let hex: String = chars.take(4).collect();
+        if hex.len() != 4 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Error::ParseAsciicast);
+        }
+        u32::from_str_radix(&hex, 16).map_err(|_| Error::ParseAsciicast)
+    }
+}
@@ -0,0 +1,74 @@
+#[test]
+fn cast_header() {
+    use crate::CastHeader;
+
+    let mut header = CastHeader::new(80, 24);
+    assert_eq!(
+        header.to_string(),
+        r#"{"version": 2, "width": 80, "height": 24}"#
+    );
+    header.timestamp = Some(1504467315);
+    header.title = Some("\"Demo\"".to_string());
+    let s = r#"{"version": 2, "width": 80, "height": 24, "timestamp": 1504467315, "title": "\"Demo\""}"#;
+    assert_eq!(header.to_string(), s);
+    assert_eq!(s.parse::<CastHeader>().unwrap(), header);
+
+    // unknown fields
+    let s = r#"{"version":2,"width":100,"height":30,"idle_time_limit":2.5,"env":{"SHELL":"/bin/bash","TERM":"xterm-256color"},"theme":null}"#;
+    assert_eq!(s.parse::<CastHeader>().unwrap(), CastHeader::new(100, 30));
+
+    assert!(r#"{"version": 1, "width": 80, "height": 24}"#.parse::<CastHeader>().is_err());
+    assert!(r#"{"version": 2, "width": 80"#.parse::<CastHeader>().is_err());
+}
+
+#[test]
+fn cast_event() {
+    use crate::{CastEvent, CastEventCode};
+
+    let event = CastEvent::output(0.248848, "\x1b[1;31mHello \"World\"\x1b[0m\r\n");
+    let s = r#"[0.248848, "o", "\u001b[1;31mHello \"World\"\u001b[0m\r\n"]"#;
+    assert_eq!(event.to_string(), s);
+    assert_eq!(s.parse::<CastEvent>().unwrap(), event);
+
+    let event: CastEvent = r#"[1.5,"r","100x40"]"#.parse().unwrap();
+    assert_eq!(event.code, CastEventCode::Resize);
+    assert_eq!(event.data, "100x40");
+
+    // escapes, surrogate pair
+    let event: CastEvent = r#"[2, "x", "\t\\\/😀é"]"#.parse().unwrap();
+    assert_eq!(event.code, CastEventCode::Other("x".to_string()));
+    assert_eq!(event.data, "\t\\/\u{1f600}\u{e9}");
+
+    // unpaired surrogates are replaced
+    let event: CastEvent = r#"[0.1, "o", "\ud800\u0000\udc00"]"#.parse().unwrap();
+    assert_eq!(event.data, "\u{fffd}\u{0}\u{fffd}");
+
+    assert!(r#"[1e999, "o", "data"]"#.parse::<CastEvent>().is_err());
+    assert!(r#"[1.0, "o"]"#.parse::<CastEvent>().is_err());
+    assert!(r#"[1.0, "o", "data"] x"#.parse::<CastEvent>().is_err());
+    // nesting is limited, instead of overflowing the stack
+    assert!("[".repeat(100_000).parse::<CastEvent>().is_err());
+}
+
+#[test]
+fn cast_reader_writer() {
+    use crate::{CastEvent, CastHeader, CastReader, CastWriter};
+
+    let events = vec![
+        CastEvent::output(0.1, "$ "),
+        CastEvent::output(1.25, "ls\r\n\u{4e2d}\u{6587}\r\n"),
+    ];
+    let mut writer = CastWriter::new(Vec::new(), &CastHeader::new(80, 24)).unwrap();
+    for event in &events {
+        writer.write_event(event).unwrap();
+    }
+    let mut cast = writer.into_inner();
+    cast.extend_from_slice(b"\n");
+
+    let reader = CastReader::new(cast.as_slice()).unwrap();
+    assert_eq!(reader.header, CastHeader::new(80, 24));
+    let read: Vec<CastEvent> = reader.map(Result::unwrap).collect();
+    assert_eq!(read, events);
+
+    assert!(CastReader::new("".as_bytes()).is_err());
+}
@@ -0,0 +1,122 @@
+use crate::{CastEvent, CastHeader, CastWriter, DisplayMessage, Error, PaneHandle, PanePipe};
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::panic;
+use std::thread::{self, JoinHandle};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+impl CastHeader {
+    /// Header with the current size of the pane (`pane_width`, `pane_height`) and time
+    pub fn from_pane(pane: &PaneHandle) -> Result<Self, Error> {
+        let cmd = DisplayMessage::new()
+            .print()
+            .target_pane(pane.id)
+            .message("#{pane_width}x#{pane_height}");
+        let output = (pane.invoker())(cmd.build())?.checked()?.to_string();
+        let (width, height) = output
+            .trim()
+            .split_once('x')
+            .ok_or_else(|| Error::TargetNotFound("pane", pane.id.to_string()))?;
+        let mut header = CastHeader::new(width.parse()?, height.parse()?);
+        header.timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|d| d.as_secs());
+        Ok(header)
+    }
+}
+
+/// Recorder of pane output into an asciicast v2 file (`pipe-pane`)
+///
+/// Output is read in a separate thread, each chunk is written as an output event with the time
+/// since the start of the recording
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use tmux_interface::{CastRecorder, PaneHandle, PaneId};
+///
+/// let file = File::create("demo.cast").unwrap();
+/// let recorder = CastRecorder::start(PaneHandle::new(PaneId(0)), file).unwrap();
+/// // ...
+/// recorder.stop().unwrap();
+/// ```
+pub struct CastRecorder<'a, W: Write + Send + 'static> {
+    pipe: PanePipe<'a>,
+    thread: Option<JoinHandle<Result<CastWriter<W>, Error>>>,
+}
+
+impl<'a, W: Write + Send + 'static> CastRecorder<'a, W> {
+    /// Start recording with header taken from the pane ([`CastHeader::from_pane`])
+    pub fn start(pane: PaneHandle<'a>, writer: W) -> Result<Self, Error> {
+        let header = CastHeader::from_pane(&pane)?;
+        Self::with_header(pane, writer, &header)
+    }
+
+    /// Start recording with custom header (e.g. title)
+    pub fn with_header(
+        pane: PaneHandle<'a>,
+        writer: W,
+        header: &CastHeader,
+    ) -> Result<Self, Error> {
+        let writer = CastWriter::new(writer, header)?;
+        let mut pipe = pane.pipe();
+        pipe.start()?;
+        let reader = pipe
+            .take_reader()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotConnected))?;
+        let start = Instant::now();
+        let thread = thread::spawn(move || record(reader, writer, start));
+        Ok(Self {
+            pipe,
+            thread: Some(thread),
+        })
+    }
+
+    /// Stop recording, returns the writer after all output was written
+    pub fn stop(mut self) -> Result<W, Error> {
+        self.pipe.stop()?;
+        match self.thread.take().map(JoinHandle::join) {
+            Some(Ok(writer)) => Ok(writer?.into_inner()),
+            Some(Err(e)) => panic::resume_unwind(e),
+            None => Err(io::Error::from(io::ErrorKind::NotConnected).into()),
+        }
+    }
+}
+
+fn record<W: Write>(
+    mut reader: File,
+    mut writer: CastWriter<W>,
+    start: Instant,
+) -> Result<CastWriter<W>, Error> {
+    let mut buf = [0; 4096];
+    let mut pending = Vec::new();
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        pending.extend_from_slice(&buf[..n]);
+        let data = take_utf8(&mut pending);
+        if !data.is_empty() {
+            writer.write_event(&CastEvent::output(start.elapsed().as_secs_f64(), data))?;
+        }
+    }
+    if !pending.is_empty() {
+        let data = String::from_utf8_lossy(&pending).into_owned();
+        writer.write_event(&CastEvent::output(start.elapsed().as_secs_f64(), data))?;
+    }
+    writer.flush()?;
+    Ok(writer)
+}
+
+// cast data is UTF-8, an incomplete character at the end is kept for the next chunk
+fn take_utf8(pending: &mut Vec<u8>) -> String {
+    let valid = match std::str::from_utf8(pending) {
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        _ => pending.len(),
+    };
+    let data: Vec<u8> = pending.drain(..valid).collect();
+    String::from_utf8_lossy(&data).into_owned()
+}
@@ -0,0 +1,61 @@
+use crate::variables::test_session::TestSession;
+
+fn wait_for(pane: &crate::PaneHandle, text: &str) -> bool {
+    use std::thread;
+    use std::time::Duration;
+
+    for _ in 0..50 {
+        if pane.capture().unwrap().contains(text) {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+    false
+}
+
+#[test]
+fn record_replay() {
+    use crate::{CastEventCode, CastReader, CastRecorder, CastReplay, DisplayMessage, Tmux};
+    use std::thread;
+    use std::time::Duration;
+
+    // terminal echoes the input, `cat` prints it again
+    let session = TestSession::with_command("cast_recorder_test", "cat");
+    let pane = session.pane();
+    let size = Tmux::with_command(
+        DisplayMessage::new()
+            .print()
+            .target_pane(pane.id)
+            .message("#{pane_width}x#{pane_height}"),
+    )
+    .output()
+    .unwrap()
+    .to_string();
+
+    let recorder = CastRecorder::start(pane, Vec::new()).unwrap();
+    thread::sleep(Duration::from_millis(100));
+    pane.send_keys(["cast_test", "Enter"]).unwrap();
+    assert!(wait_for(&pane, "cast_test\ncast_test"));
+    thread::sleep(Duration::from_millis(100));
+    let cast = recorder.stop().unwrap();
+
+    let reader = CastReader::new(cast.as_slice()).unwrap();
+    assert_eq!(
+        format!("{}x{}\n", reader.header.width, reader.header.height),
+        size
+    );
+    assert!(reader.header.timestamp.is_some());
+    let events: Vec<_> = reader.map(Result::unwrap).collect();
+    assert!(events.iter().all(|e| e.code == CastEventCode::Output));
+    assert!(events.windows(2).all(|e| e[0].time <= e[1].time));
+    let output: String = events.iter().map(|e| e.data.as_str()).collect();
+    assert_eq!(output.matches("cast_test").count(), 2);
+
+    let replay_session = TestSession::new("cast_replay_test");
+    let replay_pane = replay_session.pane();
+    CastReplay::new(CastReader::new(cast.as_slice()).unwrap())
+        .speed(0.0)
+        .play(replay_pane)
+        .unwrap();
+    assert!(wait_for(&replay_pane, "cast_test\ncast_test"));
+}
@@ -0,0 +1,138 @@
+use crate::variables::handle::pane_pipe::{mkfifo, open_fifo, quote, PANE_PIPE_TIMEOUT};
+use crate::{CastEventCode, CastHeader, CastReader, Error, PaneHandle, RespawnPane, TmuxCommand};
+use std::fs::{self, DirBuilder, File};
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::fs::DirBuilderExt;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+// Replay output of a cast into a pane
+//
+// 1. `mkfifo output`
+// 2. `respawn-pane -k 'cat output'`, cat writes data to the pane terminal (FIFO is opened with
+//    a timeout, in case `cat` doesn't start)
+// 3. write output events to the FIFO, waiting between them accordingly to their times
+// 4. close FIFO, pane keeps the last screen (`cat > /dev/null`) until killed
+
+// unique directory name for each replay of the process
+static CAST_REPLAY_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Replay of asciicast v2 files into a pane
+///
+/// The process running in the pane is killed and replaced
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::{CastReplay, PaneHandle, PaneId};
+///
+/// CastReplay::open("demo.cast")
+///     .unwrap()
+///     .speed(2.0)
+///     .play(PaneHandle::new(PaneId(0)))
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct CastReplay<B: BufRead> {
+    reader: CastReader<B>,
+    speed: f64,
+    resize: bool,
+}
+
+impl CastReplay<BufReader<File>> {
+    /// Open cast file for replay
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Ok(Self::new(CastReader::open(path)?))
+    }
+}
+
+impl<B: BufRead> CastReplay<B> {
+    /// Replay with original speed
+    pub fn new(reader: CastReader<B>) -> Self {
+        CastReplay {
+            reader,
+            speed: 1.0,
+            resize: false,
+        }
+    }
+
+    pub fn header(&self) -> &CastHeader {
+        &self.reader.header
+    }
+
+    /// Set speed factor (`2.0` - twice as fast as original, `0.0` - without delays)
+    pub fn speed(mut self, speed: f64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Resize the pane to the size of the cast and on resize events (`resize-pane`)
+    pub fn resize(mut self) -> Self {
+        self.resize = true;
+        self
+    }
+
+    /// Play the cast into the pane, returns after the last event
+    pub fn play(self, pane: PaneHandle) -> Result<(), Error> {
+        let dir = std::env::temp_dir().join(format!(
+            "tmux_interface_cast_{}_{}",
+            std::process::id(),
+            CAST_REPLAY_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        DirBuilder::new().mode(0o700).create(&dir)?;
+        let result = self.play_fifo(pane, &dir);
+        fs::remove_dir_all(dir)?;
+        result
+    }
+
+    fn play_fifo(mut self, pane: PaneHandle, dir: &Path) -> Result<(), Error> {
+        if self.resize {
+            pane.resize(Some(self.header().width), Some(self.header().height))?;
+        }
+
+        let output = dir.join("output");
+        mkfifo(&output)?;
+        let cmd = RespawnPane::new()
+            .kill()
+            .target_pane(pane.id)
+            .shell_command(format!(
+                "stty -echo; cat {}; exec cat > /dev/null",
+                quote(&output)
+            ));
+        invoke(&pane, cmd.build())?;
+        let mut fifo = open_fifo(&output, true, PANE_PIPE_TIMEOUT)?;
+
+        let mut time = 0.0;
+        for event in &mut self.reader {
+            let event = event?;
+            if self.speed > 0.0 && event.time > time {
+                // a tiny speed overflows the delay
+                let delay = Duration::try_from_secs_f64((event.time - time) / self.speed)
+                    .map_err(|_| Error::ParseAsciicast)?;
+                thread::sleep(delay);
+            }
+            time = event.time;
+            match event.code {
+                CastEventCode::Output => {
+                    fifo.write_all(event.data.as_bytes())?;
+                    fifo.flush()?;
+                }
+                CastEventCode::Resize if self.resize => {
+                    let mut size = event.data.split('x').map(|n| n.trim().parse().ok());
+                    if let (Some(width), Some(height)) = (size.next(), size.next()) {
+                        pane.resize(width, height)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+fn invoke<'a>(pane: &PaneHandle<'a>, cmd: TmuxCommand<'a>) -> Result<(), Error> {
+    (pane.invoker)(cmd)?.checked()?;
+    Ok(())
+}
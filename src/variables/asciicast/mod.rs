@@ -0,0 +1,24 @@
+pub mod cast_file;
+#[cfg(feature = "tmux_1_8")]
+#[cfg(unix)]
+pub mod cast_recorder;
+#[cfg(feature = "tmux_1_8")]
+#[cfg(unix)]
+pub mod cast_replay;
+
+pub use cast_file::{CastEvent, CastEventCode, CastHeader, CastReader, CastWriter};
+#[cfg(feature = "tmux_1_8")]
+#[cfg(unix)]
+pub use cast_recorder::CastRecorder;
+#[cfg(feature = "tmux_1_8")]
+#[cfg(unix)]
+pub use cast_replay::CastReplay;
+
+#[cfg(test)]
+#[path = "."]
+mod variables_asciicast_tests {
+    mod cast_file_tests;
+    #[cfg(feature = "tmux_2_4")]
+    #[cfg(unix)]
+    mod cast_recorder_tests;
+}
@@ -1,9 +1,12 @@
+use crate::formats::format_value::FormatValue;
 use crate::Error;
 use crate::FormatsOutput;
+use crate::Variable;
 use std::str::FromStr;
 
 // XXX: 1.9 processed
 #[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Buffer {
     /// buffer_created - Time buffer created
     #[cfg(feature = "tmux_2_6")]
@@ -24,8 +27,7 @@ impl FromStr for Buffer {
 
     fn from_str(s: &str) -> Result<Self, Error> {
         let mut buffer = Buffer::new();
-        let mut format = FormatsOutput::new();
-        format.separator(':');
+        let mut format = FormatsOutput::delimited();
 
         #[cfg(feature = "tmux_2_6")]
         format.buffer_created(&mut buffer.created);
@@ -36,7 +38,7 @@ impl FromStr for Buffer {
         #[cfg(feature = "tmux_1_7")]
         format.buffer_size(&mut buffer.size);
 
-        FormatsOutput::from_string_ext(s, &mut format);
+        FormatsOutput::from_string_ext(s, &mut format)?;
         Ok(buffer)
     }
 }
@@ -46,5 +48,20 @@ impl Buffer {
         Default::default()
     }
 
+    /// Value of the buffer `variable`, as it would be expanded by tmux
+    pub fn value(&self, variable: &Variable) -> Option<String> {
+        match variable {
+            #[cfg(feature = "tmux_2_6")]
+            Variable::BufferCreated => self.created.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_3")]
+            Variable::BufferName => self.name.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_7")]
+            Variable::BufferSample => self.sample.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_7")]
+            Variable::BufferSize => self.size.as_ref().map(FormatValue::format_value),
+            _ => None,
+        }
+    }
+
     // XXX: wrapper with format generating and result parsing using callback
 }
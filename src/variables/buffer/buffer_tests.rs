@@ -23,6 +23,7 @@ fn buffer_default() {
 #[test]
 fn buffer_parse() {
     use crate::Buffer;
+    use crate::FormatsOutput;
     use std::str::FromStr;
 
     let buffer_vec = vec![
@@ -39,7 +40,7 @@ fn buffer_parse() {
         #[cfg(feature = "tmux_1_7")]
         "3",
     ];
-    let buffer_str = buffer_vec.join(":");
+    let buffer_str = FormatsOutput::delimited_record(&buffer_vec);
     let buffer = Buffer::from_str(&buffer_str).unwrap();
 
     let buffer_orig = Buffer {
@@ -1,4 +1,4 @@
-use crate::{Buffer, Error};
+use crate::{Buffer, Error, FormatsOutput};
 use std::ops::Index;
 use std::str::FromStr;
 
@@ -27,8 +27,8 @@ impl FromStr for Buffers {
 
     fn from_str(s: &str) -> Result<Self, Error> {
         let mut buffers = Buffers::new();
-        for line in s.lines() {
-            buffers.push(Buffer::from_str(line)?);
+        for record in FormatsOutput::delimited_records(s)? {
+            buffers.push(Buffer::from_str(record)?);
         }
         Ok(buffers)
     }
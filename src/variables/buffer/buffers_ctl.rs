@@ -39,8 +39,7 @@ impl<'a> BuffersCtl<'a> {
     pub fn get_all_ext(
         invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
     ) -> Result<Buffers, Error> {
-        let mut format = Formats::new();
-        format.separator(':');
+        let mut format = Formats::delimited();
 
         #[cfg(feature = "tmux_2_6")]
         format.buffer_created();
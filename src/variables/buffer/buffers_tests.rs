@@ -1,6 +1,7 @@
 #[test]
 fn buffers_parse() {
     use crate::Buffers;
+    use crate::FormatsOutput;
     use std::str::FromStr;
 
     let buffer0_vec = vec![
@@ -24,8 +25,8 @@ fn buffers_parse() {
         "3",
     ];
 
-    let buffer0_str = buffer0_vec.join(":");
-    let buffer1_str = buffer1_vec.join(":");
+    let buffer0_str = FormatsOutput::delimited_record(&buffer0_vec);
+    let buffer1_str = FormatsOutput::delimited_record(&buffer1_vec);
     let buffers_str = format!("{}\n{}", buffer0_str, buffer1_str);
     let buffers = Buffers::from_str(&buffers_str).unwrap();
 
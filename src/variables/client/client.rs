@@ -1,9 +1,14 @@
+use crate::formats::format_value::FormatValue;
+#[cfg(feature = "tmux_2_4")]
+use crate::ClientName;
 use crate::Error;
 use crate::FormatsOutput;
+use crate::Variable;
 use std::str::FromStr;
 
 // XXX: 1.9 processed
 #[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Client {
     /// client_activity - Integer time client last had activity
     #[cfg(feature = "tmux_1_6")]
@@ -43,7 +48,7 @@ pub struct Client {
     pub last_session: Option<String>,
     /// client_name - Name of client
     #[cfg(feature = "tmux_2_4")]
-    pub name: Option<String>,
+    pub name: Option<ClientName>,
     /// client_pid - PID of client process
     #[cfg(feature = "tmux_2_1")]
     pub pid: Option<usize>,
@@ -81,8 +86,7 @@ impl FromStr for Client {
 
     fn from_str(s: &str) -> Result<Self, Error> {
         let mut client = Client::new();
-        let mut format = FormatsOutput::new();
-        format.separator(':');
+        let mut format = FormatsOutput::delimited();
 
         #[cfg(feature = "tmux_1_6")]
         format.client_activity(&mut client.activity);
@@ -131,7 +135,7 @@ impl FromStr for Client {
         #[cfg(feature = "tmux_2_4")]
         format.client_written(&mut client.written);
 
-        FormatsOutput::from_string_ext(s, &mut format);
+        FormatsOutput::from_string_ext(s, &mut format)?;
         Ok(client)
     }
 }
@@ -141,5 +145,66 @@ impl Client {
         Default::default()
     }
 
+    /// Value of the client `variable`, as it would be expanded by tmux
+    pub fn value(&self, variable: &Variable) -> Option<String> {
+        match variable {
+            #[cfg(feature = "tmux_1_6")]
+            Variable::ClientActivity => self.activity.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::ClientCellHeight => self.cell_height.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::ClientCellWidth => self.cell_width.as_ref().map(FormatValue::format_value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_2")))]
+            Variable::ClientActivityString => {
+                self.activity_string.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_1_6")]
+            Variable::ClientCreated => self.created.as_ref().map(FormatValue::format_value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_2")))]
+            Variable::ClientCreatedString => {
+                self.created_string.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_2_1")]
+            Variable::ClientControlMode => {
+                self.control_mode.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_2_1")]
+            Variable::ClientDiscarded => self.discarded.as_ref().map(FormatValue::format_value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            Variable::ClientCwd => self.cwd.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::ClientHeight => self.height.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_2")]
+            Variable::ClientKeyTable => self.key_table.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_8")]
+            Variable::ClientLastSession => {
+                self.last_session.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_2_4")]
+            Variable::ClientName => self.name.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_1")]
+            Variable::ClientPid => self.pid.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_8")]
+            Variable::ClientPrefix => self.prefix.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::ClientReadonly => self.readonly.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_8")]
+            Variable::ClientSession => self.session.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::ClientTermname => self.termname.as_ref().map(FormatValue::format_value),
+            #[cfg(all(feature = "tmux_2_4", not(feature = "tmux_3_1")))]
+            Variable::ClientTermtype => self.termtype.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::ClientTty => self.tty.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::ClientUtf8 => self.utf8.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::ClientWidth => self.width.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_4")]
+            Variable::ClientWritten => self.written.as_ref().map(FormatValue::format_value),
+            _ => None,
+        }
+    }
+
     // XXX: wrapper with format generating and result parsing using callback
 }
@@ -62,7 +62,7 @@ fn client_default() {
 // client_activity:1707509930;client_cell_height:0;client_cell_width:0;client_control_mode:0;client_created:1707479629;client_discarded:0;client_flags:attached,focused,UTF-8;client_height:65;client_key_table:root;client_last_session:;client_name:/dev/pts/0;client_pid:3215;client_prefix:0;client_readonly:0;client_session:0;client_termfeatures:bpaste,ccolour,clipboard,cstyle,focus,title;client_termname:xterm-256color;client_termtype:;client_tty:/dev/pts/0;client_uid:1000;client_user:anton;client_utf8:1;client_width:177;client_written:206823
 #[test]
 fn client_parse() {
-    use crate::Client;
+    use crate::{Client, ClientName, FormatsOutput};
     use std::str::FromStr;
 
     let client_vec = vec![
@@ -140,7 +140,7 @@ fn client_parse() {
         #[cfg(feature = "tmux_2_4")]
         "193354",
     ];
-    let client_str = client_vec.join(":");
+    let client_str = FormatsOutput::delimited_record(&client_vec);
     let client = Client::from_str(&client_str).unwrap();
 
     let client_orig = Client {
@@ -171,7 +171,7 @@ fn client_parse() {
         #[cfg(feature = "tmux_1_8")]
         last_session: None,
         #[cfg(feature = "tmux_2_4")]
-        name: Some("/dev/pts/0".to_string()),
+        name: Some(ClientName::from("/dev/pts/0")),
         #[cfg(feature = "tmux_2_1")]
         pid: Some(32150),
         #[cfg(feature = "tmux_1_8")]
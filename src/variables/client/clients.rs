@@ -1,4 +1,4 @@
-use crate::{Client, Error};
+use crate::{Client, Error, FormatsOutput};
 use std::ops::Index;
 use std::str::FromStr;
 
@@ -27,8 +27,8 @@ impl FromStr for Clients {
 
     fn from_str(s: &str) -> Result<Self, Error> {
         let mut clients = Clients::new();
-        for line in s.lines() {
-            clients.push(Client::from_str(line)?);
+        for record in FormatsOutput::delimited_records(s)? {
+            clients.push(Client::from_str(record)?);
         }
         Ok(clients)
     }
@@ -39,8 +39,7 @@ impl<'a> ClientsCtl<'a> {
     pub fn get_all_ext(
         invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
     ) -> Result<Clients, Error> {
-        let mut format = Formats::new();
-        format.separator(':');
+        let mut format = Formats::delimited();
 
         #[cfg(feature = "tmux_1_6")]
         format.client_activity();
@@ -1,6 +1,7 @@
 #[test]
 fn clients_parse() {
     use crate::Clients;
+    use crate::FormatsOutput;
     use std::str::FromStr;
 
     let client0_vec = vec![
@@ -153,8 +154,8 @@ fn clients_parse() {
         #[cfg(feature = "tmux_2_4")]
         "193354",
     ];
-    let client0_str = client0_vec.join(":");
-    let client1_str = client1_vec.join(":");
+    let client0_str = FormatsOutput::delimited_record(&client0_vec);
+    let client1_str = FormatsOutput::delimited_record(&client1_vec);
     let clients_str = format!("{}\n{}", client0_str, client1_str);
     let clients = Clients::from_str(&clients_str).unwrap();
 
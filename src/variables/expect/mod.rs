@@ -0,0 +1,15 @@
+#[cfg(feature = "tmux_2_4")]
+pub mod pane_driver;
+pub mod pattern;
+
+#[cfg(feature = "tmux_2_4")]
+pub use pane_driver::{PaneDriver, PANE_DRIVER_INTERVAL, PANE_DRIVER_TIMEOUT};
+pub use pattern::Pattern;
+
+#[cfg(test)]
+#[path = "."]
+mod variables_expect_tests {
+    #[cfg(feature = "tmux_2_4")]
+    mod pane_driver_tests;
+    mod pattern_tests;
+}
@@ -0,0 +1,200 @@
+use crate::{CapturePane, Error, PaneHandle, Pattern, SendKeys, TmuxCommand};
+use std::borrow::Cow;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Expect-style automation, polling pane contents
+//
+// 1. send input (`send-keys`)
+// 2. capture whole pane history (`capture-pane -p -J -S -`) every `interval`
+// 3. search pattern after the end of the previous match, until `timeout`
+// 4. on timeout the last captured contents (transcript) are returned in the error
+//
+// The end of the previous match is a byte offset into the transcript, it stays valid while
+// output is only appended. If the contents before it changed (`clear`, history lines dropped
+// over `history-limit`), the line of the previous match is searched for again, nearest to its
+// old line number, or the whole transcript is searched if it is gone
+
+/// Default time to wait for a pattern
+pub const PANE_DRIVER_TIMEOUT: Duration = Duration::from_secs(10);
+/// Default interval between two captures
+pub const PANE_DRIVER_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Send input to a pane and wait for output patterns
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::{PaneDriver, PaneHandle, PaneId};
+///
+/// let mut driver = PaneDriver::new(PaneHandle::new(PaneId(0)));
+/// driver.send_line("python3").unwrap();
+/// driver.wait_for_prompt(">>> ").unwrap();
+/// driver.send_line("print(6 * 7)").unwrap();
+/// driver.expect("42").unwrap();
+/// ```
+pub struct PaneDriver<'a> {
+    pub pane: PaneHandle<'a>,
+    pub timeout: Duration,
+    pub interval: Duration,
+    // byte offset of the end of the previous match in `transcript`
+    position: usize,
+    // line of the previous match up to its end, to re-locate it
+    anchor: String,
+    transcript: String,
+}
+
+impl<'a> PaneDriver<'a> {
+    pub fn new(pane: PaneHandle<'a>) -> Self {
+        Self {
+            pane,
+            timeout: PANE_DRIVER_TIMEOUT,
+            interval: PANE_DRIVER_INTERVAL,
+            position: 0,
+            anchor: String::new(),
+            transcript: String::new(),
+        }
+    }
+
+    /// Set time to wait for a pattern
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set interval between two captures
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    fn invoke(&self, cmd: TmuxCommand<'a>) -> Result<String, Error> {
+        Ok((self.pane.invoker)(cmd)?.checked()?.to_string())
+    }
+
+    /// Send text as is, without key name lookup (`send-keys -l`)
+    pub fn send_text<S: Into<Cow<'a, str>>>(&self, text: S) -> Result<(), Error> {
+        let cmd = SendKeys::new()
+            .disable_lookup()
+            .target_pane(self.pane.id)
+            .key(text);
+        self.invoke(cmd.build())?;
+        Ok(())
+    }
+
+    /// Send key names (`send-keys`, e.g. `["C-c"]`, `["Up", "Enter"]`)
+    pub fn send_keys<I, S>(&self, keys: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'a, str>>,
+    {
+        self.pane.send_keys(keys)
+    }
+
+    /// Send text followed by `Enter`
+    pub fn send_line<S: Into<Cow<'a, str>>>(&self, text: S) -> Result<(), Error> {
+        self.send_text(text)?;
+        self.send_keys(["Enter"])
+    }
+
+    /// Capture current pane contents (including history), returns them
+    pub fn refresh(&mut self) -> Result<&str, Error> {
+        let cmd = CapturePane::new()
+            .stdout()
+            .join()
+            .start_line("-")
+            .target_pane(self.pane.id);
+        let transcript = self.invoke(cmd.build())?;
+        let previous = &self.transcript[..self.position];
+        if transcript.get(..self.position) != Some(previous) {
+            self.position = self.locate(&transcript);
+        }
+        self.transcript = transcript;
+        Ok(&self.transcript)
+    }
+
+    // end of the previous match in changed contents, nearest to its old line
+    fn locate(&self, transcript: &str) -> usize {
+        if self.anchor.is_empty() {
+            return 0;
+        }
+        let line = self.transcript[..self.position].matches('\n').count();
+        transcript
+            .match_indices(self.anchor.as_str())
+            .map(|(start, anchor)| start + anchor.len())
+            .min_by_key(|end| {
+                let end_line = transcript[..*end].matches('\n').count();
+                (end_line as isize - line as isize).abs()
+            })
+            .unwrap_or(0)
+    }
+
+    /// Pane contents seen by the last capture
+    pub fn transcript(&self) -> &str {
+        &self.transcript
+    }
+
+    /// Wait until `pattern` appears after the previous match, returns the matched text
+    pub fn expect<P: Pattern>(&mut self, pattern: P) -> Result<String, Error> {
+        self.wait_for(&pattern, false)
+    }
+
+    /// Wait until `prompt` appears after the previous match, followed only by whitespace (the
+    /// program is waiting for input)
+    pub fn wait_for_prompt<P: Pattern>(&mut self, prompt: P) -> Result<(), Error> {
+        self.wait_for(&prompt, true)?;
+        Ok(())
+    }
+
+    fn wait_for<P: Pattern>(&mut self, pattern: &P, at_end: bool) -> Result<String, Error> {
+        let start = Instant::now();
+        loop {
+            self.refresh()?;
+            let rest = &self.transcript[self.position..];
+            if let Some(range) = pattern.find_in(rest) {
+                if !at_end || rest[range.end..].trim().is_empty() {
+                    let matched = rest[range.clone()].to_string();
+                    let (start, end) = (self.position + range.start, self.position + range.end);
+                    let line_start = self.transcript[..start].rfind('\n').map_or(0, |i| i + 1);
+                    self.anchor = self.transcript[line_start..end].to_string();
+                    self.position = end;
+                    return Ok(matched);
+                }
+            }
+            if start.elapsed() >= self.timeout {
+                return Err(Error::ExpectTimeout(
+                    pattern.description(),
+                    self.transcript.clone(),
+                ));
+            }
+            thread::sleep(self.interval);
+        }
+    }
+
+    /// Wait until the pane process exits, returns its exit status (`pane_dead_status`)
+    ///
+    /// The pane must be kept after the process exits (`remain-on-exit` option), `None` is
+    /// returned if the pane was closed
+    pub fn expect_exit(&mut self) -> Result<Option<usize>, Error> {
+        let start = Instant::now();
+        loop {
+            match self.pane.get() {
+                Ok(pane) if pane.dead == Some(true) => {
+                    self.refresh()?;
+                    return Ok(pane.dead_status);
+                }
+                Ok(_) => {}
+                Err(Error::TargetNotFound(..)) => return Ok(None),
+                Err(e) => return Err(e),
+            }
+            if start.elapsed() >= self.timeout {
+                self.refresh()?;
+                return Err(Error::ExpectTimeout(
+                    "exit".to_string(),
+                    self.transcript.clone(),
+                ));
+            }
+            thread::sleep(self.interval);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::variables::test_session::TestSession;
+
+fn new_session(
+    session_name: &str,
+    shell_command: &str,
+) -> (TestSession, crate::PaneDriver<'static>) {
+    use crate::{PaneDriver, SetOption, Tmux};
+    use std::time::Duration;
+
+    let session = TestSession::with_command(session_name, shell_command);
+    let id = session.pane_id;
+
+    // keep the pane after exit for `pane_dead_status`
+    let set_option = SetOption::new()
+        .window()
+        .option("remain-on-exit")
+        .value("on");
+    #[cfg(feature = "tmux_3_0a")]
+    let set_option = set_option.target_pane(id);
+    #[cfg(not(feature = "tmux_3_0a"))]
+    let set_option = set_option.target_window(id.to_string());
+    Tmux::with_command(set_option).output().unwrap();
+
+    let driver = PaneDriver::new(session.pane()).timeout(Duration::from_secs(5));
+    (session, driver)
+}
+
+#[test]
+fn expect() {
+    let (_session, mut driver) = new_session(
+        "pane_driver_expect_test",
+        "printf 'name? '; read name; echo \"hello $name\"; read n; exit $n",
+    );
+
+    driver.wait_for_prompt("name? ").unwrap();
+    driver.send_line("world").unwrap();
+    assert_eq!(driver.expect("hello").unwrap(), "hello");
+    // searched after the previous match
+    assert_eq!(driver.expect(' ').unwrap(), " ");
+    driver.expect("world").unwrap();
+    assert!(driver.transcript().contains("name? world\nhello world"));
+
+    driver.send_text("3").unwrap();
+    driver.send_keys(["Enter"]).unwrap();
+    assert_eq!(driver.expect_exit().unwrap(), Some(3));
+}
+
+#[test]
+fn expect_clear() {
+    // `clear` may keep the screen in the history, `\033[3J` clears the history
+    let (_session, mut driver) = new_session(
+        "pane_driver_clear_test",
+        "echo first output; read x; clear; printf '\\033[3J'; echo two; read x",
+    );
+
+    driver.expect("first output").unwrap();
+    driver.send_line("x").unwrap();
+    // searched in the new contents, not after the old offset
+    driver.expect("two").unwrap();
+    assert!(!driver.transcript().contains("first output"));
+}
+
+#[test]
+fn expect_timeout() {
+    use crate::Error;
+    use std::time::Duration;
+
+    let (session, driver) = new_session("pane_driver_timeout_test", "echo ready; cat");
+    let mut driver = driver.timeout(Duration::from_millis(500));
+
+    driver.expect("ready").unwrap();
+    match driver.expect("ready") {
+        Err(Error::ExpectTimeout(pattern, transcript)) => {
+            assert_eq!(pattern, "\"ready\"");
+            assert!(transcript.starts_with("ready\n"));
+        }
+        _ => panic!("timeout expected"),
+    }
+    assert!(matches!(
+        driver.expect_exit(),
+        Err(Error::ExpectTimeout(..))
+    ));
+
+    drop(session);
+    assert_eq!(driver.expect_exit().unwrap(), None);
+}
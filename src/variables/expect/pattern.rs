@@ -0,0 +1,65 @@
+use std::ops::Range;
+
+/// Pattern searched in pane contents by [`PaneDriver`](crate::PaneDriver)
+///
+/// Implemented for strings, chars and (`regex` feature) `regex::Regex`
+pub trait Pattern {
+    /// Byte range of the first match in `haystack`
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>>;
+
+    /// Pattern description used in error messages
+    fn description(&self) -> String;
+}
+
+impl Pattern for str {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        haystack.find(self).map(|start| start..start + self.len())
+    }
+
+    fn description(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Pattern for String {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        self.as_str().find_in(haystack)
+    }
+
+    fn description(&self) -> String {
+        self.as_str().description()
+    }
+}
+
+impl Pattern for char {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        haystack
+            .find(*self)
+            .map(|start| start..start + self.len_utf8())
+    }
+
+    fn description(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Pattern for regex::Regex {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        self.find(haystack).map(|m| m.range())
+    }
+
+    fn description(&self) -> String {
+        format!("/{}/", self.as_str())
+    }
+}
+
+impl<P: Pattern + ?Sized> Pattern for &P {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        (**self).find_in(haystack)
+    }
+
+    fn description(&self) -> String {
+        (**self).description()
+    }
+}
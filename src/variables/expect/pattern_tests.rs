@@ -0,0 +1,26 @@
+#[test]
+fn find_in() {
+    use crate::Pattern;
+
+    let haystack = "$ echo héllo\nhéllo\n$ ";
+    assert_eq!("héllo".find_in(haystack), Some(7..13));
+    assert_eq!("héllo".to_string().find_in(haystack), Some(7..13));
+    assert_eq!('\n'.find_in(haystack), Some(13..14));
+    assert_eq!("bye".find_in(haystack), None);
+    assert_eq!((&"$ ").find_in(&haystack[14..]), Some(7..9));
+
+    assert_eq!("$ ".description(), "\"$ \"");
+    assert_eq!('>'.description(), "'>'");
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn find_in_regex() {
+    use crate::Pattern;
+    use regex::Regex;
+
+    let re = Regex::new(r"h\w+o").unwrap();
+    assert_eq!(re.find_in("$ echo hello"), Some(7..12));
+    assert_eq!(re.find_in("$ "), None);
+    assert_eq!(re.description(), r"/h\w+o/");
+}
@@ -0,0 +1,192 @@
+#[cfg(feature = "tmux_2_3")]
+use crate::{Buffer, BuffersCtl};
+use crate::{DeleteBuffer, Error, LoadBuffer, SaveBuffer, SetBuffer, StdIO, Tmux, TmuxCommand};
+use std::borrow::Cow;
+use std::io::{self, Read, Write};
+use std::process::Child;
+
+// maximum length of data appended by `set-buffer -a`, longer data is read and written back
+const SET_BUFFER_DATA_MAX: usize = 1024;
+
+/// Paste buffer bound to a tmux client configuration (socket, ...), all commands are targeted
+/// by buffer name
+///
+/// Contents are transferred via stdin (`load-buffer -`) and stdout (`save-buffer -`) of the
+/// tmux client, so they can contain any bytes (`NUL`, invalid UTF-8) and aren't limited by the
+/// maximum argument length of `set-buffer`
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::BufferHandle;
+///
+/// let buffer = BufferHandle::new("data");
+/// buffer.write(b"\x00\x01\x02").unwrap();
+/// assert_eq!(buffer.read().unwrap(), b"\x00\x01\x02");
+/// buffer.delete().unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct BufferHandle<'a> {
+    pub name: Cow<'a, str>,
+    /// tmux client used for every command (e.g. `Tmux::new().socket_name("test")`)
+    pub tmux: Tmux<'a>,
+}
+
+impl<'a> BufferHandle<'a> {
+    pub fn new<S: Into<Cow<'a, str>>>(name: S) -> Self {
+        Self::with_tmux(name, Tmux::new())
+    }
+
+    pub fn with_tmux<S: Into<Cow<'a, str>>>(name: S, tmux: Tmux<'a>) -> Self {
+        Self {
+            name: name.into(),
+            tmux,
+        }
+    }
+
+    fn invoke(&self, cmd: TmuxCommand<'a>) -> Result<(), Error> {
+        self.tmux.clone().command(cmd).output()?.checked()?;
+        Ok(())
+    }
+
+    // wait for the spawned client, stderr is returned as error message on failure
+    fn wait(child: Child) -> Result<(), Error> {
+        let output = child.wait_with_output()?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(Error::Tmux(stderr.trim_end().to_string()))
+        }
+    }
+
+    /// Buffer variables (`list-buffers`)
+    #[cfg(feature = "tmux_2_3")]
+    pub fn get(&self) -> Result<Buffer, Error> {
+        let buffers = BuffersCtl::get_all_ext(|cmd| self.tmux.clone().command(cmd).output())?;
+        buffers
+            .into_iter()
+            .find(|buffer| buffer.name.as_deref() == Some(self.name.as_ref()))
+            .ok_or_else(|| Error::TargetNotFound("buffer", self.name.to_string()))
+    }
+
+    /// Contents of the buffer (`save-buffer -`)
+    pub fn read(&self) -> Result<Vec<u8>, Error> {
+        let mut data = Vec::new();
+        self.read_to(&mut data)?;
+        Ok(data)
+    }
+
+    /// Copy contents of the buffer into `writer` without loading them in memory
+    /// (`save-buffer -`), returns number of bytes copied
+    pub fn read_to<W: Write>(&self, mut writer: W) -> Result<u64, Error> {
+        let cmd = SaveBuffer::new().buffer_name(self.name.clone()).path("-");
+        let mut child = self
+            .tmux
+            .clone()
+            .command(cmd)
+            .stdin(Some(StdIO::Null))
+            .stdout(Some(StdIO::Piped))
+            .stderr(Some(StdIO::Piped))
+            .spawn()?;
+        let copied = match child.stdout.take() {
+            Some(mut stdout) => io::copy(&mut stdout, &mut writer),
+            None => Ok(0),
+        };
+        // tmux error message is more relevant than a copy error
+        Self::wait(child)?;
+        Ok(copied?)
+    }
+
+    /// Replace contents of the buffer, the buffer is created if it doesn't exist
+    /// (`load-buffer -`)
+    pub fn write(&self, data: &[u8]) -> Result<(), Error> {
+        self.write_from(data)
+    }
+
+    /// Replace contents of the buffer with all data from `reader` (`load-buffer -`)
+    pub fn write_from<R: Read>(&self, reader: R) -> Result<(), Error> {
+        self.load(LoadBuffer::new(), reader)
+    }
+
+    /// Replace contents of the buffer and send them to the clipboard of the client, using
+    /// OSC 52 (`load-buffer -w -`)
+    #[cfg(feature = "tmux_3_2")]
+    pub fn write_to_clipboard(&self, data: &[u8]) -> Result<(), Error> {
+        self.load(LoadBuffer::new().send_to_clipboard(), data)
+    }
+
+    fn load<R: Read>(&self, cmd: LoadBuffer<'a>, mut reader: R) -> Result<(), Error> {
+        // read all data before starting tmux, a failing reader would load truncated data at EOF
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        let cmd = cmd.buffer_name(self.name.clone()).path("-");
+        let mut child = self
+            .tmux
+            .clone()
+            .command(cmd)
+            .stdin(Some(StdIO::Piped))
+            .stdout(Some(StdIO::Null))
+            .stderr(Some(StdIO::Piped))
+            .spawn()?;
+        // stdin is closed when dropped, tmux loads the buffer at EOF
+        let written = match child.stdin.take() {
+            Some(mut stdin) => stdin.write_all(&data),
+            None => Ok(()),
+        };
+        Self::wait(child)?;
+        written?;
+        Ok(())
+    }
+
+    /// Append data to the buffer, the buffer is created if it doesn't exist
+    ///
+    /// Short UTF-8 text is appended by `set-buffer -a`, other data is appended by reading the
+    /// whole contents and writing them back, concurrent changes of the buffer are lost then
+    pub fn append(&self, data: &[u8]) -> Result<(), Error> {
+        match std::str::from_utf8(data) {
+            Ok(text) if Self::is_argument(text) => {
+                let cmd = SetBuffer::new()
+                    .append()
+                    .buffer_name(self.name.clone())
+                    .data(text.to_string());
+                self.invoke(cmd.build())
+            }
+            _ => {
+                let mut contents = match self.read() {
+                    Ok(contents) => contents,
+                    Err(Error::Tmux(message)) if message.starts_with("no buffer") => Vec::new(),
+                    Err(e) => return Err(e),
+                };
+                contents.extend_from_slice(data);
+                self.write(&contents)
+            }
+        }
+    }
+
+    // text passed unchanged as `set-buffer` argument, tmux parses a leading `-` as a flag and a
+    // trailing `;` as a command separator
+    fn is_argument(text: &str) -> bool {
+        !text.is_empty()
+            && text.len() <= SET_BUFFER_DATA_MAX
+            && !text.contains('\0')
+            && !text.starts_with('-')
+            && !text.ends_with(';')
+    }
+
+    /// Rename the buffer (`set-buffer -n`)
+    pub fn rename<S: Into<Cow<'a, str>>>(&mut self, new_name: S) -> Result<(), Error> {
+        let new_name = new_name.into();
+        let cmd = SetBuffer::new()
+            .buffer_name(self.name.clone())
+            .new_buffer_name(new_name.clone());
+        self.invoke(cmd.build())?;
+        self.name = new_name;
+        Ok(())
+    }
+
+    /// Delete the buffer (`delete-buffer`)
+    pub fn delete(self) -> Result<(), Error> {
+        self.invoke(DeleteBuffer::new().buffer_name(self.name.clone()).build())
+    }
+}
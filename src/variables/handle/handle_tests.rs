@@ -0,0 +1,258 @@
+use crate::variables::test_session::TestSession;
+use crate::Error;
+
+#[test]
+fn session_handle() {
+    let test_session = TestSession::new("session_handle_test");
+    let session = test_session.session();
+
+    let renamed = session.rename("session_handle_test_renamed").unwrap();
+    assert_eq!(renamed.id, Some(session.id));
+    assert_eq!(
+        renamed.name,
+        Some("session_handle_test_renamed".to_string())
+    );
+
+    let window = session.new_window().unwrap();
+    let windows = session.windows().unwrap();
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows[1].id, window.id);
+
+    session.kill().unwrap();
+    assert!(matches!(session.get(), Err(Error::TargetNotFound(..))));
+    assert!(matches!(session.kill(), Err(Error::Tmux(_))));
+}
+
+#[test]
+fn window_handle() {
+    let test_session = TestSession::new("window_handle_test");
+    let (session, window) = (test_session.session(), test_session.window());
+
+    let renamed = window.rename("renamed").unwrap();
+    assert_eq!(renamed.id, Some(window.id));
+    assert_eq!(renamed.name, Some("renamed".to_string()));
+
+    let pane = window.split().unwrap();
+    let panes = window.panes().unwrap();
+    assert_eq!(panes.len(), 2);
+    assert_eq!(panes[1].id, pane.id);
+    assert_eq!(pane.window().unwrap().id, window.id);
+
+    let before = window.get().unwrap().layout;
+    let after = window.select_layout("even-horizontal").unwrap().layout;
+    assert_ne!(before, after);
+
+    window.kill().unwrap();
+    assert!(matches!(session.get(), Err(Error::TargetNotFound(..))));
+}
+
+#[test]
+fn pane_handle() {
+    let test_session = TestSession::new("pane_handle_test");
+    let session = test_session.session();
+    let window = session.windows().unwrap()[0];
+    let pane = window.panes().unwrap()[0];
+    let other = pane.split().unwrap();
+
+    pane.send_keys(["printf 'pane%s\\n' _handle", "Enter"].iter().copied())
+        .unwrap();
+    // shell may be not ready yet
+    let mut output = String::new();
+    for _ in 0..50 {
+        output = pane.capture().unwrap();
+        if output.contains("pane_handle") {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    assert!(output.contains("pane_handle"));
+
+    let resized = window
+        .select_layout("even-vertical")
+        .and_then(|_| other.resize(None, Some(5)))
+        .unwrap();
+    assert_eq!(resized.height, Some(5));
+
+    other.kill().unwrap();
+    assert_eq!(window.panes().unwrap().len(), 1);
+}
+
+#[test]
+fn buffer_handle() {
+    use crate::BufferHandle;
+
+    let _session = TestSession::new("buffer_handle_test");
+
+    let mut buffer = BufferHandle::new("buffer_handle_test");
+    let data: Vec<u8> = (0..=255).cycle().take(300_000).collect();
+    buffer.write(&data).unwrap();
+    assert_eq!(buffer.read().unwrap(), data);
+
+    // a failing reader leaves the buffer unchanged
+    struct FailingReader;
+    impl std::io::Read for FailingReader {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::Other.into())
+        }
+    }
+    let reader = std::io::Read::chain(&b"truncated"[..], FailingReader);
+    assert!(matches!(buffer.write_from(reader), Err(Error::IO(_))));
+    assert_eq!(buffer.read().unwrap(), data);
+
+    buffer.write(b"a\x00b").unwrap();
+    buffer.append(b"\xffc").unwrap();
+    let mut read = Vec::new();
+    assert_eq!(buffer.read_to(&mut read).unwrap(), 5);
+    assert_eq!(read, b"a\x00b\xffc");
+    #[cfg(feature = "tmux_2_3")]
+    assert_eq!(buffer.get().unwrap().size, Some(5));
+
+    buffer.rename("buffer_handle_test_renamed").unwrap();
+    assert_eq!(buffer.name, "buffer_handle_test_renamed");
+    assert_eq!(buffer.read().unwrap(), b"a\x00b\xffc");
+    assert!(matches!(
+        BufferHandle::new("buffer_handle_test").read(),
+        Err(Error::Tmux(_))
+    ));
+
+    buffer.clone().delete().unwrap();
+    #[cfg(feature = "tmux_2_3")]
+    assert!(matches!(buffer.get(), Err(Error::TargetNotFound(..))));
+    assert!(matches!(buffer.delete(), Err(Error::Tmux(_))));
+}
+
+#[test]
+fn buffer_handle_append() {
+    use crate::{BufferHandle, Tmux};
+
+    let _session = TestSession::new("buffer_handle_append_test");
+
+    // created by `set-buffer -a`, appended by `set-buffer -a` and read-modify-write
+    let buffer = BufferHandle::new("buffer_handle_append_test");
+    buffer.append("text ".as_bytes()).unwrap();
+    buffer.append(b"-a;").unwrap();
+    buffer.append("\u{e9}#{pane_id}".as_bytes()).unwrap();
+    assert_eq!(
+        buffer.read().unwrap(),
+        "text -a;\u{e9}#{pane_id}".as_bytes()
+    );
+    buffer.delete().unwrap();
+
+    let buffer = BufferHandle::new("buffer_handle_append_test");
+    buffer.append(b"\xff").unwrap();
+    assert_eq!(buffer.read().unwrap(), b"\xff");
+    buffer.delete().unwrap();
+
+    // only a missing buffer is empty
+    let tmux = Tmux::new().socket_name("buffer_handle_append_no_server");
+    let buffer = BufferHandle::with_tmux("buffer_handle_append_test", tmux);
+    assert!(matches!(buffer.append(b"\xff"), Err(Error::Tmux(_))));
+}
+
+#[cfg(feature = "tmux_2_7")]
+#[cfg(unix)]
+#[test]
+fn pane_pipe() {
+    use std::io::{Read, Write};
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    // terminal echoes the input, `cat` prints it again
+    let session = TestSession::with_command("pane_pipe_test", "cat");
+    let pane = session.pane();
+
+    let mut pipe = pane.pipe().directions(true, true);
+    pipe.start().unwrap();
+    assert!(pipe.is_started());
+
+    let mut reader = pipe.take_reader().unwrap();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut output = Vec::new();
+        let mut buf = [0; 256];
+        while let Ok(n) = reader.read(&mut buf) {
+            output.extend_from_slice(&buf[..n]);
+            let s = String::from_utf8_lossy(&output);
+            if n == 0 || s.matches("pipe_test").count() == 2 {
+                break;
+            }
+        }
+        tx.send(output).unwrap();
+    });
+
+    pipe.write_all(b"pipe_test\n").unwrap();
+    let output = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&output)
+            .matches("pipe_test")
+            .count(),
+        2
+    );
+
+    pipe.stop().unwrap();
+    assert!(!pipe.is_started());
+    assert!(pipe.write_all(b"closed\n").is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn pane_pipe_timeout() {
+    use crate::{PaneId, PanePipe, TmuxCommand, TmuxOutput};
+    use std::io;
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::{ExitStatus, Output};
+    use std::time::Duration;
+
+    // `pipe-pane` succeeds, but nothing opens the FIFO
+    let invoker = |_: TmuxCommand| {
+        Ok(TmuxOutput(Output {
+            status: ExitStatus::from_raw(0),
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        }))
+    };
+    let mut pipe = PanePipe::with_invoker(PaneId(1), &invoker).timeout(Duration::from_millis(100));
+    match pipe.start() {
+        Err(Error::IO(e)) => assert_eq!(e.kind(), io::ErrorKind::TimedOut),
+        _ => panic!("timeout expected"),
+    }
+    assert!(!pipe.is_started());
+}
+
+#[cfg(feature = "tmux_2_7")]
+#[cfg(unix)]
+#[test]
+fn pane_pipe_start_error() {
+    use crate::{PaneId, PanePipe, TmuxCommand, TmuxOutput};
+    use std::io;
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::{ExitStatus, Output};
+
+    let invoker = |_: TmuxCommand| {
+        Ok(TmuxOutput(Output {
+            status: ExitStatus::from_raw(1 << 8),
+            stdout: Vec::new(),
+            stderr: b"can't find pane: %1001".to_vec(),
+        }))
+    };
+
+    // no direction selected
+    let mut pipe = PanePipe::with_invoker(PaneId(1001), &invoker).directions(false, false);
+    match pipe.start() {
+        Err(Error::IO(e)) => assert_eq!(e.kind(), io::ErrorKind::InvalidInput),
+        _ => panic!("invalid input expected"),
+    }
+    assert!(!pipe.is_started());
+
+    // temporary directory is removed if `pipe-pane` fails
+    let mut pipe = PanePipe::with_invoker(PaneId(1001), &invoker);
+    assert!(matches!(pipe.start(), Err(Error::Tmux(_))));
+    assert!(!pipe.is_started());
+    let prefix = format!("tmux_interface_pipe_{}_1001_", std::process::id());
+    let left = std::fs::read_dir(std::env::temp_dir())
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .any(|entry| entry.file_name().to_string_lossy().starts_with(&prefix));
+    assert!(!left);
+}
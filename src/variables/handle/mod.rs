@@ -0,0 +1,30 @@
+#[cfg(feature = "tmux_2_0")]
+pub mod buffer_handle;
+#[cfg(feature = "tmux_1_8")]
+pub mod pane_handle;
+#[cfg(feature = "tmux_1_8")]
+#[cfg(unix)]
+pub mod pane_pipe;
+#[cfg(feature = "tmux_1_8")]
+pub mod session_handle;
+#[cfg(feature = "tmux_1_8")]
+pub mod window_handle;
+
+#[cfg(feature = "tmux_2_0")]
+pub use buffer_handle::BufferHandle;
+#[cfg(feature = "tmux_1_8")]
+pub use pane_handle::PaneHandle;
+#[cfg(feature = "tmux_1_8")]
+#[cfg(unix)]
+pub use pane_pipe::PanePipe;
+#[cfg(feature = "tmux_1_8")]
+pub use session_handle::SessionHandle;
+#[cfg(feature = "tmux_1_8")]
+pub use window_handle::WindowHandle;
+
+#[cfg(test)]
+#[path = "."]
+mod variables_handle_tests {
+    #[cfg(feature = "tmux_2_4")]
+    mod handle_tests;
+}
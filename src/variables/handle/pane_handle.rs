@@ -0,0 +1,134 @@
+#[cfg(unix)]
+use crate::PanePipe;
+#[cfg(feature = "tmux_2_4")]
+use crate::SendKeys;
+use crate::{
+    CapturePane, DisplayMessage, Error, KillPane, Pane, PaneId, PanesCtl, ResizePane, Screen,
+    ScreenCtl, SplitWindow, Tmux, TmuxCommand, TmuxOutput, WindowHandle, WindowId,
+};
+#[cfg(feature = "tmux_2_4")]
+use std::borrow::Cow;
+use std::str::FromStr;
+
+/// Pane bound to an invoker, all commands are targeted by pane id (`%1`)
+#[derive(Clone, Copy)]
+pub struct PaneHandle<'a> {
+    pub id: PaneId,
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+}
+
+impl<'a> PaneHandle<'a> {
+    pub fn new(id: PaneId) -> Self {
+        Self {
+            id,
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+        }
+    }
+
+    pub fn with_invoker(
+        id: PaneId,
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Self {
+        Self { id, invoker }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    fn invoke(&self, cmd: TmuxCommand<'a>) -> Result<String, Error> {
+        Ok((self.invoker)(cmd)?.checked()?.to_string())
+    }
+
+    /// Current pane variables (`display-message -p`)
+    pub fn get(&self) -> Result<Pane, Error> {
+        let cmd = DisplayMessage::new()
+            .print()
+            .target_pane(self.id)
+            .message(PanesCtl::formats().to_string());
+        let output = self.invoke(cmd.build())?;
+        // tmux falls back to the current pane, if the target doesn't exist anymore
+        let pane = Pane::from_str(output.trim_end_matches('\n'))?;
+        match pane.id {
+            Some(id) if id == self.id => Ok(pane),
+            _ => Err(Error::TargetNotFound("pane", self.id.to_string())),
+        }
+    }
+
+    /// Window containing the pane
+    pub fn window(&self) -> Result<WindowHandle<'a>, Error> {
+        let cmd = DisplayMessage::new()
+            .print()
+            .target_pane(self.id)
+            .message("#{window_id}");
+        let output = self.invoke(cmd.build())?;
+        let id = output.trim().parse::<WindowId>()?;
+        Ok(WindowHandle::with_invoker(id, self.invoker))
+    }
+
+    /// Send keys to the pane (`send-keys`, e.g. `["echo hello", "Enter"]`)
+    #[cfg(feature = "tmux_2_4")]
+    pub fn send_keys<I, S>(&self, keys: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'a, str>>,
+    {
+        let cmd = keys
+            .into_iter()
+            .fold(SendKeys::new().target_pane(self.id), |cmd, key| {
+                cmd.key(key)
+            });
+        self.invoke(cmd.build())?;
+        Ok(())
+    }
+
+    /// Visible contents of the pane (`capture-pane -p`)
+    pub fn capture(&self) -> Result<String, Error> {
+        let cmd = CapturePane::new().stdout().target_pane(self.id);
+        self.invoke(cmd.build())
+    }
+
+    /// Visible contents of the pane with colours and attributes (`capture-pane -e -p`), see
+    /// [`ScreenCtl`] for other options
+    pub fn screen(&self) -> Result<Screen, Error> {
+        ScreenCtl::with_invoker(self.invoker).get(self.id)
+    }
+
+    /// Pipe streaming output of the pane (not started, see [`PanePipe::start`])
+    #[cfg(unix)]
+    pub fn pipe(&self) -> PanePipe<'a> {
+        PanePipe::with_invoker(self.id, self.invoker)
+    }
+
+    /// Split the pane, without selecting the new pane (`split-window -d`)
+    pub fn split(&self) -> Result<PaneHandle<'a>, Error> {
+        let cmd = SplitWindow::new()
+            .detached()
+            .print()
+            .format("#{pane_id}")
+            .target_pane(self.id);
+        let output = self.invoke(cmd.build())?;
+        let id = output.trim().parse::<PaneId>()?;
+        Ok(PaneHandle::with_invoker(id, self.invoker))
+    }
+
+    /// Resize the pane to the given width and (or) height in cells (`resize-pane -x -y`),
+    /// returns refreshed pane variables
+    pub fn resize(&self, width: Option<usize>, height: Option<usize>) -> Result<Pane, Error> {
+        let mut cmd = ResizePane::new().target_pane(self.id);
+        if let Some(width) = width {
+            cmd = cmd.width(width.to_string());
+        }
+        if let Some(height) = height {
+            cmd = cmd.height(height.to_string());
+        }
+        self.invoke(cmd.build())?;
+        self.get()
+    }
+
+    /// Kill the pane (`kill-pane`)
+    pub fn kill(self) -> Result<(), Error> {
+        self.invoke(KillPane::new().target_pane(self.id).build())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{Error, PaneId, PipePane, Tmux, TmuxCommand, TmuxOutput};
+use std::fs::{self, DirBuilder, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::os::unix::fs::DirBuilderExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+// Pane output is streamed through named pipes (FIFOs) in a private temporary directory
+//
+// 1. `mkfifo output input`
+// 2. `pipe-pane -O 'cat > output'`, `pipe-pane -I 'cat < input'` or both
+//    (`pipe-pane -I -O 'cat < input & cat > output'`)
+// 3. open FIFOs, blocks until `cat` opens the other end (or `timeout`, if `cat` wasn't started)
+// 4. `pipe-pane` without command closes the pipe, `cat` gets EOF and exits, FIFOs are removed
+
+/// Default time to wait for the pipe command to open the FIFOs
+pub const PANE_PIPE_TIMEOUT: Duration = Duration::from_secs(5);
+
+const PANE_PIPE_OUTPUT: &str = "output";
+#[cfg(feature = "tmux_2_7")]
+const PANE_PIPE_INPUT: &str = "input";
+
+// unique directory name for each pipe of the process
+static PANE_PIPE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Output of a pane as [`Read`] stream and (tmux >= 2.7) input of a pane as [`Write`] stream
+/// (`pipe-pane`)
+///
+/// The pipe is closed and its temporary files removed when dropped
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::io::Read;
+/// use tmux_interface::{PaneId, PanePipe};
+///
+/// let mut pipe = PanePipe::new(PaneId(0));
+/// pipe.start().unwrap();
+/// let mut buf = [0; 1024];
+/// let n = pipe.read(&mut buf).unwrap();
+/// pipe.stop().unwrap();
+/// ```
+pub struct PanePipe<'a> {
+    pub id: PaneId,
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    /// pipe output of the pane (`[-O]`)
+    pub output: bool,
+    /// pipe input to the pane (`[-I]`)
+    #[cfg(feature = "tmux_2_7")]
+    pub input: bool,
+    /// time to wait for the pipe command to open the FIFOs
+    pub timeout: Duration,
+    dir: Option<PathBuf>,
+    reader: Option<File>,
+    writer: Option<File>,
+}
+
+impl<'a> PanePipe<'a> {
+    pub fn new(id: PaneId) -> Self {
+        Self::with_invoker(id, &|cmd| Tmux::with_command(cmd).output())
+    }
+
+    pub fn with_invoker(
+        id: PaneId,
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Self {
+        Self {
+            id,
+            invoker,
+            output: true,
+            #[cfg(feature = "tmux_2_7")]
+            input: false,
+            timeout: PANE_PIPE_TIMEOUT,
+            dir: None,
+            reader: None,
+            writer: None,
+        }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Set directions: output of the pane (`[-O]`), input to the pane (`[-I]`)
+    #[cfg(feature = "tmux_2_7")]
+    pub fn directions(mut self, output: bool, input: bool) -> Self {
+        self.output = output;
+        self.input = input;
+        self
+    }
+
+    /// Set time to wait for the pipe command to open the FIFOs
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn invoke(&self, cmd: TmuxCommand<'a>) -> Result<(), Error> {
+        (self.invoker)(cmd)?.checked()?;
+        Ok(())
+    }
+
+    /// Pipe is started
+    pub fn is_started(&self) -> bool {
+        self.dir.is_some()
+    }
+
+    /// Start piping, replaces other pipe of the pane if any
+    pub fn start(&mut self) -> Result<(), Error> {
+        self.stop()?;
+        // `pipe-pane` without a command would only stop piping
+        #[cfg(feature = "tmux_2_7")]
+        if !self.output && !self.input {
+            return Err(io::Error::from(io::ErrorKind::InvalidInput).into());
+        }
+
+        let dir = std::env::temp_dir().join(format!(
+            "tmux_interface_pipe_{}_{}_{}",
+            std::process::id(),
+            self.id.0,
+            PANE_PIPE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        DirBuilder::new().mode(0o700).create(&dir)?;
+        self.dir = Some(dir.clone());
+        match self.start_in(&dir) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                // removes the temporary directory, the pipe command may be waiting for the other
+                // FIFO
+                let _ = self.stop();
+                Err(e)
+            }
+        }
+    }
+
+    fn start_in(&mut self, dir: &Path) -> Result<(), Error> {
+        let output = dir.join(PANE_PIPE_OUTPUT);
+        #[cfg(feature = "tmux_2_7")]
+        let input = dir.join(PANE_PIPE_INPUT);
+        let mut commands = Vec::new();
+        #[cfg(feature = "tmux_2_7")]
+        if self.input {
+            mkfifo(&input)?;
+            commands.push(format!("cat < {}", quote(&input)));
+        }
+        if self.output {
+            mkfifo(&output)?;
+            commands.push(format!("cat > {}", quote(&output)));
+        }
+
+        let cmd = PipePane::new()
+            .target_pane(self.id)
+            .shell_command(commands.join(" & "));
+        #[cfg(feature = "tmux_2_7")]
+        let cmd = if self.input { cmd.stdout() } else { cmd };
+        #[cfg(feature = "tmux_2_7")]
+        let cmd = if self.output { cmd.stdin() } else { cmd };
+        self.invoke(cmd.build())?;
+
+        if self.output {
+            self.reader = Some(open_fifo(&output, false, self.timeout)?);
+        }
+        #[cfg(feature = "tmux_2_7")]
+        if self.input {
+            self.writer = Some(open_fifo(&input, true, self.timeout)?);
+        }
+        Ok(())
+    }
+
+    /// Stop piping (`pipe-pane` without command), removes temporary files
+    ///
+    /// Readers taken by [`PanePipe::take_reader`] get EOF after remaining output
+    pub fn stop(&mut self) -> Result<(), Error> {
+        let dir = match self.dir.take() {
+            Some(dir) => dir,
+            None => return Ok(()),
+        };
+        // `cat < input` exits at EOF
+        self.writer = None;
+        let result = self.invoke(PipePane::new().target_pane(self.id).build());
+        self.reader = None;
+        fs::remove_dir_all(dir)?;
+        result
+    }
+
+    /// Take output stream (e.g. for reading in another thread)
+    pub fn take_reader(&mut self) -> Option<File> {
+        self.reader.take()
+    }
+
+    /// Take input stream (e.g. for writing in another thread)
+    #[cfg(feature = "tmux_2_7")]
+    pub fn take_writer(&mut self) -> Option<File> {
+        self.writer.take()
+    }
+}
+
+impl<'a> Read for PanePipe<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.reader.as_mut() {
+            Some(reader) => reader.read(buf),
+            None => Err(io::ErrorKind::NotConnected.into()),
+        }
+    }
+}
+
+impl<'a> Write for PanePipe<'a> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.writer.as_mut() {
+            Some(writer) => writer.write(buf),
+            None => Err(io::ErrorKind::NotConnected.into()),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.writer.as_mut() {
+            Some(writer) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Drop for PanePipe<'a> {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
+
+pub(crate) fn mkfifo(path: &Path) -> Result<(), Error> {
+    let output = Command::new("mkfifo")
+        .arg("-m")
+        .arg("600")
+        .arg(path)
+        .output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(Error::IO(io::Error::other(stderr.trim_end().to_string())))
+    }
+}
+
+// open a FIFO for reading or writing, opening blocks until the other end is opened, so it is
+// opened in a helper thread, the thread is released by opening the other end after `timeout`
+pub(crate) fn open_fifo(path: &Path, write: bool, timeout: Duration) -> Result<File, Error> {
+    let open = |path: &Path, write: bool| OpenOptions::new().read(!write).write(write).open(path);
+    let (tx, rx) = mpsc::channel();
+    let fifo = path.to_path_buf();
+    thread::spawn(move || tx.send(open(&fifo, write)));
+    match rx.recv_timeout(timeout) {
+        Ok(file) => Ok(file?),
+        Err(_) => {
+            let other = open(path, !write);
+            // opened file (if any) is dropped
+            let _ = rx.recv();
+            other?;
+            Err(Error::IO(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("FIFO {} not opened by other side", path.display()),
+            )))
+        }
+    }
+}
+
+// single quoted shell word
+pub(crate) fn quote(path: &Path) -> String {
+    format!("'{}'", path.to_string_lossy().replace('\'', "'\\''"))
+}
@@ -0,0 +1,93 @@
+use crate::{
+    DisplayMessage, Error, KillSession, ListWindows, NewWindow, RenameSession, Session, SessionId,
+    SessionsCtl, Tmux, TmuxCommand, TmuxOutput, WindowHandle, WindowId,
+};
+use std::borrow::Cow;
+use std::str::FromStr;
+
+/// Session bound to an invoker, all commands are targeted by session id (`$1`), so renaming
+/// the session doesn't break the handle
+#[derive(Clone, Copy)]
+pub struct SessionHandle<'a> {
+    pub id: SessionId,
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+}
+
+impl<'a> SessionHandle<'a> {
+    pub fn new(id: SessionId) -> Self {
+        Self {
+            id,
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+        }
+    }
+
+    pub fn with_invoker(
+        id: SessionId,
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Self {
+        Self { id, invoker }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    fn invoke(&self, cmd: TmuxCommand<'a>) -> Result<String, Error> {
+        Ok((self.invoker)(cmd)?.checked()?.to_string())
+    }
+
+    /// Current session variables (`display-message -p`)
+    pub fn get(&self) -> Result<Session, Error> {
+        let cmd = DisplayMessage::new()
+            .print()
+            .target_pane(self.id.to_string())
+            .message(SessionsCtl::formats().to_string());
+        let output = self.invoke(cmd.build())?;
+        // tmux falls back to the current session, if the target doesn't exist anymore
+        let session = Session::from_str(output.trim_end_matches('\n'))?;
+        match session.id {
+            Some(id) if id == self.id => Ok(session),
+            _ => Err(Error::TargetNotFound("session", self.id.to_string())),
+        }
+    }
+
+    /// Rename the session (`rename-session`), returns refreshed session variables
+    pub fn rename<S: Into<Cow<'a, str>>>(&self, new_name: S) -> Result<Session, Error> {
+        let cmd = RenameSession::new()
+            .target_session(self.id)
+            .new_name(new_name);
+        self.invoke(cmd.build())?;
+        self.get()
+    }
+
+    /// Kill the session (`kill-session`)
+    pub fn kill(self) -> Result<(), Error> {
+        self.invoke(KillSession::new().target_session(self.id).build())?;
+        Ok(())
+    }
+
+    /// Create a new window at the next free index of the session, without selecting it
+    /// (`new-window -d`)
+    pub fn new_window(&self) -> Result<WindowHandle<'a>, Error> {
+        let cmd = NewWindow::new()
+            .detached()
+            .print()
+            .format("#{window_id}")
+            .target_window(format!("{}:", self.id));
+        let output = self.invoke(cmd.build())?;
+        let id = output.trim().parse::<WindowId>()?;
+        Ok(WindowHandle::with_invoker(id, self.invoker))
+    }
+
+    /// Windows of the session (`list-windows`)
+    pub fn windows(&self) -> Result<Vec<WindowHandle<'a>>, Error> {
+        let cmd = ListWindows::new()
+            .format("#{window_id}")
+            .target_session(self.id);
+        let output = self.invoke(cmd.build())?;
+        output
+            .lines()
+            .map(|id| Ok(WindowHandle::with_invoker(id.parse()?, self.invoker)))
+            .collect()
+    }
+}
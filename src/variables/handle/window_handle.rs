@@ -0,0 +1,106 @@
+use crate::{
+    DisplayMessage, Error, KillWindow, ListPanes, PaneHandle, PaneId, RenameWindow, SelectLayout,
+    SplitWindow, Tmux, TmuxCommand, TmuxOutput, Window, WindowId, WindowsCtl,
+};
+use std::borrow::Cow;
+use std::str::FromStr;
+
+/// Window bound to an invoker, all commands are targeted by window id (`@1`)
+#[derive(Clone, Copy)]
+pub struct WindowHandle<'a> {
+    pub id: WindowId,
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+}
+
+impl<'a> WindowHandle<'a> {
+    pub fn new(id: WindowId) -> Self {
+        Self {
+            id,
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+        }
+    }
+
+    pub fn with_invoker(
+        id: WindowId,
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Self {
+        Self { id, invoker }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    fn invoke(&self, cmd: TmuxCommand<'a>) -> Result<String, Error> {
+        Ok((self.invoker)(cmd)?.checked()?.to_string())
+    }
+
+    /// Current window variables (`display-message -p`)
+    ///
+    /// Session dependent values (`window_index`, ...) are taken from the first session the
+    /// window is linked into
+    pub fn get(&self) -> Result<Window, Error> {
+        let cmd = DisplayMessage::new()
+            .print()
+            .target_pane(self.id.to_string())
+            .message(WindowsCtl::formats().to_string());
+        let output = self.invoke(cmd.build())?;
+        // tmux falls back to the current window, if the target doesn't exist anymore
+        let window = Window::from_str(output.trim_end_matches('\n'))?;
+        match window.id {
+            Some(id) if id == self.id => Ok(window),
+            _ => Err(Error::TargetNotFound("window", self.id.to_string())),
+        }
+    }
+
+    /// Rename the window (`rename-window`), returns refreshed window variables
+    pub fn rename<S: Into<Cow<'a, str>>>(&self, new_name: S) -> Result<Window, Error> {
+        let cmd = RenameWindow::new()
+            .target_window(self.id)
+            .new_name(new_name);
+        self.invoke(cmd.build())?;
+        self.get()
+    }
+
+    /// Kill the window (`kill-window`)
+    pub fn kill(self) -> Result<(), Error> {
+        self.invoke(KillWindow::new().target_window(self.id).build())?;
+        Ok(())
+    }
+
+    /// Split the active pane of the window, without selecting the new pane (`split-window -d`)
+    pub fn split(&self) -> Result<PaneHandle<'a>, Error> {
+        let cmd = SplitWindow::new()
+            .detached()
+            .print()
+            .format("#{pane_id}")
+            .target_pane(self.id.to_string());
+        let output = self.invoke(cmd.build())?;
+        let id = output.trim().parse::<PaneId>()?;
+        Ok(PaneHandle::with_invoker(id, self.invoker))
+    }
+
+    /// Arrange panes of the window (`select-layout`, e.g. `tiled`, `even-horizontal` or a
+    /// layout string), returns refreshed window variables
+    pub fn select_layout<S: Into<Cow<'a, str>>>(&self, layout_name: S) -> Result<Window, Error> {
+        let cmd = SelectLayout::new().layout_name(layout_name);
+        #[cfg(feature = "tmux_2_7")]
+        let cmd = cmd.target_pane(self.id.to_string());
+        #[cfg(not(feature = "tmux_2_7"))]
+        let cmd = cmd.target_window(self.id);
+        self.invoke(cmd.build())?;
+        self.get()
+    }
+
+    /// Panes of the window (`list-panes`)
+    pub fn panes(&self) -> Result<Vec<PaneHandle<'a>>, Error> {
+        let cmd = ListPanes::new()
+            .format("#{pane_id}")
+            .target(self.id.to_string());
+        let output = self.invoke(cmd.build())?;
+        output
+            .lines()
+            .map(|id| Ok(PaneHandle::with_invoker(id.parse()?, self.invoker)))
+            .collect()
+    }
+}
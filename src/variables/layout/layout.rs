@@ -1,8 +1,10 @@
 use crate::Error;
 use crate::LayoutCell;
+use std::fmt;
 use std::str::FromStr;
 
 #[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Layout {
     pub checksum: usize, // layout checksum (ref: layout-custom.c -> layout_checksum())
     pub cell: LayoutCell,
@@ -15,13 +17,20 @@ impl FromStr for Layout {
 
     fn from_str(s: &str) -> Result<Layout, Error> {
         let mut layout = Layout::new();
-        let ls: Vec<&str> = s.split(',').collect();
+        // checksum is separated from the cells by the first `,`
+        let ls: Vec<&str> = s.splitn(2, ',').collect();
         layout.checksum = usize::from_str_radix(ls[0], 16)?;
-        layout.cell = ls[1].parse()?;
+        layout.cell = ls.get(1).ok_or(Error::ParseLayout)?.parse()?;
         Ok(layout)
     }
 }
 
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04x},{}", self.checksum, self.cell)
+    }
+}
+
 impl Layout {
     pub fn new() -> Self {
         Default::default()
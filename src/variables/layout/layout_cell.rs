@@ -1,8 +1,11 @@
 use crate::Error;
+use std::fmt;
 use std::str::Chars;
 use std::str::FromStr;
 
 #[derive(PartialEq, Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum LayoutType {
     LeftRight,
     TopBottom,
@@ -23,10 +26,36 @@ impl FromStr for LayoutCell {
     }
 }
 
+// `WxH,X,Y,ID`, `WxH,X,Y{cells}` or `WxH,X,Y[cells]`
+impl fmt::Display for LayoutCell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{},{},{}", self.x, self.y, self.x_off, self.y_off)?;
+        let (open, close) = match self.style {
+            LayoutType::LeftRight => ('{', '}'),
+            LayoutType::TopBottom => ('[', ']'),
+            LayoutType::WindowPane => {
+                if let Some(id) = self.id {
+                    write!(f, ",{}", id)?;
+                }
+                return Ok(());
+            }
+        };
+        let cells = self
+            .cells
+            .iter()
+            .flatten()
+            .map(|cell| cell.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        write!(f, "{}{}{}", open, cells, close)
+    }
+}
+
 // NOTE: tmux source: layout_custom.c
 // XXX: checksum can be improved using hex crate
 // XXX: implement trait parse FromStr?
 #[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LayoutCell {
     pub x: usize,
     pub y: usize,
@@ -62,3 +62,18 @@ fn parse() {
 //let l = Layouts::new();
 //parse2(&self, layouts_str).unwrap();
 //}
+
+#[test]
+fn to_string() {
+    use crate::Layout;
+
+    let layouts = [
+        "8b65,177x64,0,0,1",
+        "8b65,177x64,0,0[177x46,0,0,1,177x17,0,47,4]",
+        "bcde,178x64,0,0[177x32,0,0{88x32,0,0,1,44x32,89,0,4,43x32,134,0,5},177x31,0,33{88x31,0,33,2,88x31,89,33,3}]",
+    ];
+    for s in layouts.iter() {
+        let layout: Layout = s.parse().unwrap();
+        assert_eq!(layout.to_string(), *s);
+    }
+}
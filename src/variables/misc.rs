@@ -1,3 +1,6 @@
+#[cfg(feature = "tmux_2_4")]
+use crate::{PaneId, SessionId, WindowId};
+
 #[derive(Default, PartialEq, Clone, Debug)]
 pub struct Server {
     /// pid - Server PID
@@ -32,16 +35,16 @@ pub struct Hook {
     pub hook: Option<String>,
     ///hook_pane - ID of pane where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
-    pub pane: Option<usize>,
+    pub pane: Option<PaneId>,
     ///hook_session - ID of session where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
-    pub session: Option<usize>,
+    pub session: Option<SessionId>,
     ///hook_session_name - Name of session where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
     pub session_name: Option<String>,
     ///hook_window - ID of window where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
-    pub window: Option<usize>,
+    pub window: Option<WindowId>,
     ///hook_window_name - Name of window where hook was run, if any
     #[cfg(feature = "tmux_2_4")]
     pub window_name: Option<String>,
@@ -6,22 +6,45 @@
 //! * pane
 //! * layout
 //! * client
+//! * screen (pane contents)
+//! * expect (pane automation)
+//! * asciicast (pane recording)
+//! * process (pane processes, Linux only)
 //!
 //! # See Also
 //! * [Formats][`crate::formats`]
 //! * [Tmux Manual -> Formats](https://man7.org/linux/man-pages/man1/tmux.1.html#FORMATS)
+pub mod asciicast;
 pub mod buffer;
 pub mod client;
+pub mod expect;
+pub mod handle;
 pub mod layout;
 pub mod misc;
 pub mod pane;
+#[cfg(target_os = "linux")]
+pub mod process;
+pub mod query;
+pub mod screen;
 pub mod session;
+pub mod snapshot;
+#[cfg(test)]
+#[cfg(feature = "tmux_1_8")]
+pub(crate) mod test_session;
 pub mod window;
 
+pub use asciicast::*;
 pub use buffer::*;
 pub use client::*;
+pub use expect::*;
+pub use handle::*;
 pub use layout::*;
 pub use misc::*;
 pub use pane::*;
+#[cfg(target_os = "linux")]
+pub use process::*;
+pub use query::*;
+pub use screen::*;
 pub use session::*;
+pub use snapshot::*;
 pub use window::*;
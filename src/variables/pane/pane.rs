@@ -1,18 +1,19 @@
+use crate::formats::format_value::FormatValue;
 use crate::Error;
 use crate::FormatsOutput;
+use crate::PaneId;
 #[cfg(feature = "tmux_1_8")]
 use crate::PaneTabs;
+use crate::Variable;
 use std::str::FromStr;
 
-pub const PANE_VARS_SEPARATOR: char = '\'';
-
 //pub fn get_fmt_string(bitflags: usize) -> String {
 //let lsp_format = PANE_VARS_REGEX_VEC
 //.iter()
 //.filter(|t| bitflags & t.1 == t.1)
 //.map(|t| format!("#{{{}}}", t.0))
 //.collect::<Vec<String>>()
-//.join(PANE_VARS_SEPARATOR);
+//.join(":");
 //lsp_format
 //}
 
@@ -20,6 +21,7 @@ pub const PANE_VARS_SEPARATOR: char = '\'';
 // accordingly to tmux.h: Formats
 // XXX: check all types
 #[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pane {
     /// pane_active - 1 if active pane
     #[cfg(feature = "tmux_1_6")]
@@ -59,7 +61,7 @@ pub struct Pane {
     pub height: Option<usize>,
     /// pane_id - #D Unique pane ID
     #[cfg(feature = "tmux_1_6")]
-    pub id: Option<usize>,
+    pub id: Option<PaneId>,
     /// pane_in_mode - 1 if pane is in a mode
     #[cfg(feature = "tmux_1_8")]
     pub in_mode: Option<bool>,
@@ -129,9 +131,19 @@ impl FromStr for Pane {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Error> {
+        Self::from_values(&FormatsOutput::delimited_values(s)?)
+    }
+}
+
+impl Pane {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Parse values of one record of a [`PanesCtl::formats`](crate::PanesCtl::formats) query
+    pub fn from_values(values: &[&str]) -> Result<Self, Error> {
         let mut pane = Pane::new();
-        let mut format = FormatsOutput::new();
-        format.separator(PANE_VARS_SEPARATOR);
+        let mut format = FormatsOutput::delimited();
 
         #[cfg(feature = "tmux_1_6")]
         format.pane_active(&mut pane.active);
@@ -202,13 +214,90 @@ impl FromStr for Pane {
         #[cfg(feature = "tmux_1_6")]
         format.pane_width(&mut pane.width);
 
-        FormatsOutput::from_string_ext(s, &mut format);
+        FormatsOutput::from_values_ext(values, &mut format)?;
         Ok(pane)
     }
-}
 
-impl Pane {
-    pub fn new() -> Self {
-        Default::default()
+    /// Value of the pane `variable`, as it would be expanded by tmux
+    pub fn value(&self, variable: &Variable) -> Option<String> {
+        match variable {
+            #[cfg(feature = "tmux_1_6")]
+            Variable::PaneActive => self.active.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_6")]
+            Variable::PaneAtBottom => self.at_bottom.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_6")]
+            Variable::PaneAtLeft => self.at_left.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_6")]
+            Variable::PaneAtRight => self.at_right.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_6")]
+            Variable::PaneAtTop => self.at_top.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_0")]
+            Variable::PaneBottom => self.bottom.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_8")]
+            Variable::PaneCurrentCommand => {
+                self.current_command.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_1_7")]
+            Variable::PaneCurrentPath => self.current_path.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::PaneDead => self.dead.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_0")]
+            Variable::PaneDeadStatus => self.dead_status.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_6")]
+            Variable::PaneFormat => self.format.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::PaneHeight => self.height.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::PaneId => self.id.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_8")]
+            Variable::PaneInMode => self.in_mode.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_7")]
+            Variable::PaneIndex => self.index.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_0")]
+            Variable::PaneInputOff => self.input_off.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_0")]
+            Variable::PaneLeft => self.left.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_0")]
+            Variable::PaneMarked => self.marked.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_0")]
+            Variable::PaneMarkedSet => self.marked_set.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_5")]
+            Variable::PaneMode => self.mode.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::PanePath => self.path.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::PanePid => self.pid.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_6")]
+            Variable::PanePipe => self.pipe.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_0")]
+            Variable::PaneRight => self.right.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_5")]
+            Variable::PaneSearchString => {
+                self.search_string.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_1_6")]
+            Variable::PaneStartCommand => {
+                self.start_command.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_0")))]
+            Variable::PaneStartPath => self.start_path.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_9")]
+            Variable::PaneSynchronized => self.synchronized.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_8")]
+            Variable::PaneTabs => self.tabs.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::PaneTitle => self.title.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_0")]
+            Variable::PaneTop => self.top.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::PaneTty => self.tty.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_4")]
+            Variable::PaneUnseenChanges => {
+                self.unseen_changes.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_1_6")]
+            Variable::PaneWidth => self.width.as_ref().map(FormatValue::format_value),
+            _ => None,
+        }
     }
 }
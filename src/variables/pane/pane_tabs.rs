@@ -1,7 +1,9 @@
 use crate::Error;
+use std::fmt;
 use std::str::FromStr;
 
 #[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PaneTabs(pub Vec<usize>);
 
 impl FromStr for PaneTabs {
@@ -16,3 +18,15 @@ impl FromStr for PaneTabs {
         Ok(Self(tabs))
     }
 }
+
+impl fmt::Display for PaneTabs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = self
+            .0
+            .iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<String>>()
+            .join(&','.to_string());
+        write!(f, "{}", output)
+    }
+}
@@ -19,6 +19,7 @@ fn bool() {
 
 #[test]
 fn parse() {
+    use crate::FormatsOutput;
     use crate::Pane;
     use std::str::FromStr;
 
@@ -129,7 +130,7 @@ fn parse() {
         "177",
     ];
     //let pane_str = "1'1'1'1'1'63'bash'/home/user'0''1'64'%0'0'0'0'0'0'0''1945'0'176'''0''asus'0'/dev/pts/2'177";
-    let pane_str = pane_vec.join("'");
+    let pane_str = FormatsOutput::delimited_record(&pane_vec);
     let pane = Pane::from_str(&pane_str).unwrap();
     #[cfg(feature = "tmux_1_7")]
     assert_eq!(pane.current_path, Some("/home/user".to_string()));
@@ -156,7 +157,7 @@ fn bitflag_operations() {
 
 #[test]
 fn parse2() {
-    use crate::Pane;
+    use crate::{FormatsOutput, Pane, PaneId};
     use std::str::FromStr;
 
     let origin = Pane {
@@ -185,7 +186,7 @@ fn parse2() {
         #[cfg(feature = "tmux_1_6")]
         height: Some(64),
         #[cfg(feature = "tmux_1_6")]
-        id: Some(0),
+        id: Some(PaneId(0)),
         #[cfg(feature = "tmux_1_8")]
         in_mode: Some(false),
         #[cfg(feature = "tmux_1_7")]
@@ -336,7 +337,7 @@ fn parse2() {
     ];
     //let pane_str = "1'1'1'1'1'63'bash'/home/user'0''1'64'%0'0'0'0'0'0'0''1945'0'176'''0''asus'0'/dev/pts/2'177";
     //let pane_str = "1'1'1'1'1'63'bash'/home/user'0''1'64'%0'0'0'0'0'0'0''1945'0'176'''0'8,16,24,32,40,48,56,64,72,80,88,96,104,112,120,128,136,144,152,160,168,176'asus'0'/dev/pts/2'177";
-    let pane_str = pane_vec.join("'");
+    let pane_str = FormatsOutput::delimited_record(&pane_vec);
     let pane = Pane::from_str(&pane_str).unwrap();
     //assert_eq!(pane.current_path, Some("/home/user".to_string()));
     //assert_eq!(pane.tty, Some("/dev/pts/2".to_string()));
@@ -1,4 +1,4 @@
-use crate::{Error, Pane};
+use crate::{Error, FormatsOutput, Pane};
 //use std::borrow::Cow;
 use std::ops::Index;
 use std::str::FromStr;
@@ -28,8 +28,8 @@ impl FromStr for Panes {
 
     fn from_str(s: &str) -> Result<Self, Error> {
         let mut panes = Panes::new();
-        for line in s.lines() {
-            panes.push(Pane::from_str(line)?);
+        for record in FormatsOutput::delimited_records(s)? {
+            panes.push(Pane::from_str(record)?);
         }
         Ok(panes)
     }
@@ -1,5 +1,6 @@
-use crate::variables::pane::pane::PANE_VARS_SEPARATOR;
-use crate::{Error, Formats, ListPanes, Panes, Tmux, TmuxCommand, TmuxOutput};
+use crate::{
+    Error, Formats, FormatsOutput, ListPanes, Pane, Panes, Tmux, TmuxCommand, TmuxOutput, Variable,
+};
 use std::borrow::Cow;
 use std::str::FromStr;
 
@@ -49,8 +50,55 @@ impl<'a> PanesCtl<'a> {
     where
         S: Into<Cow<'a, str>>,
     {
-        let mut format = Formats::new();
-        format.separator(PANE_VARS_SEPARATOR);
+        let lsp_format = Self::formats().to_string();
+
+        // all or belonging to target
+        let cmd = ListPanes::new().format(lsp_format);
+        let cmd = match target {
+            Some(target) => cmd.target(target),
+            None => cmd.all(),
+        };
+        let cmd = cmd.build();
+
+        let output = (invoker)(cmd)?.to_string();
+        Panes::from_str(&output)
+    }
+
+    /// Panes with values of additional variables (e.g. [`Variable::Custom`]), in their order
+    pub fn get_with<S: Into<Cow<'a, str>>>(
+        &self,
+        target: Option<S>,
+        variables: &[Variable],
+    ) -> Result<Vec<(Pane, Vec<String>)>, Error> {
+        Self::get_all_with_ext(target, variables, self.invoker())
+    }
+
+    pub fn get_all_with(&self, variables: &[Variable]) -> Result<Vec<(Pane, Vec<String>)>, Error> {
+        Self::get_all_with_ext(None::<&str>, variables, self.invoker())
+    }
+
+    pub fn get_all_with_ext<S: Into<Cow<'a, str>>>(
+        target: Option<S>,
+        variables: &[Variable],
+        invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<Vec<(Pane, Vec<String>)>, Error> {
+        let mut format = Self::formats();
+        let len = format.variables.len();
+        format.variables.extend_from_slice(variables);
+
+        let cmd = ListPanes::new().format(format.to_string());
+        let cmd = match target {
+            Some(target) => cmd.target(target),
+            None => cmd.all(),
+        };
+
+        let output = (invoker)(cmd.build())?.to_string();
+        FormatsOutput::parse_records_with(&output, len, variables.len(), Pane::from_values)
+    }
+
+    /// Formats of all pane variables, parsed by [`Pane::from_values`]
+    pub fn formats() -> Formats {
+        let mut format = Formats::delimited();
 
         #[cfg(feature = "tmux_1_6")]
         format.pane_active();
@@ -121,17 +169,6 @@ impl<'a> PanesCtl<'a> {
         #[cfg(feature = "tmux_1_6")]
         format.pane_width();
 
-        let lsp_format = format.to_string();
-
-        // all or belonging to target
-        let cmd = ListPanes::new().format(lsp_format);
-        let cmd = match target {
-            Some(target) => cmd.target(target),
-            None => cmd.all(),
-        };
-        let cmd = cmd.build();
-
-        let output = (invoker)(cmd)?.to_string();
-        Panes::from_str(&output)
+        format
     }
 }
@@ -5,3 +5,29 @@ fn get() {
     let panes = PanesCtl::default().get_all().unwrap();
     dbg!(panes);
 }
+
+#[test]
+fn get_all_with() {
+    use crate::variables::test_session::TestSession;
+    use crate::{PanesCtl, Variable};
+
+    const TEST_SESSION_NAME: &str = "panes_ctl_get_all_with";
+
+    let session = TestSession::new(TEST_SESSION_NAME);
+    let pane_id = session.pane_id;
+
+    let variables = [
+        Variable::Custom("#{pane_width}x#{pane_height}".to_string()),
+        Variable::Custom("@panes_ctl_unset".to_string()),
+    ];
+    let panes = PanesCtl::default().get_all_with(&variables).unwrap();
+    let (pane, values) = panes
+        .iter()
+        .find(|(pane, _)| pane.id == Some(pane_id))
+        .unwrap();
+    assert_eq!(
+        values[0],
+        format!("{}x{}", pane.width.unwrap(), pane.height.unwrap())
+    );
+    assert_eq!(values[1], "");
+}
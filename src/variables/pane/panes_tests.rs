@@ -1,6 +1,6 @@
 #[test]
 fn parse() {
-    use crate::Panes;
+    use crate::{FormatsOutput, PaneId, Panes};
     use std::str::FromStr;
 
     //"1'1'1'1'1'63'bash'/home/user'0''1'64'%0'0'0'0'0'0'0''1945'0'176'''0'8,16,24,\
@@ -217,9 +217,9 @@ fn parse() {
         #[cfg(feature = "tmux_1_6")]
         "177",
     ];
-    let pane0_str = pane0_vec.join("'");
-    let pane1_str = pane1_vec.join("'");
+    let pane0_str = FormatsOutput::delimited_record(&pane0_vec);
+    let pane1_str = FormatsOutput::delimited_record(&pane1_vec);
     let panes_str = format!("{}\n{}", pane0_str, pane1_str);
     let panes = Panes::from_str(&panes_str).unwrap();
-    assert_eq!(panes[0].id, Some(0));
+    assert_eq!(panes[0].id, Some(PaneId(0)));
 }
@@ -0,0 +1,15 @@
+pub mod pane_processes;
+pub mod process_info;
+pub mod process_tree;
+
+pub use pane_processes::PaneProcesses;
+pub use process_info::ProcessInfo;
+pub use process_tree::ProcessTree;
+
+#[cfg(test)]
+#[path = "."]
+mod variables_process_tests {
+    mod pane_processes_tests;
+    mod process_info_tests;
+    mod process_tree_tests;
+}
@@ -0,0 +1,117 @@
+#[cfg(feature = "tmux_1_6")]
+use crate::Pane;
+#[cfg(feature = "tmux_1_8")]
+use crate::{DisplayMessage, PaneHandle, PaneId};
+use crate::{Error, ProcessInfo, ProcessTree};
+use std::path::Path;
+
+// `comm` of shells, the first process of most panes
+const PANE_SHELLS: &[&str] = &[
+    "ash", "bash", "csh", "dash", "elvish", "fish", "ksh", "mksh", "nu", "sh", "tcsh", "xonsh",
+    "zsh",
+];
+
+/// Processes running in a pane (Linux only)
+///
+/// The first process of the pane (`pane_pid`, usually a shell) with all its descendants, the
+/// foreground process group of the pane terminal decides what is actually running
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::{PaneHandle, PaneId};
+///
+/// let processes = PaneHandle::new(PaneId(0)).processes().unwrap();
+/// if !processes.is_idle() {
+///     let foreground = processes.foreground().unwrap();
+///     println!("running: {}", foreground.command());
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct PaneProcesses {
+    pub tree: ProcessTree,
+}
+
+impl PaneProcesses {
+    /// Read processes of the pane with the first process `pid` (`pane_pid`)
+    pub fn read(pid: usize) -> Result<Self, Error> {
+        Ok(Self::new(ProcessTree::read(pid)?))
+    }
+
+    pub fn new(tree: ProcessTree) -> Self {
+        Self { tree }
+    }
+
+    /// First process of the pane (`pane_pid`)
+    pub fn root(&self) -> &ProcessInfo {
+        &self.tree.process
+    }
+
+    /// Processes of the foreground process group of the pane terminal
+    pub fn foreground_group(&self) -> Vec<&ProcessInfo> {
+        match self.root().tpgid {
+            Some(tpgid) => self.tree.iter().filter(|p| p.pgrp == tpgid).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Foreground process (e.g. `vim` started from the shell), leader of the foreground process
+    /// group if it is in the pane
+    pub fn foreground(&self) -> Option<&ProcessInfo> {
+        let group = self.foreground_group();
+        group
+            .iter()
+            .find(|p| p.tpgid == Some(p.pid))
+            .or_else(|| group.first())
+            .copied()
+    }
+
+    /// Working directory of the foreground process, or the first process
+    pub fn current_path(&self) -> Option<&Path> {
+        self.foreground()
+            .and_then(|p| p.cwd.as_deref())
+            .or_else(|| self.root().cwd.as_deref())
+    }
+
+    /// The first process of the pane is a shell waiting at its prompt, in the foreground and
+    /// without any child processes (commands, background jobs)
+    ///
+    /// Other programs waiting for input (e.g. an editor) aren't idle, as their state can be lost
+    pub fn is_idle(&self) -> bool {
+        let root = self.root();
+        self.tree.children.is_empty()
+            && root.is_foreground()
+            && matches!(root.state, 'S' | 'I')
+            && PANE_SHELLS.contains(&root.comm.as_str())
+    }
+}
+
+#[cfg(feature = "tmux_1_6")]
+impl Pane {
+    /// Processes running in the pane (`pane_pid`, Linux only)
+    pub fn processes(&self) -> Result<PaneProcesses, Error> {
+        match self.pid {
+            Some(pid) => PaneProcesses::read(pid),
+            None => Err(Error::TargetNotFound("process", "pane_pid".to_string())),
+        }
+    }
+}
+
+#[cfg(feature = "tmux_1_8")]
+impl<'a> PaneHandle<'a> {
+    /// Processes running in the pane (`display-message -p '#{pane_pid}'`, Linux only)
+    pub fn processes(&self) -> Result<PaneProcesses, Error> {
+        let cmd = DisplayMessage::new()
+            .print()
+            .target_pane(self.id)
+            .message("#{pane_id} #{pane_pid}");
+        let output = (self.invoker)(cmd.build())?.checked()?.to_string();
+        // tmux falls back to the current pane, if the target doesn't exist anymore
+        match output.trim().split_once(' ') {
+            Some((id, pid)) if id.parse::<PaneId>().ok() == Some(self.id) => {
+                PaneProcesses::read(pid.parse()?)
+            }
+            _ => Err(Error::TargetNotFound("pane", self.id.to_string())),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+#[test]
+fn pane_processes_foreground() {
+    use crate::{PaneProcesses, ProcessInfo, ProcessTree};
+
+    let process = |pid, ppid, pgrp, state| ProcessInfo {
+        pid,
+        ppid,
+        pgrp,
+        tpgid: Some(20),
+        state,
+        ..Default::default()
+    };
+    // shell (10) running a pipeline `20 | 21`
+    let processes = vec![process(20, 10, 20, 'S'), process(21, 10, 20, 'R')];
+    let processes = PaneProcesses::new(ProcessTree::from_processes(
+        process(10, 1, 10, 'S'),
+        processes,
+    ));
+    assert_eq!(processes.foreground().map(|p| p.pid), Some(20));
+    assert_eq!(processes.foreground_group().len(), 2);
+    assert!(!processes.is_idle());
+
+    // shell waiting at the prompt
+    let mut shell = process(10, 1, 10, 'S');
+    shell.tpgid = Some(10);
+    shell.comm = "bash".to_string();
+    let processes = PaneProcesses::new(ProcessTree::from_processes(shell.clone(), Vec::new()));
+    assert_eq!(processes.foreground().map(|p| p.pid), Some(10));
+    assert!(processes.is_idle());
+
+    // background job of the shell
+    let mut job = process(30, 10, 30, 'S');
+    job.tpgid = Some(10);
+    let processes = PaneProcesses::new(ProcessTree::from_processes(shell.clone(), vec![job]));
+    assert!(!processes.is_idle());
+
+    // other program than a shell waiting for input
+    shell.comm = "vim".to_string();
+    let processes = PaneProcesses::new(ProcessTree::from_processes(shell, Vec::new()));
+    assert!(!processes.is_idle());
+}
+
+#[cfg(feature = "tmux_2_4")]
+#[test]
+fn pane_processes() {
+    use crate::variables::test_session::TestSession;
+    use crate::Error;
+    use std::thread;
+    use std::time::Duration;
+
+    let session = TestSession::with_command("pane_processes_test", "sh");
+    let pane = session.pane();
+
+    let foreground = || {
+        pane.processes()
+            .unwrap()
+            .foreground()
+            .map(|p| p.comm.clone())
+    };
+    for _ in 0..50 {
+        if pane.processes().unwrap().is_idle() {
+            break;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+    let processes = pane.processes().unwrap();
+    assert!(processes.is_idle());
+    assert_eq!(processes.root().comm, "sh");
+    assert_eq!(foreground().as_deref(), Some("sh"));
+
+    pane.send_keys(["cd /tmp && sleep 30", "Enter"]).unwrap();
+    for _ in 0..50 {
+        if foreground().as_deref() == Some("sleep") {
+            break;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+    let processes = pane.processes().unwrap();
+    assert!(!processes.is_idle());
+    let sleep = processes.foreground().unwrap();
+    assert_eq!(sleep.argv, vec!["sleep", "30"]);
+    assert_eq!(sleep.ppid, processes.root().pid);
+    assert_eq!(
+        processes.current_path().and_then(|p| p.to_str()),
+        Some("/tmp")
+    );
+
+    pane.kill().unwrap();
+    assert!(matches!(
+        pane.processes(),
+        Err(Error::TargetNotFound(..)) | Err(Error::Tmux(..))
+    ));
+}
@@ -0,0 +1,102 @@
+use crate::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// Linux `/proc/[pid]/stat`, `comm` can contain spaces and parentheses
+//
+// ```text
+// 1234 (bash) S 1200 1234 1234 34817 5678 4194304 ...
+// pid (comm) state ppid pgrp session tty_nr tpgid ...
+// ```
+//
+// ref: https://man7.org/linux/man-pages/man5/proc.5.html
+
+/// Process information read from `/proc` (Linux only)
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ProcessInfo {
+    pub pid: usize,
+    /// parent process id
+    pub ppid: usize,
+    /// process group id
+    pub pgrp: usize,
+    /// session id
+    pub session: usize,
+    /// controlling terminal (device number), `0` if none
+    pub tty_nr: usize,
+    /// foreground process group of the controlling terminal
+    pub tpgid: Option<usize>,
+    /// `R` running, `S` sleeping, `D` disk sleep, `T` stopped, `Z` zombie, ...
+    pub state: char,
+    /// executable name (truncated to 15 characters by the kernel)
+    pub comm: String,
+    /// command line arguments, empty for kernel threads and zombies
+    pub argv: Vec<String>,
+    /// working directory, `None` if not permitted
+    pub cwd: Option<PathBuf>,
+}
+
+impl ProcessInfo {
+    /// Read `/proc/[pid]/stat`, `/proc/[pid]/cmdline` and `/proc/[pid]/cwd`
+    pub fn read(pid: usize) -> Result<Self, Error> {
+        let dir = PathBuf::from(format!("/proc/{}", pid));
+        let mut process = fs::read_to_string(dir.join("stat"))?.parse::<ProcessInfo>()?;
+        process.argv = fs::read(dir.join("cmdline"))?
+            .split(|b| *b == 0)
+            .filter(|arg| !arg.is_empty())
+            .map(|arg| String::from_utf8_lossy(arg).into_owned())
+            .collect();
+        process.cwd = fs::read_link(dir.join("cwd")).ok();
+        Ok(process)
+    }
+
+    /// Command line with arguments quoted for a shell (e.g. to restore the command), `comm` if
+    /// arguments are unknown
+    pub fn command(&self) -> String {
+        if self.argv.is_empty() {
+            return self.comm.clone();
+        }
+        let args: Vec<String> = self.argv.iter().map(|arg| shell_quote(arg)).collect();
+        args.join(" ")
+    }
+
+    /// Process is in the foreground process group of its terminal
+    pub fn is_foreground(&self) -> bool {
+        self.tpgid == Some(self.pgrp)
+    }
+}
+
+// only `/proc/[pid]/stat` fields, `argv` and `cwd` are empty
+impl FromStr for ProcessInfo {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pid, rest) = s.split_once(" (").ok_or(Error::ParseProcessInfo)?;
+        let (comm, rest) = rest.rsplit_once(") ").ok_or(Error::ParseProcessInfo)?;
+        let fields: Vec<&str> = rest.split_whitespace().collect();
+        if fields.len() < 6 {
+            return Err(Error::ParseProcessInfo);
+        }
+        Ok(ProcessInfo {
+            pid: pid.trim().parse()?,
+            comm: comm.to_string(),
+            state: fields[0].chars().next().ok_or(Error::ParseProcessInfo)?,
+            ppid: fields[1].parse()?,
+            pgrp: fields[2].parse()?,
+            session: fields[3].parse()?,
+            tty_nr: fields[4].parse()?,
+            // -1 if there is no controlling terminal
+            tpgid: fields[5].parse().ok(),
+            ..Default::default()
+        })
+    }
+}
+
+fn shell_quote(arg: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "%+,-./:=@_".contains(c);
+    if !arg.is_empty() && arg.chars().all(safe) {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
@@ -0,0 +1,60 @@
+#[test]
+fn process_info_parse() {
+    use crate::ProcessInfo;
+
+    let stat = "1234 (bash) S 1200 1234 1234 34817 5678 4194304 1068 0 0 0 1 0 0 0 20 0 1 0";
+    let process = stat.parse::<ProcessInfo>().unwrap();
+    assert_eq!(
+        process,
+        ProcessInfo {
+            pid: 1234,
+            ppid: 1200,
+            pgrp: 1234,
+            session: 1234,
+            tty_nr: 34817,
+            tpgid: Some(5678),
+            state: 'S',
+            comm: "bash".to_string(),
+            ..Default::default()
+        }
+    );
+    assert!(!process.is_foreground());
+
+    // spaces and parentheses in comm, no controlling terminal
+    let stat = "42 (tmux: server (1)) R 1 42 42 0 -1 4194368 0";
+    let process = stat.parse::<ProcessInfo>().unwrap();
+    assert_eq!(process.comm, "tmux: server (1)");
+    assert_eq!(process.state, 'R');
+    assert_eq!(process.tty_nr, 0);
+    assert_eq!(process.tpgid, None);
+
+    assert!("42 (bash) S 1 42".parse::<ProcessInfo>().is_err());
+    assert!("bash S 1 42 42 0 -1".parse::<ProcessInfo>().is_err());
+}
+
+#[test]
+fn process_info_command() {
+    use crate::ProcessInfo;
+
+    let mut process = ProcessInfo {
+        comm: "vim".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(process.command(), "vim");
+    process.argv = vec!["vim".into(), "-O".into(), "a b.txt".into(), "it's".into()];
+    assert_eq!(process.command(), r#"vim -O 'a b.txt' 'it'\''s'"#);
+}
+
+#[test]
+fn process_info_read() {
+    use crate::ProcessInfo;
+    use std::env;
+    use std::process;
+
+    let process = ProcessInfo::read(process::id() as usize).unwrap();
+    assert_eq!(process.pid, process::id() as usize);
+    assert_eq!(process.argv, env::args().collect::<Vec<String>>());
+    assert_eq!(process.cwd, Some(env::current_dir().unwrap()));
+
+    assert!(ProcessInfo::read(usize::MAX).is_err());
+}
@@ -0,0 +1,63 @@
+use crate::{Error, ProcessInfo};
+use std::fs;
+
+/// Process with all its descendants (Linux only)
+#[derive(Clone, PartialEq, Debug)]
+pub struct ProcessTree {
+    pub process: ProcessInfo,
+    /// child processes ordered by pid
+    pub children: Vec<ProcessTree>,
+}
+
+impl ProcessTree {
+    /// Read the process and its descendants, scanning all processes in `/proc`
+    pub fn read(pid: usize) -> Result<Self, Error> {
+        let root = ProcessInfo::read(pid)?;
+        let mut processes = Vec::new();
+        for entry in fs::read_dir("/proc")? {
+            let entry = entry?;
+            let pid = match entry.file_name().to_str().map(str::parse::<usize>) {
+                Some(Ok(pid)) if pid != root.pid => pid,
+                _ => continue,
+            };
+            // processes can exit during the scan
+            if let Ok(process) = ProcessInfo::read(pid) {
+                processes.push(process);
+            }
+        }
+        Ok(Self::from_processes(root, processes))
+    }
+
+    /// Build the tree of the `root` from the list of processes, unrelated processes are ignored
+    pub fn from_processes(root: ProcessInfo, mut processes: Vec<ProcessInfo>) -> Self {
+        processes.sort_by_key(|process| process.pid);
+        Self::build(root, &processes)
+    }
+
+    fn build(process: ProcessInfo, processes: &[ProcessInfo]) -> Self {
+        let children = processes
+            .iter()
+            .filter(|child| child.ppid == process.pid && child.pid != process.pid)
+            .map(|child| Self::build(child.clone(), processes))
+            .collect();
+        ProcessTree { process, children }
+    }
+
+    /// All processes of the tree, parents before their children
+    pub fn iter(&self) -> impl Iterator<Item = &ProcessInfo> {
+        let mut processes = Vec::new();
+        self.collect(&mut processes);
+        processes.into_iter()
+    }
+
+    fn collect<'a>(&'a self, processes: &mut Vec<&'a ProcessInfo>) {
+        processes.push(&self.process);
+        for child in &self.children {
+            child.collect(processes);
+        }
+    }
+
+    pub fn find(&self, pid: usize) -> Option<&ProcessInfo> {
+        self.iter().find(|process| process.pid == pid)
+    }
+}
@@ -0,0 +1,49 @@
+#[test]
+fn process_tree() {
+    use crate::{ProcessInfo, ProcessTree};
+
+    let process = |pid, ppid| ProcessInfo {
+        pid,
+        ppid,
+        ..Default::default()
+    };
+    let processes = vec![
+        process(1, 0),
+        process(30, 10),
+        process(20, 10),
+        process(21, 20),
+        process(40, 1),
+    ];
+    let tree = ProcessTree::from_processes(process(10, 1), processes);
+    let pids: Vec<usize> = tree.iter().map(|p| p.pid).collect();
+    assert_eq!(pids, vec![10, 20, 21, 30]);
+    assert_eq!(tree.children.len(), 2);
+    assert_eq!(tree.find(21).map(|p| p.ppid), Some(20));
+    assert_eq!(tree.find(40), None);
+}
+
+#[test]
+fn process_tree_read() {
+    use crate::ProcessTree;
+    use std::process::{self, Command};
+    use std::thread;
+    use std::time::Duration;
+
+    let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+    // arguments are of the parent (this test) until the child calls `exec`
+    let mut found = None;
+    for _ in 0..50 {
+        let tree = ProcessTree::read(process::id() as usize).unwrap();
+        found = tree.find(child.id() as usize).cloned();
+        if found.as_ref().map_or(false, |p| p.argv == ["sleep", "10"]) {
+            break;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    let found = found.unwrap();
+    assert_eq!(found.ppid, process::id() as usize);
+    assert_eq!(found.argv, vec!["sleep", "10"]);
+}
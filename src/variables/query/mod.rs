@@ -0,0 +1,10 @@
+pub mod query_ctl;
+
+#[cfg(feature = "tmux_1_6")]
+pub use query_ctl::QueryCtl;
+
+#[cfg(test)]
+#[path = "."]
+mod variables_query_tests {
+    mod query_ctl_tests;
+}
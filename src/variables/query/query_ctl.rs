@@ -0,0 +1,142 @@
+#[cfg(feature = "tmux_1_7")]
+use crate::ListBuffers;
+use crate::{
+    DisplayMessage, Error, FormatQuery, FormatsOutput, IntoTargetPane, IntoTargetSession,
+    ListClients, ListPanes, ListSessions, ListWindows, Tmux, TmuxCommand, TmuxOutput,
+};
+use std::borrow::Cow;
+
+/// Fetch user defined [`FormatQuery`] structs (e.g. `#[derive(TmuxFormat)]`), one per session,
+/// window, pane, client or buffer
+pub struct QueryCtl<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+}
+
+impl<'a> Default for QueryCtl<'a> {
+    fn default() -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+        }
+    }
+}
+
+impl<'a> QueryCtl<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self { invoker }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// One struct for the target pane (`display-message -p`)
+    pub fn get<T: FormatQuery, S: IntoTargetPane<'a>>(
+        &self,
+        target_pane: Option<S>,
+    ) -> Result<T, Error> {
+        Self::get_ext(target_pane, self.invoker())
+    }
+
+    pub fn get_ext<T: FormatQuery, S: IntoTargetPane<'a>>(
+        target_pane: Option<S>,
+        invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<T, Error> {
+        let cmd = DisplayMessage::new().print().message(T::format());
+        let cmd = match target_pane {
+            Some(target_pane) => cmd.target_pane(target_pane),
+            None => cmd,
+        };
+
+        let output = (invoker)(cmd.build())?.to_string();
+        match FormatsOutput::delimited_records(&output)?.as_slice() {
+            [record] => T::from_record(record),
+            _ => Err(Error::ParseFormatsOutput),
+        }
+    }
+
+    pub fn get_sessions<T: FormatQuery>(&self) -> Result<Vec<T>, Error> {
+        Self::get_sessions_ext(self.invoker())
+    }
+
+    pub fn get_sessions_ext<T: FormatQuery>(
+        invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<Vec<T>, Error> {
+        let cmd = ListSessions::new().format(T::format()).build();
+        let output = (invoker)(cmd)?.to_string();
+        T::from_output(&output)
+    }
+
+    /// Windows of the target session or all windows
+    pub fn get_windows<T: FormatQuery, S: IntoTargetSession<'a>>(
+        &self,
+        target_session: Option<S>,
+    ) -> Result<Vec<T>, Error> {
+        Self::get_windows_ext(target_session, self.invoker())
+    }
+
+    pub fn get_windows_ext<T: FormatQuery, S: IntoTargetSession<'a>>(
+        target_session: Option<S>,
+        invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<Vec<T>, Error> {
+        let cmd = ListWindows::new().format(T::format());
+        let cmd = match target_session {
+            Some(target_session) => cmd.target_session(target_session),
+            None => cmd.all(),
+        };
+
+        let output = (invoker)(cmd.build())?.to_string();
+        T::from_output(&output)
+    }
+
+    /// Panes of the target window or all panes
+    pub fn get_panes<T: FormatQuery, S: Into<Cow<'a, str>>>(
+        &self,
+        target_window: Option<S>,
+    ) -> Result<Vec<T>, Error> {
+        Self::get_panes_ext(target_window, self.invoker())
+    }
+
+    pub fn get_panes_ext<T: FormatQuery, S: Into<Cow<'a, str>>>(
+        target_window: Option<S>,
+        invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<Vec<T>, Error> {
+        let cmd = ListPanes::new().format(T::format());
+        let cmd = match target_window {
+            Some(target_window) => cmd.target(target_window),
+            None => cmd.all(),
+        };
+
+        let output = (invoker)(cmd.build())?.to_string();
+        T::from_output(&output)
+    }
+
+    pub fn get_clients<T: FormatQuery>(&self) -> Result<Vec<T>, Error> {
+        Self::get_clients_ext(self.invoker())
+    }
+
+    pub fn get_clients_ext<T: FormatQuery>(
+        invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<Vec<T>, Error> {
+        let cmd = ListClients::new().format(T::format()).build();
+        let output = (invoker)(cmd)?.to_string();
+        T::from_output(&output)
+    }
+
+    #[cfg(feature = "tmux_1_7")]
+    pub fn get_buffers<T: FormatQuery>(&self) -> Result<Vec<T>, Error> {
+        Self::get_buffers_ext(self.invoker())
+    }
+
+    #[cfg(feature = "tmux_1_7")]
+    pub fn get_buffers_ext<T: FormatQuery>(
+        invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<Vec<T>, Error> {
+        let cmd = ListBuffers::new().format(T::format()).build();
+        let output = (invoker)(cmd)?.to_string();
+        T::from_output(&output)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{Error, FormatQuery, FromFormatValue, PaneId, WindowId};
+
+#[derive(Debug, PartialEq)]
+struct MyPane {
+    id: PaneId,
+    window_id: WindowId,
+}
+
+impl FormatQuery for MyPane {
+    fn formats() -> Vec<String> {
+        vec!["#{pane_id}".to_string(), "#{window_id}".to_string()]
+    }
+
+    fn from_values(values: &[&str]) -> Result<Self, Error> {
+        if values.len() != 2 {
+            return Err(Error::ParseFormatsOutput);
+        }
+        Ok(MyPane {
+            id: PaneId::from_format_value(values[0])?,
+            window_id: WindowId::from_format_value(values[1])?,
+        })
+    }
+}
+
+#[test]
+fn get_panes() {
+    use crate::variables::test_session::TestSession;
+    use crate::QueryCtl;
+
+    const TEST_SESSION_NAME: &str = "query_ctl_get_panes";
+
+    let session = TestSession::new(TEST_SESSION_NAME);
+    let pane = MyPane {
+        id: session.pane_id,
+        window_id: session.window_id,
+    };
+
+    let panes = QueryCtl::default().get_panes::<MyPane, &str>(None);
+    let window_panes =
+        QueryCtl::default().get_panes::<MyPane, _>(Some(session.window_id.to_string()));
+    assert!(panes.unwrap().contains(&pane));
+    assert_eq!(window_panes.unwrap(), vec![pane]);
+}
@@ -0,0 +1,22 @@
+#[cfg(feature = "tmux_1_8")]
+pub mod screen_cell;
+#[cfg(feature = "tmux_1_8")]
+pub mod screen_ctl;
+#[cfg(feature = "tmux_1_8")]
+pub mod screen_grid;
+
+#[cfg(feature = "tmux_1_8")]
+pub use screen_cell::{CellAttributes, ScreenCell};
+#[cfg(feature = "tmux_1_8")]
+pub use screen_ctl::ScreenCtl;
+#[cfg(feature = "tmux_1_8")]
+pub use screen_grid::Screen;
+
+#[cfg(test)]
+#[path = "."]
+mod variables_screen_tests {
+    #[cfg(feature = "tmux_2_4")]
+    mod screen_ctl_tests;
+    #[cfg(feature = "tmux_1_8")]
+    mod screen_grid_tests;
+}
@@ -0,0 +1,131 @@
+use crate::Colour;
+use std::fmt;
+
+/// Attributes of a [`ScreenCell`] (SGR parameters of `capture-pane -e`)
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+pub struct CellAttributes(pub usize);
+
+impl CellAttributes {
+    pub const NONE: CellAttributes = CellAttributes(0);
+    /// SGR 1
+    pub const BOLD: CellAttributes = CellAttributes(0b0000_0000_0000_0001);
+    /// SGR 2
+    pub const DIM: CellAttributes = CellAttributes(0b0000_0000_0000_0010);
+    /// SGR 3
+    pub const ITALICS: CellAttributes = CellAttributes(0b0000_0000_0000_0100);
+    /// SGR 4
+    pub const UNDERSCORE: CellAttributes = CellAttributes(0b0000_0000_0000_1000);
+    /// SGR 5
+    pub const BLINK: CellAttributes = CellAttributes(0b0000_0000_0001_0000);
+    /// SGR 7
+    pub const REVERSE: CellAttributes = CellAttributes(0b0000_0000_0010_0000);
+    /// SGR 8
+    pub const HIDDEN: CellAttributes = CellAttributes(0b0000_0000_0100_0000);
+    /// SGR 9
+    pub const STRIKETHROUGH: CellAttributes = CellAttributes(0b0000_0000_1000_0000);
+    /// SGR 4:2 (or 21)
+    pub const DOUBLE_UNDERSCORE: CellAttributes = CellAttributes(0b0000_0001_0000_0000);
+    /// SGR 4:3
+    pub const CURLY_UNDERSCORE: CellAttributes = CellAttributes(0b0000_0010_0000_0000);
+    /// SGR 4:4
+    pub const DOTTED_UNDERSCORE: CellAttributes = CellAttributes(0b0000_0100_0000_0000);
+    /// SGR 4:5
+    pub const DASHED_UNDERSCORE: CellAttributes = CellAttributes(0b0000_1000_0000_0000);
+    /// SGR 53
+    pub const OVERLINE: CellAttributes = CellAttributes(0b0001_0000_0000_0000);
+    /// terminal alternate character set (SO, SI)
+    pub const ACS: CellAttributes = CellAttributes(0b0010_0000_0000_0000);
+
+    /// All kinds of underscore
+    pub const UNDERSCORES: CellAttributes = CellAttributes(
+        Self::UNDERSCORE.0
+            | Self::DOUBLE_UNDERSCORE.0
+            | Self::CURLY_UNDERSCORE.0
+            | Self::DOTTED_UNDERSCORE.0
+            | Self::DASHED_UNDERSCORE.0,
+    );
+
+    pub fn contains(&self, attributes: CellAttributes) -> bool {
+        self.0 & attributes.0 == attributes.0
+    }
+
+    pub fn insert(&mut self, attributes: CellAttributes) {
+        self.0 |= attributes.0;
+    }
+
+    pub fn remove(&mut self, attributes: CellAttributes) {
+        self.0 &= !attributes.0;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+// same names as style attributes (e.g. `bold,italics`)
+impl fmt::Display for CellAttributes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let attributes = [
+            (Self::ACS, "acs"),
+            (Self::BOLD, "bold"),
+            (Self::DIM, "dim"),
+            (Self::UNDERSCORE, "underscore"),
+            (Self::BLINK, "blink"),
+            (Self::REVERSE, "reverse"),
+            (Self::HIDDEN, "hidden"),
+            (Self::ITALICS, "italics"),
+            (Self::OVERLINE, "overline"),
+            (Self::STRIKETHROUGH, "strikethrough"),
+            (Self::DOUBLE_UNDERSCORE, "double-underscore"),
+            (Self::CURLY_UNDERSCORE, "curly-underscore"),
+            (Self::DOTTED_UNDERSCORE, "dotted-underscore"),
+            (Self::DASHED_UNDERSCORE, "dashed-underscore"),
+        ];
+        let names: Vec<&str> = attributes
+            .iter()
+            .filter(|(attribute, _)| self.contains(*attribute))
+            .map(|(_, name)| *name)
+            .collect();
+        write!(f, "{}", names.join(","))
+    }
+}
+
+/// One cell of a [`Screen`](crate::Screen)
+///
+/// A wide character (e.g. CJK) occupies two cells: the first one holds the character with
+/// `width` 2, the following one is a padding cell with empty `text` and `width` 0
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ScreenCell {
+    /// Character, followed by combining characters if any
+    pub text: String,
+    /// Number of columns the character occupies
+    pub width: usize,
+    /// Foreground colour
+    pub fg: Colour,
+    /// Background colour
+    pub bg: Colour,
+    pub attributes: CellAttributes,
+}
+
+impl Default for ScreenCell {
+    fn default() -> Self {
+        Self {
+            text: " ".to_string(),
+            width: 1,
+            fg: Colour::Default,
+            bg: Colour::Default,
+            attributes: CellAttributes::NONE,
+        }
+    }
+}
+
+impl ScreenCell {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Padding cell following a wide character
+    pub fn is_padding(&self) -> bool {
+        self.width == 0
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{CapturePane, Error, IntoTargetPane, Screen, Tmux, TmuxCommand, TmuxOutput};
+use std::borrow::Cow;
+use std::str::FromStr;
+
+/// Capture pane contents into a [`Screen`] (`capture-pane -e -p`)
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::{Colour, ScreenCtl};
+///
+/// let screen = ScreenCtl::new().start_line("-10").get("%0").unwrap();
+/// if let Some((x, y)) = screen.find("error") {
+///     assert_eq!(screen.cell(x, y).unwrap().fg, Colour::Red);
+/// }
+/// ```
+pub struct ScreenCtl<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+
+    /// `[-a]`
+    pub alternate_screen: bool,
+
+    /// `[-J]`
+    #[cfg(feature = "tmux_2_4")]
+    pub join: bool,
+
+    /// `[-E end-line]`
+    pub end_line: Option<Cow<'a, str>>,
+
+    /// `[-S start-line]`
+    pub start_line: Option<Cow<'a, str>>,
+}
+
+impl<'a> Default for ScreenCtl<'a> {
+    fn default() -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+            alternate_screen: false,
+            #[cfg(feature = "tmux_2_4")]
+            join: false,
+            end_line: None,
+            start_line: None,
+        }
+    }
+}
+
+impl<'a> ScreenCtl<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self {
+            invoker,
+            ..Default::default()
+        }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Capture the alternate screen (`[-a]`)
+    pub fn alternate_screen(mut self) -> Self {
+        self.alternate_screen = true;
+        self
+    }
+
+    /// Join wrapped lines (`[-J]`)
+    #[cfg(feature = "tmux_2_4")]
+    pub fn join(mut self) -> Self {
+        self.join = true;
+        self
+    }
+
+    /// Last line to capture, `-` for the end of the visible pane (`[-E end-line]`)
+    pub fn end_line<S: Into<Cow<'a, str>>>(mut self, end_line: S) -> Self {
+        self.end_line = Some(end_line.into());
+        self
+    }
+
+    /// First line to capture, negative numbers are lines in the history, `-` for the start of the
+    /// history (`[-S start-line]`)
+    pub fn start_line<S: Into<Cow<'a, str>>>(mut self, start_line: S) -> Self {
+        self.start_line = Some(start_line.into());
+        self
+    }
+
+    /// `capture-pane` command used by [`ScreenCtl::get`]
+    pub fn capture_pane<S: IntoTargetPane<'a>>(&self, target_pane: S) -> CapturePane<'a> {
+        let mut cmd = CapturePane::new()
+            .escape_sequences()
+            .stdout()
+            .target_pane(target_pane);
+        if self.alternate_screen {
+            cmd = cmd.alternate_screen();
+        }
+        #[cfg(feature = "tmux_2_4")]
+        if self.join {
+            cmd = cmd.join();
+        }
+        if let Some(end_line) = &self.end_line {
+            cmd = cmd.end_line(end_line.clone());
+        }
+        if let Some(start_line) = &self.start_line {
+            cmd = cmd.start_line(start_line.clone());
+        }
+        cmd
+    }
+
+    pub fn get<S: IntoTargetPane<'a>>(&self, target_pane: S) -> Result<Screen, Error> {
+        let cmd = self.capture_pane(target_pane).build();
+        let output = (self.invoker)(cmd)?.checked()?.to_string();
+        Screen::from_str(&output)
+    }
+}
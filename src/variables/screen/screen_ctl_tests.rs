@@ -0,0 +1,45 @@
+#[test]
+fn get() {
+    use crate::variables::test_session::TestSession;
+    use crate::{CellAttributes, Colour, ScreenCtl};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    let session = TestSession::new("screen_ctl_test");
+    let pane = session.pane();
+
+    pane.send_keys([
+        "clear; printf '\\033[1;31mred\\033[0m \\033[42mgreen\\033[0m\\n'",
+        "Enter",
+    ])
+    .unwrap();
+
+    // shell start can take a while
+    let start = Instant::now();
+    let screen = loop {
+        let screen = pane.screen().unwrap();
+        if screen.find("red green").is_some() || start.elapsed() > Duration::from_secs(5) {
+            break screen;
+        }
+        thread::sleep(Duration::from_millis(100));
+    };
+
+    let (x, y) = screen.find("red green").unwrap();
+    let red = screen.cell(x, y).unwrap();
+    assert_eq!(red.fg, Colour::Red);
+    assert_eq!(red.attributes, CellAttributes::BOLD);
+    let green = screen.cell(x + 4, y).unwrap();
+    assert_eq!(green.bg, Colour::Green);
+    assert_eq!(green.fg, Colour::Default);
+
+    let screen = ScreenCtl::new()
+        .join()
+        .start_line("0")
+        .end_line(y.to_string())
+        .get(pane.id)
+        .unwrap();
+    assert_eq!(screen.height(), y + 1);
+
+    drop(session);
+    assert!(ScreenCtl::new().get(pane.id).is_err());
+}
@@ -0,0 +1,312 @@
+use crate::{CellAttributes, Colour, Error, ScreenCell};
+use std::str::FromStr;
+
+const ESC: char = '\x1b';
+const BEL: char = '\x07';
+// shift out, shift in (alternate character set)
+const SO: char = '\x0e';
+const SI: char = '\x0f';
+
+/// Grid of cells, parsed from `capture-pane -e -p` output
+///
+/// Lines are not padded: trailing spaces are trimmed by tmux (unless `-N`), lines joined by `-J`
+/// can be wider than the pane
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Screen {
+    pub lines: Vec<Vec<ScreenCell>>,
+}
+
+// NOTE: tmux source: grid.c -> grid_string_cells_code()
+//
+// tmux emits only changes of attributes between cells, the current style is kept across lines.
+// Unknown or malformed escape sequences are ignored, the same way a terminal does
+impl FromStr for Screen {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut screen = Screen::new();
+        let mut style = ScreenCell::new();
+        let mut line = Vec::new();
+
+        let s = s.strip_suffix('\n').unwrap_or(s);
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\n' => screen.lines.push(std::mem::take(&mut line)),
+                ESC => match chars.next() {
+                    // CSI: parameters and intermediate bytes, then the final byte
+                    Some('[') => {
+                        let mut params = String::new();
+                        for c in chars.by_ref() {
+                            if ('\x40'..='\x7e').contains(&c) {
+                                if c == 'm' {
+                                    sgr(&mut style, &params);
+                                }
+                                break;
+                            }
+                            params.push(c);
+                        }
+                    }
+                    // OSC (e.g. hyperlinks): terminated by BEL or ST (`ESC \`)
+                    Some(']') => {
+                        while let Some(c) = chars.next() {
+                            if c == BEL || (c == ESC && chars.next_if_eq(&'\\').is_some()) {
+                                break;
+                            }
+                        }
+                    }
+                    // character set designation
+                    Some('(') | Some(')') => {
+                        chars.next();
+                    }
+                    _ => {}
+                },
+                SO => style.attributes.insert(CellAttributes::ACS),
+                SI => style.attributes.remove(CellAttributes::ACS),
+                c if c.is_control() => {}
+                c => match char_width(c) {
+                    // combining character, appended to the previous character
+                    0 => {
+                        if let Some(cell) = line.iter_mut().rev().find(|cell| !cell.is_padding()) {
+                            cell.text.push(c);
+                        }
+                    }
+                    width => {
+                        line.push(ScreenCell {
+                            text: c.to_string(),
+                            width,
+                            ..style.clone()
+                        });
+                        if width == 2 {
+                            line.push(ScreenCell {
+                                text: String::new(),
+                                width: 0,
+                                ..style.clone()
+                            });
+                        }
+                    }
+                },
+            }
+        }
+        if !s.is_empty() {
+            screen.lines.push(line);
+        }
+
+        Ok(screen)
+    }
+}
+
+impl Screen {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Number of lines
+    pub fn height(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Number of cells of the longest line
+    pub fn width(&self) -> usize {
+        self.lines.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Cell at column `x` of line `y` (both starting from 0)
+    pub fn cell(&self, x: usize, y: usize) -> Option<&ScreenCell> {
+        self.lines.get(y).and_then(|line| line.get(x))
+    }
+
+    /// Text of line `y`, without escape sequences
+    pub fn line(&self, y: usize) -> Option<String> {
+        self.lines
+            .get(y)
+            .map(|line| line.iter().map(|cell| cell.text.as_str()).collect())
+    }
+
+    /// Text of all lines, without escape sequences
+    pub fn text(&self) -> String {
+        (0..self.height())
+            .filter_map(|y| self.line(y))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Position (column, line) of the first occurrence of `text`
+    pub fn find(&self, text: &str) -> Option<(usize, usize)> {
+        self.lines.iter().enumerate().find_map(|(y, line)| {
+            (0..line.len())
+                .filter(|x| !line[*x].is_padding())
+                .find(|x| {
+                    let mut rest = text;
+                    for cell in &line[*x..] {
+                        if rest.is_empty() {
+                            break;
+                        }
+                        match rest.strip_prefix(cell.text.as_str()) {
+                            Some(r) => rest = r,
+                            None => return false,
+                        }
+                    }
+                    rest.is_empty()
+                })
+                .map(|x| (x, y))
+        })
+    }
+}
+
+// apply `ESC [ params m` to the current style
+//
+// parameters are separated by `;`, subparameters (`4:3`, `38:2::r:g:b`) by `:`
+fn sgr(style: &mut ScreenCell, params: &str) {
+    let params: Vec<Vec<u32>> = params
+        .split(';')
+        .map(|p| p.split(':').map(|n| n.parse().unwrap_or(0)).collect())
+        .collect();
+
+    let mut i = 0;
+    while i < params.len() {
+        let param = &params[i];
+        match param[0] {
+            0 => {
+                style.fg = Colour::Default;
+                style.bg = Colour::Default;
+                // alternate character set is not an SGR attribute
+                style
+                    .attributes
+                    .remove(CellAttributes(!CellAttributes::ACS.0));
+            }
+            1 => style.attributes.insert(CellAttributes::BOLD),
+            2 => style.attributes.insert(CellAttributes::DIM),
+            3 => style.attributes.insert(CellAttributes::ITALICS),
+            4 => {
+                style.attributes.remove(CellAttributes::UNDERSCORES);
+                match param.get(1) {
+                    None | Some(1) => style.attributes.insert(CellAttributes::UNDERSCORE),
+                    Some(2) => style.attributes.insert(CellAttributes::DOUBLE_UNDERSCORE),
+                    Some(3) => style.attributes.insert(CellAttributes::CURLY_UNDERSCORE),
+                    Some(4) => style.attributes.insert(CellAttributes::DOTTED_UNDERSCORE),
+                    Some(5) => style.attributes.insert(CellAttributes::DASHED_UNDERSCORE),
+                    _ => {}
+                }
+            }
+            5 | 6 => style.attributes.insert(CellAttributes::BLINK),
+            7 => style.attributes.insert(CellAttributes::REVERSE),
+            8 => style.attributes.insert(CellAttributes::HIDDEN),
+            9 => style.attributes.insert(CellAttributes::STRIKETHROUGH),
+            21 => {
+                style.attributes.remove(CellAttributes::UNDERSCORES);
+                style.attributes.insert(CellAttributes::DOUBLE_UNDERSCORE);
+            }
+            22 => style.attributes.remove(CellAttributes(
+                CellAttributes::BOLD.0 | CellAttributes::DIM.0,
+            )),
+            23 => style.attributes.remove(CellAttributes::ITALICS),
+            24 => style.attributes.remove(CellAttributes::UNDERSCORES),
+            25 => style.attributes.remove(CellAttributes::BLINK),
+            27 => style.attributes.remove(CellAttributes::REVERSE),
+            28 => style.attributes.remove(CellAttributes::HIDDEN),
+            29 => style.attributes.remove(CellAttributes::STRIKETHROUGH),
+            n @ 30..=37 => style.fg = colour(n - 30),
+            n @ 40..=47 => style.bg = colour(n - 40),
+            // extended colours, underscore colour (58) is consumed but not kept
+            n @ 38 | n @ 48 | n @ 58 => {
+                let (extended, consumed) = extended_colour(&params[i..]);
+                if let Some(extended) = extended {
+                    match n {
+                        38 => style.fg = extended,
+                        48 => style.bg = extended,
+                        _ => {}
+                    }
+                }
+                i += consumed;
+            }
+            39 => style.fg = Colour::Default,
+            49 => style.bg = Colour::Default,
+            53 => style.attributes.insert(CellAttributes::OVERLINE),
+            55 => style.attributes.remove(CellAttributes::OVERLINE),
+            // aixterm bright colours are colours 8 to 15 of the 256-colour set
+            n @ 90..=97 => style.fg = Colour::ColourSet256((n - 90 + 8) as u8),
+            n @ 100..=107 => style.bg = Colour::ColourSet256((n - 100 + 8) as u8),
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+// `38;5;n`, `38;2;r;g;b`, `38:5:n`, `38:2::r:g:b` or `38:2:r:g:b`, returns the colour and the
+// number of additional `;` separated parameters used
+fn extended_colour(params: &[Vec<u32>]) -> (Option<Colour>, usize) {
+    let param = &params[0];
+    if param.len() > 1 {
+        let colour = match param[1] {
+            5 => param.get(2).map(|n| Colour::ColourSet256(*n as u8)),
+            2 if param.len() >= 5 => Some(rgb(&param[param.len() - 3..])),
+            _ => None,
+        };
+        return (colour, 0);
+    }
+    let next: Vec<u32> = params[1..].iter().map(|p| p[0]).collect();
+    match next.first() {
+        Some(5) if next.len() >= 2 => (Some(Colour::ColourSet256(next[1] as u8)), 2),
+        Some(2) if next.len() >= 4 => (Some(rgb(&next[1..4])), 4),
+        _ => (None, next.len()),
+    }
+}
+
+fn rgb(rgb: &[u32]) -> Colour {
+    Colour::HEX(((rgb[0] & 0xff) << 16) | ((rgb[1] & 0xff) << 8) | (rgb[2] & 0xff))
+}
+
+fn colour(n: u32) -> Colour {
+    match n {
+        0 => Colour::Black,
+        1 => Colour::Red,
+        2 => Colour::Green,
+        3 => Colour::Yellow,
+        4 => Colour::Blue,
+        5 => Colour::Magenta,
+        6 => Colour::Cyan,
+        _ => Colour::White,
+    }
+}
+
+// XXX: approximation of wcwidth(), mb use unicode-width crate?
+fn char_width(c: char) -> usize {
+    let c = c as u32;
+    let zero = [
+        (0x0300, 0x036f),
+        (0x0483, 0x0489),
+        (0x0591, 0x05bd),
+        (0x0610, 0x061a),
+        (0x064b, 0x065f),
+        (0x1ab0, 0x1aff),
+        (0x1dc0, 0x1dff),
+        (0x200b, 0x200f),
+        (0x20d0, 0x20ff),
+        (0xfe00, 0xfe0f),
+        (0xfe20, 0xfe2f),
+    ];
+    let wide = [
+        (0x1100, 0x115f),
+        (0x2e80, 0x303e),
+        (0x3041, 0x33ff),
+        (0x3400, 0x4dbf),
+        (0x4e00, 0x9fff),
+        (0xa000, 0xa4cf),
+        (0xac00, 0xd7a3),
+        (0xf900, 0xfaff),
+        (0xfe30, 0xfe4f),
+        (0xff00, 0xff60),
+        (0xffe0, 0xffe6),
+        (0x1f300, 0x1f64f),
+        (0x1f900, 0x1f9ff),
+        (0x20000, 0x3fffd),
+    ];
+    if zero.iter().any(|(start, end)| (*start..=*end).contains(&c)) {
+        0
+    } else if wide.iter().any(|(start, end)| (*start..=*end).contains(&c)) {
+        2
+    } else {
+        1
+    }
+}
@@ -0,0 +1,112 @@
+#[test]
+fn parse_text() {
+    use crate::Screen;
+    use std::str::FromStr;
+
+    let screen = Screen::from_str("$ echo hello\nhello\n\n").unwrap();
+    assert_eq!(screen.height(), 3);
+    assert_eq!(screen.width(), 12);
+    assert_eq!(screen.line(1), Some("hello".to_string()));
+    assert_eq!(screen.line(2), Some("".to_string()));
+    assert_eq!(screen.text(), "$ echo hello\nhello\n");
+    assert_eq!(screen.find("hello"), Some((7, 0)));
+    assert_eq!(screen.find("bye"), None);
+    assert_eq!(screen.cell(0, 1).unwrap().text, "h");
+    assert_eq!(screen.cell(5, 1), None);
+
+    assert_eq!(Screen::from_str("").unwrap().height(), 0);
+}
+
+#[test]
+fn parse_colours() {
+    use crate::{CellAttributes, Colour, Screen};
+    use std::str::FromStr;
+
+    let s = concat!(
+        "\x1b[31ma\x1b[42mb\x1b[39mc\x1b[0md",
+        "\x1b[38;5;100me\x1b[48;2;1;2;3mf\x1b[38:2::255:0:16mg\n",
+        // style is kept across lines
+        "h\x1b[0;94;101mi\x1b[38:5:7;49mj"
+    );
+    let screen = Screen::from_str(s).unwrap();
+
+    let colours = |x, y| {
+        let cell = screen.cell(x, y).unwrap();
+        (cell.fg.clone(), cell.bg.clone())
+    };
+    assert_eq!(colours(0, 0), (Colour::Red, Colour::Default));
+    assert_eq!(colours(1, 0), (Colour::Red, Colour::Green));
+    assert_eq!(colours(2, 0), (Colour::Default, Colour::Green));
+    assert_eq!(colours(3, 0), (Colour::Default, Colour::Default));
+    assert_eq!(colours(4, 0), (Colour::ColourSet256(100), Colour::Default));
+    assert_eq!(
+        colours(5, 0),
+        (Colour::ColourSet256(100), Colour::HEX(0x010203))
+    );
+    assert_eq!(
+        colours(6, 0),
+        (Colour::HEX(0xff0010), Colour::HEX(0x010203))
+    );
+    assert_eq!(
+        colours(0, 1),
+        (Colour::HEX(0xff0010), Colour::HEX(0x010203))
+    );
+    assert_eq!(
+        colours(1, 1),
+        (Colour::ColourSet256(12), Colour::ColourSet256(9))
+    );
+    assert_eq!(colours(2, 1), (Colour::ColourSet256(7), Colour::Default));
+    assert_eq!(screen.line(0), Some("abcdefg".to_string()));
+    assert!(screen.cell(0, 0).unwrap().attributes.is_empty());
+    assert_eq!(screen.cell(0, 0).unwrap().attributes, CellAttributes::NONE);
+}
+
+#[test]
+fn parse_attributes() {
+    use crate::{CellAttributes, Screen};
+    use std::str::FromStr;
+
+    let s = concat!(
+        "\x1b[1;3ma\x1b[22mb\x1b[4:3mc\x1b[4md\x1b[24;7me",
+        "\x0eq\x0f\x1b[0;9;53mf\x1b]8;;https://example.com\x1b\\g\x1b]8;;\x07h"
+    );
+    let screen = Screen::from_str(s).unwrap();
+
+    let attributes = |x| screen.cell(x, 0).unwrap().attributes;
+    let mut bold_italics = CellAttributes::BOLD;
+    bold_italics.insert(CellAttributes::ITALICS);
+    assert_eq!(attributes(0), bold_italics);
+    assert_eq!(attributes(0).to_string(), "bold,italics");
+    assert_eq!(attributes(1), CellAttributes::ITALICS);
+    assert!(attributes(2).contains(CellAttributes::CURLY_UNDERSCORE));
+    assert!(!attributes(2).contains(CellAttributes::UNDERSCORE));
+    assert!(attributes(3).contains(CellAttributes::UNDERSCORE));
+    assert!(!attributes(3).contains(CellAttributes::CURLY_UNDERSCORE));
+    assert!(!attributes(4).contains(CellAttributes::UNDERSCORE));
+    assert!(attributes(4).contains(CellAttributes::REVERSE));
+    assert!(attributes(5).contains(CellAttributes::ACS));
+    assert!(!attributes(6).contains(CellAttributes::ACS));
+    assert_eq!(attributes(6).to_string(), "overline,strikethrough");
+    assert_eq!(attributes(7), attributes(6));
+    assert_eq!(screen.line(0), Some("abcdeqfgh".to_string()));
+}
+
+#[test]
+fn parse_wide_chars() {
+    use crate::Screen;
+    use std::str::FromStr;
+
+    let screen = Screen::from_str("a\u{4e2d}\u{6587}e\u{301}z").unwrap();
+    assert_eq!(screen.width(), 7);
+    assert_eq!(screen.cell(1, 0).unwrap().text, "\u{4e2d}");
+    assert_eq!(screen.cell(1, 0).unwrap().width, 2);
+    assert!(screen.cell(2, 0).unwrap().is_padding());
+    assert_eq!(screen.cell(3, 0).unwrap().text, "\u{6587}");
+    assert_eq!(screen.cell(5, 0).unwrap().text, "e\u{301}");
+    assert_eq!(screen.cell(6, 0).unwrap().text, "z");
+    assert_eq!(screen.find("\u{6587}e\u{301}"), Some((3, 0)));
+    assert_eq!(
+        screen.line(0),
+        Some("a\u{4e2d}\u{6587}e\u{301}z".to_string())
+    );
+}
@@ -1,7 +1,11 @@
+use crate::formats::format_value::FormatValue;
 use crate::Error;
 use crate::FormatsOutput;
+#[cfg(feature = "tmux_1_8")]
+use crate::SessionId;
 #[cfg(feature = "tmux_2_5")]
 use crate::SessionStack;
+use crate::Variable;
 use std::str::FromStr;
 
 // XXX: number of all flags, needed for array init
@@ -9,6 +13,7 @@ use std::str::FromStr;
 // accordingly to tmux.h: Formats
 // XXX: check all types
 #[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Session {
     // NOTE: u64
     /// session_activity - Time of session last activity
@@ -51,7 +56,7 @@ pub struct Session {
     /// session_group_many_attached - 1 if multiple clients attached to sessions in gro
     #[cfg(feature = "tmux_3_1")]
     pub group_many_attached: Option<bool>,
-    /// session_size - Size of session group
+    /// session_group_size - Size of session group
     #[cfg(feature = "tmux_2_7")]
     pub group_size: Option<String>,
     /// session_grouped - 1 if session in a group
@@ -65,7 +70,7 @@ pub struct Session {
     pub width: Option<usize>,
     /// session_id - Unique session ID
     #[cfg(feature = "tmux_1_8")]
-    pub id: Option<usize>,
+    pub id: Option<SessionId>,
     // NOTE: u64
     /// session_last_attached - Time session last attached
     #[cfg(feature = "tmux_2_1")]
@@ -91,9 +96,19 @@ impl FromStr for Session {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Error> {
+        Self::from_values(&FormatsOutput::delimited_values(s)?)
+    }
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Parse values of one record of a [`SessionsCtl::formats`](crate::SessionsCtl::formats) query
+    pub fn from_values(values: &[&str]) -> Result<Self, Error> {
         let mut session = Session::new();
-        let mut format = FormatsOutput::new();
-        format.separator(':');
+        let mut format = FormatsOutput::delimited();
 
         #[cfg(feature = "tmux_2_1")]
         format.session_activity(&mut session.activity);
@@ -144,14 +159,84 @@ impl FromStr for Session {
         #[cfg(feature = "tmux_1_6")]
         format.session_windows(&mut session.windows);
 
-        FormatsOutput::from_string_ext(s, &mut format);
+        FormatsOutput::from_values_ext(values, &mut format)?;
         Ok(session)
     }
-}
 
-impl Session {
-    pub fn new() -> Self {
-        Default::default()
+    /// Value of the session `variable`, as it would be expanded by tmux
+    pub fn value(&self, variable: &Variable) -> Option<String> {
+        match variable {
+            #[cfg(feature = "tmux_2_1")]
+            Variable::SessionActivity => self.activity.as_ref().map(FormatValue::format_value),
+            #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_2_2")))]
+            Variable::SessionActivityString => {
+                self.activity_string.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_2_1")]
+            Variable::SessionAlerts => self.alerts.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::SessionAttached => self.attached.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::SessionAttachedList => {
+                self.attached_list.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_1_6")]
+            Variable::SessionCreated => self.created.as_ref().map(FormatValue::format_value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_2")))]
+            Variable::SessionCreatedString => {
+                self.created_string.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_2_6")]
+            Variable::SessionFormat => self.format.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::SessionGroup => self.group.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::SessionGroupAttached => {
+                self.group_attached.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_3_1")]
+            Variable::SessionGroupAttachedList => self
+                .group_attached_list
+                .as_ref()
+                .map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_7")]
+            Variable::SessionGroupList => self.group_list.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::SessionGroupManyAttached => self
+                .group_many_attached
+                .as_ref()
+                .map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_7")]
+            Variable::SessionGroupSize => self.group_size.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::SessionGrouped => self.grouped.as_ref().map(FormatValue::format_value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_9")))]
+            Variable::SessionHeight => self.height.as_ref().map(FormatValue::format_value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_9")))]
+            Variable::SessionWidth => self.width.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_8")]
+            Variable::SessionId => self.id.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_1")]
+            Variable::SessionLastAttached => {
+                self.last_attached.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_2_2")))]
+            Variable::SessionLastAttachedString => self
+                .last_attached_string
+                .as_ref()
+                .map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_0")]
+            Variable::SessionManyAttached => {
+                self.many_attached.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_1_6")]
+            Variable::SessionName => self.name.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_5")]
+            Variable::SessionStack => self.stack.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::SessionWindows => self.windows.as_ref().map(FormatValue::format_value),
+            _ => None,
+        }
     }
 
     // XXX: wrapper with format generating and result parsing using callback
@@ -1,7 +1,9 @@
 use crate::Error;
+use std::fmt;
 use std::str::FromStr;
 
 #[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SessionStack(pub Vec<usize>);
 
 const SESSION_STACK_SEPARATOR: char = ',';
@@ -18,3 +20,15 @@ impl FromStr for SessionStack {
         Ok(Self(sv))
     }
 }
+
+impl fmt::Display for SessionStack {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = self
+            .0
+            .iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<String>>()
+            .join(&SESSION_STACK_SEPARATOR.to_string());
+        write!(f, "{}", output)
+    }
+}
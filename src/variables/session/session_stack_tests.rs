@@ -7,3 +7,11 @@ fn parse() {
     let session_stack_origin = SessionStack(vec![3, 2, 1]);
     assert_eq!(session_stack, session_stack_origin);
 }
+
+#[test]
+fn session_stack_to_string() {
+    use crate::SessionStack;
+
+    let stack = SessionStack(vec![2, 0, 1]);
+    assert_eq!(stack.to_string(), "2,0,1");
+}
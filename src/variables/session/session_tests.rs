@@ -10,9 +10,10 @@ fn show_generated_struct() {
 
 #[test]
 fn parse() {
-    use crate::Session;
+    use crate::FormatsOutput;
     #[cfg(feature = "tmux_2_5")]
     use crate::SessionStack;
+    use crate::{Session, SessionId};
     use std::str::FromStr;
 
     let session_vec = vec![
@@ -89,7 +90,7 @@ fn parse() {
         #[cfg(feature = "tmux_1_6")]
         "3",
     ];
-    let session_str = session_vec.join(":");
+    let session_str = FormatsOutput::delimited_record(&session_vec);
 
     let session = Session::from_str(&session_str).unwrap();
     let session_sample = Session {
@@ -128,7 +129,7 @@ fn parse() {
         #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_9")))]
         width: Some(0),
         #[cfg(feature = "tmux_1_8")]
-        id: Some(0),
+        id: Some(SessionId(0)),
         #[cfg(feature = "tmux_2_1")]
         last_attached: Some(1557947146),
         #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_2_2")))]
@@ -1,4 +1,4 @@
-use crate::{Error, Session};
+use crate::{Error, FormatsOutput, Session};
 use std::ops::Index;
 use std::str::FromStr;
 
@@ -27,8 +27,8 @@ impl FromStr for Sessions {
 
     fn from_str(s: &str) -> Result<Self, Error> {
         let mut sessions = Sessions::new();
-        for line in s.lines() {
-            sessions.push(Session::from_str(line)?);
+        for record in FormatsOutput::delimited_records(s)? {
+            sessions.push(Session::from_str(record)?);
         }
         Ok(sessions)
     }
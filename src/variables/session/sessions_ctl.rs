@@ -1,4 +1,7 @@
-use crate::{Error, Formats, ListSessions, Sessions, Tmux, TmuxCommand, TmuxOutput};
+use crate::{
+    Error, Formats, FormatsOutput, ListSessions, Session, Sessions, Tmux, TmuxCommand, TmuxOutput,
+    Variable,
+};
 use std::str::FromStr;
 
 // trait top level options, then server session window pane
@@ -39,8 +42,37 @@ impl<'a> SessionsCtl<'a> {
     pub fn get_all_ext(
         invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
     ) -> Result<Sessions, Error> {
-        let mut format = Formats::new();
-        format.separator(':');
+        let ls_format = Self::formats().to_string();
+
+        let cmd = ListSessions::new().format(ls_format).build();
+        let output = (invoker)(cmd)?.to_string();
+        Sessions::from_str(&output)
+    }
+
+    /// Sessions with values of additional variables (e.g. [`Variable::Custom`]), in their order
+    pub fn get_all_with(
+        &self,
+        variables: &[Variable],
+    ) -> Result<Vec<(Session, Vec<String>)>, Error> {
+        Self::get_all_with_ext(variables, self.invoker())
+    }
+
+    pub fn get_all_with_ext(
+        variables: &[Variable],
+        invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<Vec<(Session, Vec<String>)>, Error> {
+        let mut format = Self::formats();
+        let len = format.variables.len();
+        format.variables.extend_from_slice(variables);
+
+        let cmd = ListSessions::new().format(format.to_string()).build();
+        let output = (invoker)(cmd)?.to_string();
+        FormatsOutput::parse_records_with(&output, len, variables.len(), Session::from_values)
+    }
+
+    /// Formats of all session variables, parsed by [`Session::from_values`]
+    pub fn formats() -> Formats {
+        let mut format = Formats::delimited();
 
         #[cfg(feature = "tmux_2_1")]
         format.session_activity();
@@ -91,10 +123,6 @@ impl<'a> SessionsCtl<'a> {
         #[cfg(feature = "tmux_1_6")]
         format.session_windows();
 
-        let ls_format = format.to_string();
-
-        let cmd = ListSessions::new().format(ls_format).build();
-        let output = (invoker)(cmd)?.to_string();
-        Sessions::from_str(&output)
+        format
     }
 }
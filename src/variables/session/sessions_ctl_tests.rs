@@ -5,3 +5,26 @@ fn get() {
     let sessions = SessionsCtl::default().get_all().unwrap();
     dbg!(sessions);
 }
+
+#[test]
+fn get_all_with() {
+    use crate::variables::test_session::TestSession;
+    use crate::{SessionsCtl, Variable};
+
+    const TEST_SESSION_NAME: &str = "sessions_ctl_get_all_with";
+
+    let session = TestSession::new(TEST_SESSION_NAME);
+    let session_id = session.session_id;
+
+    let variables = [
+        Variable::Custom("@sessions_ctl_unset".to_string()),
+        Variable::Custom("#{session_id}:#{session_name}".to_string()),
+    ];
+    let sessions = SessionsCtl::default().get_all_with(&variables).unwrap();
+    let (_, values) = sessions
+        .iter()
+        .find(|(session, _)| session.id == Some(session_id))
+        .unwrap();
+    assert_eq!(values[0], "");
+    assert_eq!(values[1], format!("{}:{}", session_id, TEST_SESSION_NAME));
+}
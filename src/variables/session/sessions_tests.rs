@@ -1,6 +1,6 @@
 #[test]
 fn parse() {
-    use crate::Sessions;
+    use crate::{FormatsOutput, SessionId, Sessions};
     use std::str::FromStr;
 
     //"1557947146::1:1557947146:1::::0::0:$0:1557947146:0:0:3,2,1:3\n\
@@ -153,14 +153,14 @@ fn parse() {
         #[cfg(feature = "tmux_1_6")]
         "3",
     ];
-    let session1_str = session1_vec.join(":");
-    let session2_str = session2_vec.join(":");
+    let session1_str = FormatsOutput::delimited_record(&session1_vec);
+    let session2_str = FormatsOutput::delimited_record(&session2_vec);
 
     let sessions_str = format!("{}\n{}", session1_str, session2_str);
     let sessions = Sessions::from_str(&sessions_str).unwrap();
 
     #[cfg(feature = "tmux_1_8")]
-    assert_eq!(sessions[0].id, Some(0));
+    assert_eq!(sessions[0].id, Some(SessionId(0)));
 
     //"1557947146::1:1557947146:1::::0::0:$0:1557947146:0:0:4,3,2,1:4\n\
     //1557947146::0:1557947146:1::::0::0:$40:1557947146:0:test_has_session:1:1";
@@ -312,10 +312,10 @@ fn parse() {
         #[cfg(feature = "tmux_1_6")]
         "1",
     ];
-    let session1_str = session1_vec.join(":");
-    let session2_str = session2_vec.join(":");
+    let session1_str = FormatsOutput::delimited_record(&session1_vec);
+    let session2_str = FormatsOutput::delimited_record(&session2_vec);
     let sessions_str = format!("{}\n{}", session1_str, session2_str);
     let sessions = Sessions::from_str(&sessions_str).unwrap();
     #[cfg(feature = "tmux_1_8")]
-    assert_eq!(sessions[1].id, Some(40));
+    assert_eq!(sessions[1].id, Some(SessionId(40)));
 }
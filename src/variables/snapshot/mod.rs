@@ -0,0 +1,22 @@
+#[cfg(feature = "tmux_1_8")]
+pub mod server_snapshot;
+#[cfg(feature = "tmux_1_8")]
+pub mod snapshot_ctl;
+#[cfg(feature = "tmux_1_8")]
+pub mod snapshot_watcher;
+
+#[cfg(feature = "tmux_1_8")]
+pub use server_snapshot::{Snapshot, SnapshotWindow, WindowLink};
+#[cfg(feature = "tmux_1_8")]
+pub use snapshot_ctl::SnapshotCtl;
+#[cfg(feature = "tmux_1_8")]
+pub use snapshot_watcher::{SnapshotWatcher, SNAPSHOT_WATCHER_INTERVAL};
+
+#[cfg(test)]
+#[path = "."]
+mod variables_snapshot_tests {
+    #[cfg(feature = "tmux_1_8")]
+    mod snapshot_tests;
+    #[cfg(feature = "tmux_1_8")]
+    mod snapshot_watcher_tests;
+}
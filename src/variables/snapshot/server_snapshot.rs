@@ -0,0 +1,406 @@
+use crate::control_mode::server_state::ServerStateEvent;
+use crate::{
+    Client, ClientName, Error, FormatsOutput, Pane, PaneId, Session, SessionId, SessionsCtl,
+    Window, WindowId, WindowsCtl,
+};
+use std::str::FromStr;
+
+// Server structure at one point in time
+//
+// sessions -(links)-> windows -> panes, clients -> session
+//
+// NOTE: a window linked into multiple sessions is stored once, its session dependent values
+// (`window_index`, `window_active`) are stored per session in `WindowLink`
+//
+// NOTE: clients are identified by `client_tty` (name of the client, if attached to a terminal)
+
+/// Window linked into a session
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowLink {
+    pub session_id: SessionId,
+    pub window_id: WindowId,
+    /// `window_index` - Index of window in the session
+    pub index: Option<usize>,
+    /// `window_active` - 1 if window is the current window of the session
+    pub active: Option<bool>,
+}
+
+/// Window with all its panes
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotWindow {
+    pub window: Window,
+    pub panes: Vec<Pane>,
+}
+
+/// Sessions, windows, panes and clients of the server, see [`SnapshotCtl`](crate::SnapshotCtl)
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+    pub sessions: Vec<Session>,
+    /// windows of all sessions (once, if linked into multiple sessions)
+    pub windows: Vec<SnapshotWindow>,
+    /// windows of each session
+    pub links: Vec<WindowLink>,
+    pub clients: Vec<Client>,
+}
+
+impl FromStr for Snapshot {
+    type Err = Error;
+
+    /// Parse `list-panes -a` output of a [`SnapshotCtl::formats`](crate::SnapshotCtl::formats)
+    /// query (without clients)
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let session_len = SessionsCtl::formats().variables.len();
+        let window_len = WindowsCtl::formats().variables.len();
+
+        let mut snapshot = Snapshot::new();
+        for record in FormatsOutput::delimited_records(s)? {
+            let values = FormatsOutput::delimited_values(record)?;
+            if values.len() < session_len + window_len {
+                return Err(Error::ParseFormatsOutput);
+            }
+            let (session, values) = values.split_at(session_len);
+            let (window, pane) = values.split_at(window_len);
+            snapshot.insert(
+                Session::from_values(session)?,
+                Window::from_values(window)?,
+                Pane::from_values(pane)?,
+            )?;
+        }
+        Ok(snapshot)
+    }
+}
+
+impl Snapshot {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // add a pane with its window and session (all of them known already or new)
+    fn insert(&mut self, session: Session, window: Window, pane: Pane) -> Result<(), Error> {
+        let session_id = session.id.ok_or(Error::ParseFormatsOutput)?;
+        let window_id = window.id.ok_or(Error::ParseFormatsOutput)?;
+        let pane_id = pane.id.ok_or(Error::ParseFormatsOutput)?;
+
+        if self.session(session_id).is_none() {
+            self.sessions.push(session);
+        }
+
+        if !self
+            .links
+            .iter()
+            .any(|l| l.session_id == session_id && l.window_id == window_id)
+        {
+            self.links.push(WindowLink {
+                session_id,
+                window_id,
+                index: window.index,
+                active: window.active,
+            });
+        }
+
+        let index = match self
+            .windows
+            .iter()
+            .position(|w| w.window.id == Some(window_id))
+        {
+            Some(index) => index,
+            None => {
+                self.windows.push(SnapshotWindow {
+                    window,
+                    panes: Vec::new(),
+                });
+                self.windows.len() - 1
+            }
+        };
+
+        let panes = &mut self.windows[index].panes;
+        if !panes.iter().any(|p| p.id == Some(pane_id)) {
+            panes.push(pane);
+        }
+        Ok(())
+    }
+
+    /// Get session by id
+    pub fn session(&self, session_id: SessionId) -> Option<&Session> {
+        self.sessions.iter().find(|s| s.id == Some(session_id))
+    }
+
+    /// Get window by id
+    pub fn window(&self, window_id: WindowId) -> Option<&SnapshotWindow> {
+        self.windows.iter().find(|w| w.window.id == Some(window_id))
+    }
+
+    /// Get pane by id
+    pub fn pane(&self, pane_id: PaneId) -> Option<&Pane> {
+        self.panes().find(|p| p.id == Some(pane_id))
+    }
+
+    /// All panes of all windows
+    pub fn panes(&self) -> impl Iterator<Item = &Pane> {
+        self.windows.iter().flat_map(|w| w.panes.iter())
+    }
+
+    /// Windows of the session, ordered by their index in the session
+    pub fn session_windows(&self, session_id: SessionId) -> Vec<&SnapshotWindow> {
+        let mut links = self
+            .links
+            .iter()
+            .filter(|l| l.session_id == session_id)
+            .collect::<Vec<&WindowLink>>();
+        links.sort_by_key(|l| l.index);
+        links
+            .iter()
+            .filter_map(|l| self.window(l.window_id))
+            .collect()
+    }
+
+    /// Sessions the window is linked into
+    pub fn window_sessions(&self, window_id: WindowId) -> Vec<&Session> {
+        self.links
+            .iter()
+            .filter(|l| l.window_id == window_id)
+            .filter_map(|l| self.session(l.session_id))
+            .collect()
+    }
+
+    /// Window containing the pane
+    pub fn pane_window(&self, pane_id: PaneId) -> Option<&SnapshotWindow> {
+        self.windows
+            .iter()
+            .find(|w| w.panes.iter().any(|p| p.id == Some(pane_id)))
+    }
+
+    /// Clients attached to the session
+    pub fn session_clients(&self, session_id: SessionId) -> Vec<&Client> {
+        match self.session(session_id) {
+            Some(session) => self
+                .clients
+                .iter()
+                .filter(|c| c.session.is_some() && c.session == session.name)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Session the client is attached to
+    pub fn client_session(&self, client: &Client) -> Option<&Session> {
+        match client.session {
+            Some(ref name) => self.sessions.iter().find(|s| s.name.as_ref() == Some(name)),
+            None => None,
+        }
+    }
+
+    fn link(&self, session_id: SessionId, window_id: WindowId) -> Option<&WindowLink> {
+        self.links
+            .iter()
+            .find(|l| l.session_id == session_id && l.window_id == window_id)
+    }
+
+    // current window of the session
+    fn active_window(&self, session_id: SessionId) -> Option<WindowId> {
+        self.links
+            .iter()
+            .find(|l| l.session_id == session_id && l.active == Some(true))
+            .map(|l| l.window_id)
+    }
+
+    /// Compare with a newer snapshot, get structural changes (in the same order as
+    /// [`ServerState`](crate::control_mode::server_state::ServerState) does: added, changed,
+    /// closed)
+    pub fn diff(&self, new: &Snapshot) -> Vec<ServerStateEvent> {
+        let mut events = Vec::new();
+
+        for session in new.sessions.iter() {
+            let session_id = match session.id {
+                Some(id) => id,
+                None => continue,
+            };
+            match self.session(session_id) {
+                None => events.push(ServerStateEvent::SessionAdded(session_id)),
+                Some(old) => {
+                    if let (Some(name), true) = (&session.name, old.name != session.name) {
+                        events.push(ServerStateEvent::SessionRenamed {
+                            session_id,
+                            name: name.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        for link in new.links.iter() {
+            match self.link(link.session_id, link.window_id) {
+                None => events.push(ServerStateEvent::WindowAdded {
+                    session_id: link.session_id,
+                    window_id: link.window_id,
+                }),
+                Some(old) => {
+                    if let (Some(index), true) = (link.index, old.index != link.index) {
+                        events.push(ServerStateEvent::WindowMoved {
+                            session_id: link.session_id,
+                            window_id: link.window_id,
+                            index,
+                        });
+                    }
+                }
+            }
+        }
+
+        // current window of existing sessions
+        for session_id in new.sessions.iter().filter_map(|s| s.id) {
+            if self.session(session_id).is_none() {
+                continue;
+            }
+            let active = new.active_window(session_id);
+            if let (Some(window_id), true) = (active, active != self.active_window(session_id)) {
+                events.push(ServerStateEvent::SessionWindowChanged {
+                    session_id,
+                    window_id,
+                });
+            }
+        }
+
+        for window_state in new.windows.iter() {
+            let window_id = match window_state.window.id {
+                Some(id) => id,
+                None => continue,
+            };
+            let old = self.window(window_id);
+            Self::diff_panes(window_id, old, window_state, &mut events);
+            if let Some(old) = old {
+                let window = &window_state.window;
+                if let (Some(name), true) = (&window.name, old.window.name != window.name) {
+                    events.push(ServerStateEvent::WindowRenamed {
+                        window_id,
+                        name: name.to_string(),
+                    });
+                }
+                if old.window.layout != window.layout {
+                    events.push(ServerStateEvent::LayoutChanged { window_id });
+                }
+            }
+        }
+
+        for window_state in self.windows.iter() {
+            if let Some(window_id) = window_state.window.id {
+                if new.window(window_id).is_none() {
+                    for pane_id in window_state.panes.iter().filter_map(|p| p.id) {
+                        events.push(ServerStateEvent::PaneClosed { window_id, pane_id });
+                    }
+                }
+            }
+        }
+
+        for link in self.links.iter() {
+            if new.link(link.session_id, link.window_id).is_none() {
+                events.push(ServerStateEvent::WindowClosed {
+                    session_id: link.session_id,
+                    window_id: link.window_id,
+                });
+            }
+        }
+
+        for session_id in self.sessions.iter().filter_map(|s| s.id) {
+            if new.session(session_id).is_none() {
+                events.push(ServerStateEvent::SessionClosed(session_id));
+            }
+        }
+
+        self.diff_clients(new, &mut events);
+
+        events
+    }
+
+    // panes of the window, `old` is `None` for a new window
+    fn diff_panes(
+        window_id: WindowId,
+        old: Option<&SnapshotWindow>,
+        new: &SnapshotWindow,
+        events: &mut Vec<ServerStateEvent>,
+    ) {
+        let old_panes = old.map(|w| w.panes.as_slice()).unwrap_or(&[]);
+
+        for pane in new.panes.iter() {
+            let pane_id = match pane.id {
+                Some(id) => id,
+                None => continue,
+            };
+            match old_panes.iter().find(|p| p.id == Some(pane_id)) {
+                None => events.push(ServerStateEvent::PaneAdded { window_id, pane_id }),
+                Some(old_pane) => {
+                    if let (Some(width), Some(height), true) = (
+                        pane.width,
+                        pane.height,
+                        (old_pane.width, old_pane.height) != (pane.width, pane.height),
+                    ) {
+                        events.push(ServerStateEvent::PaneResized {
+                            window_id,
+                            pane_id,
+                            width,
+                            height,
+                        });
+                    }
+                }
+            }
+        }
+
+        for pane_id in old_panes.iter().filter_map(|p| p.id) {
+            if !new.panes.iter().any(|p| p.id == Some(pane_id)) {
+                events.push(ServerStateEvent::PaneClosed { window_id, pane_id });
+            }
+        }
+
+        let active = |panes: &'_ [Pane]| {
+            panes
+                .iter()
+                .find(|p| p.active == Some(true))
+                .and_then(|p| p.id)
+        };
+        if let (Some(_), Some(pane_id)) = (old, active(&new.panes)) {
+            if active(old_panes) != Some(pane_id) {
+                events.push(ServerStateEvent::WindowPaneChanged { window_id, pane_id });
+            }
+        }
+    }
+
+    fn diff_clients(&self, new: &Snapshot, events: &mut Vec<ServerStateEvent>) {
+        for client in new.clients.iter() {
+            let tty = match client.tty {
+                Some(ref tty) => tty,
+                None => continue,
+            };
+            let session = match new.client_session(client) {
+                Some(session) => session,
+                None => continue,
+            };
+            let session_id = match session.id {
+                Some(id) => id,
+                None => continue,
+            };
+            match self.clients.iter().find(|c| c.tty.as_ref() == Some(tty)) {
+                None => events.push(ServerStateEvent::ClientAttached {
+                    client: ClientName::from(tty.as_str()),
+                    session_id,
+                }),
+                Some(old) => {
+                    if old.session != client.session {
+                        events.push(ServerStateEvent::ClientSessionChanged {
+                            client: ClientName::from(tty.as_str()),
+                            session_id,
+                            name: session.name.clone().unwrap_or_default(),
+                        });
+                    }
+                }
+            }
+        }
+
+        for tty in self.clients.iter().filter_map(|c| c.tty.as_ref()) {
+            if !new.clients.iter().any(|c| c.tty.as_ref() == Some(tty)) {
+                events.push(ServerStateEvent::ClientDetached(tty.as_str().into()));
+            }
+        }
+    }
+}
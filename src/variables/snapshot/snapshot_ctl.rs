@@ -0,0 +1,58 @@
+use crate::{
+    ClientsCtl, Error, Formats, ListPanes, PanesCtl, SessionsCtl, Snapshot, Tmux, TmuxCommand,
+    TmuxOutput, WindowsCtl,
+};
+use std::str::FromStr;
+
+pub struct SnapshotCtl<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+}
+
+impl<'a> Default for SnapshotCtl<'a> {
+    fn default() -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+        }
+    }
+}
+
+impl<'a> SnapshotCtl<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self { invoker }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    pub fn get(&self) -> Result<Snapshot, Error> {
+        Self::get_ext(self.invoker())
+    }
+
+    /// Sessions, windows and panes using one `list-panes -a`, clients using `list-clients`
+    pub fn get_ext(
+        invoker: impl Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<Snapshot, Error> {
+        let lsp_format = Self::formats().to_string();
+
+        let cmd = ListPanes::new().all().format(lsp_format).build();
+        let output = (invoker)(cmd)?.to_string();
+        let mut snapshot = Snapshot::from_str(&output)?;
+
+        snapshot.clients = ClientsCtl::get_all_ext(&invoker)?.0;
+        Ok(snapshot)
+    }
+
+    /// Formats of all session, window and pane variables (in this order), parsed by
+    /// [`Snapshot::from_str`]
+    pub fn formats() -> Formats {
+        let mut format = SessionsCtl::formats();
+        format.variables.extend(WindowsCtl::formats().variables);
+        format.variables.extend(PanesCtl::formats().variables);
+        format
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{
+    Client, FormatsOutput, PaneId, PanesCtl, SessionId, SessionsCtl, Snapshot, Variable, WindowId,
+    WindowsCtl,
+};
+use std::str::FromStr;
+
+// `list-panes -a` record, values of not given variables are empty
+fn record(
+    session: &[(Variable, &str)],
+    window: &[(Variable, &str)],
+    pane: &[(Variable, &str)],
+) -> String {
+    fn values<'a>(variables: &[Variable], given: &[(Variable, &'a str)]) -> Vec<&'a str> {
+        variables
+            .iter()
+            .map(|v| {
+                given
+                    .iter()
+                    .find(|(var, _)| var == v)
+                    .map(|(_, value)| *value)
+                    .unwrap_or("")
+            })
+            .collect()
+    }
+
+    let mut all = values(&SessionsCtl::formats().variables, session);
+    all.extend(values(&WindowsCtl::formats().variables, window));
+    all.extend(values(&PanesCtl::formats().variables, pane));
+    FormatsOutput::delimited_record(&all)
+}
+
+// $1 (alpha): @1 [%1, %2], @2 [%3]
+// $2 (beta): @3 [%4], @1 linked at index 5
+fn output() -> String {
+    let alpha = [
+        (Variable::SessionId, "$1"),
+        (Variable::SessionName, "alpha"),
+    ];
+    let beta = [(Variable::SessionId, "$2"), (Variable::SessionName, "beta")];
+    let window = |id, index, active| {
+        vec![
+            (Variable::WindowId, id),
+            (Variable::WindowIndex, index),
+            (Variable::WindowActive, active),
+        ]
+    };
+    let pane = |id| vec![(Variable::PaneId, id)];
+
+    [
+        record(&alpha, &window("@1", "0", "1"), &pane("%1")),
+        record(&alpha, &window("@1", "0", "1"), &pane("%2")),
+        record(&alpha, &window("@2", "1", "0"), &pane("%3")),
+        record(&beta, &window("@3", "0", "1"), &pane("%4")),
+        record(&beta, &window("@1", "5", "0"), &pane("%1")),
+        record(&beta, &window("@1", "5", "0"), &pane("%2")),
+    ]
+    .join("\n")
+}
+
+#[test]
+fn from_str() {
+    let snapshot = Snapshot::from_str(&output()).unwrap();
+
+    assert_eq!(snapshot.sessions.len(), 2);
+    assert_eq!(snapshot.windows.len(), 3);
+    assert_eq!(snapshot.links.len(), 4);
+    assert_eq!(snapshot.panes().count(), 4);
+
+    let window = snapshot.window(WindowId(1)).unwrap();
+    assert_eq!(
+        window.panes.iter().map(|p| p.id).collect::<Vec<_>>(),
+        vec![Some(PaneId(1)), Some(PaneId(2))]
+    );
+    assert_eq!(
+        snapshot.pane_window(PaneId(3)).unwrap().window.id,
+        Some(WindowId(2))
+    );
+    assert_eq!(
+        snapshot.session(SessionId(2)).unwrap().name,
+        Some("beta".to_string())
+    );
+}
+
+#[test]
+fn linked_window() {
+    let snapshot = Snapshot::from_str(&output()).unwrap();
+
+    let names = snapshot
+        .window_sessions(WindowId(1))
+        .iter()
+        .map(|s| s.name.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![Some("alpha".to_string()), Some("beta".to_string())]
+    );
+
+    // ordered by index in the session
+    let windows = snapshot
+        .session_windows(SessionId(2))
+        .iter()
+        .map(|w| w.window.id)
+        .collect::<Vec<_>>();
+    assert_eq!(windows, vec![Some(WindowId(3)), Some(WindowId(1))]);
+
+    let link = snapshot
+        .links
+        .iter()
+        .find(|l| l.session_id == SessionId(2) && l.window_id == WindowId(1))
+        .unwrap();
+    assert_eq!(link.index, Some(5));
+    assert_eq!(link.active, Some(false));
+}
+
+#[test]
+fn clients() {
+    let mut snapshot = Snapshot::from_str(&output()).unwrap();
+    snapshot.clients = vec![
+        Client {
+            session: Some("beta".to_string()),
+            ..Default::default()
+        },
+        Client::default(),
+    ];
+
+    assert_eq!(snapshot.session_clients(SessionId(2)).len(), 1);
+    assert!(snapshot.session_clients(SessionId(1)).is_empty());
+    assert_eq!(
+        snapshot.client_session(&snapshot.clients[0]).unwrap().id,
+        Some(SessionId(2))
+    );
+    assert!(snapshot.client_session(&snapshot.clients[1]).is_none());
+}
+
+#[test]
+fn from_str_missing_id() {
+    use crate::Error;
+
+    let output = record(
+        &[],
+        &[(Variable::WindowId, "@1")],
+        &[(Variable::PaneId, "%1")],
+    );
+    assert!(matches!(
+        Snapshot::from_str(&output),
+        Err(Error::ParseFormatsOutput)
+    ));
+}
+
+#[test]
+fn get() {
+    use crate::variables::test_session::TestSession;
+    use crate::SnapshotCtl;
+
+    const TEST_SESSION_NAME: &str = "snapshot_get";
+
+    let session = TestSession::new(TEST_SESSION_NAME);
+    let (session_id, window_id, pane_id) = (session.session_id, session.window_id, session.pane_id);
+
+    let snapshot = SnapshotCtl::new().get().unwrap();
+    let name = snapshot.session(session_id).unwrap().name.as_deref();
+    assert_eq!(name, Some(TEST_SESSION_NAME));
+    let windows = snapshot.session_windows(session_id);
+    assert_eq!(windows.len(), 1);
+    assert_eq!(windows[0].window.id, Some(window_id));
+    assert!(windows[0].panes.iter().any(|p| p.id == Some(pane_id)));
+}
+
+#[test]
+fn diff_added_closed() {
+    use crate::control_mode::server_state::ServerStateEvent;
+
+    let old = Snapshot::from_str(&output()).unwrap();
+    let mut new = old.clone();
+
+    // close session $2 (unlinks @1, closes @3 with %4), split %3 into %5
+    new.sessions.retain(|s| s.id != Some(SessionId(2)));
+    new.links.retain(|l| l.session_id != SessionId(2));
+    new.windows.retain(|w| w.window.id != Some(WindowId(3)));
+    let mut pane = new.pane(PaneId(3)).unwrap().clone();
+    pane.id = Some(PaneId(5));
+    new.windows[1].panes.push(pane);
+
+    assert_eq!(
+        old.diff(&new),
+        vec![
+            ServerStateEvent::PaneAdded {
+                window_id: WindowId(2),
+                pane_id: PaneId(5),
+            },
+            ServerStateEvent::PaneClosed {
+                window_id: WindowId(3),
+                pane_id: PaneId(4),
+            },
+            ServerStateEvent::WindowClosed {
+                session_id: SessionId(2),
+                window_id: WindowId(3),
+            },
+            ServerStateEvent::WindowClosed {
+                session_id: SessionId(2),
+                window_id: WindowId(1),
+            },
+            ServerStateEvent::SessionClosed(SessionId(2)),
+        ]
+    );
+    assert_eq!(new.diff(&old).len(), 5);
+    assert!(old.diff(&old).is_empty());
+}
+
+#[test]
+fn diff_changed() {
+    use crate::control_mode::server_state::ServerStateEvent;
+
+    let old = Snapshot::from_str(&output()).unwrap();
+    let mut new = old.clone();
+
+    new.sessions[0].name = Some("gamma".to_string());
+    // swap current window of $1, move @1 in $2
+    for link in new.links.iter_mut() {
+        match (link.session_id, link.window_id) {
+            (SessionId(1), window_id) => link.active = Some(window_id == WindowId(2)),
+            (SessionId(2), WindowId(1)) => link.index = Some(2),
+            _ => {}
+        }
+    }
+    new.windows[0].window.name = Some("vim".to_string());
+    let panes = &mut new.windows[0].panes;
+    panes[0].active = Some(false);
+    panes[1].active = Some(true);
+    panes[1].width = Some(40);
+    panes[1].height = Some(24);
+
+    assert_eq!(
+        old.diff(&new),
+        vec![
+            ServerStateEvent::SessionRenamed {
+                session_id: SessionId(1),
+                name: "gamma".to_string(),
+            },
+            ServerStateEvent::WindowMoved {
+                session_id: SessionId(2),
+                window_id: WindowId(1),
+                index: 2,
+            },
+            ServerStateEvent::SessionWindowChanged {
+                session_id: SessionId(1),
+                window_id: WindowId(2),
+            },
+            ServerStateEvent::PaneResized {
+                window_id: WindowId(1),
+                pane_id: PaneId(2),
+                width: 40,
+                height: 24,
+            },
+            ServerStateEvent::WindowPaneChanged {
+                window_id: WindowId(1),
+                pane_id: PaneId(2),
+            },
+            ServerStateEvent::WindowRenamed {
+                window_id: WindowId(1),
+                name: "vim".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn diff_clients() {
+    use crate::control_mode::server_state::ServerStateEvent;
+    use crate::ClientName;
+
+    let client = |tty: &str, session: &str| Client {
+        tty: Some(tty.to_string()),
+        session: Some(session.to_string()),
+        ..Default::default()
+    };
+
+    let mut old = Snapshot::from_str(&output()).unwrap();
+    let mut new = old.clone();
+    old.clients = vec![client("/dev/pts/1", "alpha"), client("/dev/pts/2", "alpha")];
+    new.clients = vec![client("/dev/pts/1", "beta"), client("/dev/pts/3", "alpha")];
+
+    assert_eq!(
+        old.diff(&new),
+        vec![
+            ServerStateEvent::ClientSessionChanged {
+                client: ClientName::from("/dev/pts/1"),
+                session_id: SessionId(2),
+                name: "beta".to_string(),
+            },
+            ServerStateEvent::ClientAttached {
+                client: ClientName::from("/dev/pts/3"),
+                session_id: SessionId(1),
+            },
+            ServerStateEvent::ClientDetached(ClientName::from("/dev/pts/2")),
+        ]
+    );
+}
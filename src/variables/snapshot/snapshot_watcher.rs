@@ -0,0 +1,83 @@
+use crate::control_mode::server_state::ServerStateEvent;
+use crate::{Error, Snapshot, SnapshotCtl, Tmux, TmuxCommand, TmuxOutput};
+use std::collections::VecDeque;
+use std::thread;
+use std::time::Duration;
+
+// Polling alternative of control mode notifications
+//
+// 1. get snapshot using `SnapshotCtl` every `interval`
+// 2. compare with the previous one (`Snapshot::diff`)
+// 3. emit changes as `ServerStateEvent`s (the first poll emits the whole server as added)
+
+/// Default interval between two polls
+pub const SNAPSHOT_WATCHER_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watcher of server changes polling [`Snapshot`]s
+pub struct SnapshotWatcher<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    pub interval: Duration,
+    pub snapshot: Snapshot,
+    polled: bool,
+    events: VecDeque<ServerStateEvent>,
+}
+
+impl<'a> Default for SnapshotWatcher<'a> {
+    fn default() -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+            interval: SNAPSHOT_WATCHER_INTERVAL,
+            snapshot: Snapshot::new(),
+            polled: false,
+            events: VecDeque::new(),
+        }
+    }
+}
+
+impl<'a> SnapshotWatcher<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self {
+            invoker,
+            ..Default::default()
+        }
+    }
+
+    /// Set interval between two polls
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Get a new snapshot (without waiting), returns changes since the previous one
+    pub fn poll(&mut self) -> Result<Vec<ServerStateEvent>, Error> {
+        let snapshot = SnapshotCtl::get_ext(self.invoker)?;
+        let events = self.snapshot.diff(&snapshot);
+        self.snapshot = snapshot;
+        self.polled = true;
+        Ok(events)
+    }
+}
+
+/// Endless iterator of changes, waits `interval` between polls returning no changes
+impl<'a> Iterator for SnapshotWatcher<'a> {
+    type Item = Result<ServerStateEvent, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Some(Ok(event));
+            }
+            if self.polled {
+                thread::sleep(self.interval);
+            }
+            match self.poll() {
+                Ok(events) => self.events.extend(events),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
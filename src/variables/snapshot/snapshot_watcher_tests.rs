@@ -0,0 +1,26 @@
+#[test]
+fn poll() {
+    use crate::control_mode::server_state::ServerStateEvent;
+    use crate::variables::test_session::TestSession;
+    use crate::SnapshotWatcher;
+    use std::time::Duration;
+
+    const TEST_SESSION_NAME: &str = "snapshot_watcher_poll";
+
+    let mut watcher = SnapshotWatcher::new().interval(Duration::from_millis(100));
+    watcher.poll().unwrap();
+
+    let session = TestSession::new(TEST_SESSION_NAME);
+    let session_id = session.session_id;
+
+    let events = watcher.poll().unwrap();
+    assert!(events.contains(&ServerStateEvent::SessionAdded(session_id)));
+
+    drop(session);
+
+    let event = watcher.next().unwrap().unwrap();
+    assert!(matches!(event, ServerStateEvent::PaneClosed { .. }));
+    assert!(watcher
+        .find(|e| matches!(e, Ok(ServerStateEvent::SessionClosed(id)) if *id == session_id))
+        .is_some());
+}
@@ -0,0 +1,60 @@
+use crate::{
+    KillSession, NewSession, PaneHandle, PaneId, SessionHandle, SessionId, Tmux, WindowHandle,
+    WindowId,
+};
+
+/// Detached session created by a test, killed on drop (also if the test panics)
+pub(crate) struct TestSession {
+    pub session_id: SessionId,
+    pub window_id: WindowId,
+    pub pane_id: PaneId,
+}
+
+impl TestSession {
+    pub fn new(session_name: &str) -> Self {
+        Self::create(NewSession::new().session_name(session_name))
+    }
+
+    /// Session running `shell_command` instead of the default shell
+    pub fn with_command(session_name: &str, shell_command: &str) -> Self {
+        Self::create(
+            NewSession::new()
+                .session_name(session_name)
+                .shell_command(shell_command),
+        )
+    }
+
+    fn create(cmd: NewSession) -> Self {
+        let cmd = cmd
+            .detached()
+            .print()
+            .format("#{session_id} #{window_id} #{pane_id}");
+        let output = Tmux::with_command(cmd).output().unwrap().to_string();
+        let ids: Vec<&str> = output.split_whitespace().collect();
+        assert_eq!(ids.len(), 3, "new-session output: {:?}", output);
+        TestSession {
+            session_id: ids[0].parse().unwrap(),
+            window_id: ids[1].parse().unwrap(),
+            pane_id: ids[2].parse().unwrap(),
+        }
+    }
+
+    pub fn session(&self) -> SessionHandle<'static> {
+        SessionHandle::new(self.session_id)
+    }
+
+    pub fn window(&self) -> WindowHandle<'static> {
+        WindowHandle::new(self.window_id)
+    }
+
+    pub fn pane(&self) -> PaneHandle<'static> {
+        PaneHandle::new(self.pane_id)
+    }
+}
+
+impl Drop for TestSession {
+    fn drop(&mut self) {
+        // the test may have killed the session already
+        let _ = Tmux::with_command(KillSession::new().target_session(self.session_id)).output();
+    }
+}
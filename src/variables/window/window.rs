@@ -1,13 +1,16 @@
+use crate::formats::format_value::FormatValue;
+use crate::Variable;
+#[cfg(feature = "tmux_1_7")]
+use crate::WindowId;
 use crate::{Error, FormatsOutput, Layout, WindowFlags};
 use std::str::FromStr;
 
 // NOTE: variables were first intoduced in tmux 1.6
 
-pub const WINDOW_VARS_SEPARATOR: char = '\'';
-
 // accordingly to tmux.h: Formats
 // XXX: check all types, optionality
 #[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Window {
     /// window_active - 1 if window active
     #[cfg(feature = "tmux_1_6")]
@@ -68,7 +71,7 @@ pub struct Window {
     pub height: Option<usize>,
     /// window_id - Unique window ID
     #[cfg(feature = "tmux_1_7")]
-    pub id: Option<usize>,
+    pub id: Option<WindowId>,
     /// window_index - #I Index of window
     #[cfg(feature = "tmux_1_6")]
     pub index: Option<usize>,
@@ -127,10 +130,19 @@ impl FromStr for Window {
 
     // XXX: mb deserialize like serde something?
     fn from_str(s: &str) -> Result<Self, Error> {
-        let mut window = Window::new();
-        let mut format = FormatsOutput::new();
+        Self::from_values(&FormatsOutput::delimited_values(s)?)
+    }
+}
+
+impl Window {
+    pub fn new() -> Self {
+        Default::default()
+    }
 
-        format.separator(WINDOW_VARS_SEPARATOR);
+    /// Parse values of one record of a [`WindowsCtl::formats`](crate::WindowsCtl::formats) query
+    pub fn from_values(values: &[&str]) -> Result<Self, Error> {
+        let mut window = Window::new();
+        let mut format = FormatsOutput::delimited();
 
         #[cfg(feature = "tmux_1_6")]
         format.window_active(&mut window.active);
@@ -208,13 +220,116 @@ impl FromStr for Window {
         #[cfg(feature = "tmux_2_0")]
         format.window_zoomed_flag(&mut window.zoomed_flag);
 
-        FormatsOutput::from_string_ext(s, &mut format);
+        FormatsOutput::from_values_ext(values, &mut format)?;
         Ok(window)
     }
-}
 
-impl Window {
-    pub fn new() -> Self {
-        Default::default()
+    /// Value of the window `variable`, as it would be expanded by tmux
+    pub fn value(&self, variable: &Variable) -> Option<String> {
+        match variable {
+            #[cfg(feature = "tmux_1_6")]
+            Variable::WindowActive => self.active.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::WindowActiveClients => {
+                self.active_clients.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_3_1")]
+            Variable::WindowActiveClientsList => self
+                .active_clients_list
+                .as_ref()
+                .map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::WindowActiveSessions => {
+                self.active_sessions.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_3_1")]
+            Variable::WindowActiveSessionsList => self
+                .active_sessions_list
+                .as_ref()
+                .map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_1")]
+            Variable::WindowActivity => self.activity.as_ref().map(FormatValue::format_value),
+            #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_2_2")))]
+            Variable::WindowActivityString => {
+                self.activity_string.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(any(
+                all(feature = "tmux_1_9", not(feature = "tmux_2_2")),
+                feature = "tmux_2_3"
+            ))]
+            Variable::WindowActivityFlag => {
+                self.activity_flag.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_1_9")]
+            Variable::WindowBellFlag => self.bell_flag.as_ref().map(FormatValue::format_value),
+            #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
+            Variable::WindowContentFlag => {
+                self.content_flag.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_2_9")]
+            Variable::WindowBigger => self.bigger.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::WindowCellHeight => self.cell_height.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::WindowCellWidth => self.cell_width.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_9")]
+            Variable::WindowEndFlag => self.end_flag.as_ref().map(FormatValue::format_value),
+            #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_2_6")))]
+            Variable::WindowFindMatches => {
+                self.find_matches.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_1_6")]
+            Variable::WindowFlags => self.flags.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_6")]
+            Variable::WindowFormat => self.format.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::WindowHeight => self.height.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_7")]
+            Variable::WindowId => self.id.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::WindowIndex => self.index.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_0")]
+            Variable::WindowLastFlag => self.last_flag.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::WindowLayout => self.layout.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_1")]
+            Variable::WindowLinked => self.linked.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::WindowLinkedSessions => {
+                self.linked_sessions.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_3_1")]
+            Variable::WindowLinkedSessionsList => self
+                .linked_sessions_list
+                .as_ref()
+                .map(FormatValue::format_value),
+            #[cfg(feature = "tmux_3_1")]
+            Variable::WindowMarkedFlag => self.marked_flag.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_6")]
+            Variable::WindowName => self.name.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_9")]
+            Variable::WindowOffsetX => self.offset_x.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_9")]
+            Variable::WindowOffsetY => self.offset_y.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_7")]
+            Variable::WindowPanes => self.panes.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_1_9")]
+            Variable::WindowSilenceFlag => {
+                self.silence_flag.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_2_5")]
+            Variable::WindowStackIndex => self.stack_index.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_9")]
+            Variable::WindowStartFlag => self.start_flag.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_2")]
+            Variable::WindowVisibleLayout => {
+                self.visible_layout.as_ref().map(FormatValue::format_value)
+            }
+            #[cfg(feature = "tmux_1_6")]
+            Variable::WindowWidth => self.width.as_ref().map(FormatValue::format_value),
+            #[cfg(feature = "tmux_2_0")]
+            Variable::WindowZoomedFlag => self.zoomed_flag.as_ref().map(FormatValue::format_value),
+            _ => None,
+        }
     }
 }
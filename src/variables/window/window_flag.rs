@@ -1,4 +1,5 @@
 use crate::Error;
+use std::fmt;
 use std::str::FromStr;
 
 const WINDOW_FLAG_DEFAULT: usize = 0b0000_0000;
@@ -41,3 +42,24 @@ impl FromStr for WindowFlags {
         Ok(wf)
     }
 }
+
+// same order as tmux `window_printable_flags()`
+impl fmt::Display for WindowFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let flags = [
+            (WINDOW_FLAG_ACTIVITY, '#'),
+            (WINDOW_FLAG_BELL, '!'),
+            (WINDOW_FLAG_SILENCED, '~'),
+            (WINDOW_FLAG_CURRENT, '*'),
+            (WINDOW_FLAG_LAST, '-'),
+            (WINDOW_FLAG_MARKED, 'M'),
+            (WINDOW_FLAG_ZOOMED, 'Z'),
+        ];
+        for (flag, c) in flags.iter() {
+            if self.0 & flag != 0 {
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -11,7 +11,7 @@ fn show_generated_struct() {
 //let window_str = "1557947146'0'1'0'''*'1'64'@0'4'0'3484,177x64,0,0{88x64,0,0,3,88x64,89,0,18}'0'bash'''2'0'0''3484,177x64,0,0{88x64,0,0,3,88x64,89,0,18}'177'0";
 #[test]
 fn parse1() {
-    use crate::Window;
+    use crate::{FormatsOutput, Window, WindowId};
     use std::str::FromStr;
 
     let window_vec = vec![
@@ -127,17 +127,17 @@ fn parse1() {
         #[cfg(feature = "tmux_2_0")]
         "0",
     ];
-    let window_str = window_vec.join("'");
+    let window_str = FormatsOutput::delimited_record(&window_vec);
 
     let window = Window::from_str(&window_str).unwrap();
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(window.id, Some(0));
+    assert_eq!(window.id, Some(WindowId(0)));
 }
 
 //let window_str = "1557947146'0'0'0'''*'1'64'@1'1'0'c3bd,177x64,0,0,0'0'bash'''1'0'2''c3bd,177x64,0,0,0'177'0";
 #[test]
 fn parse2() {
-    use crate::Window;
+    use crate::{FormatsOutput, Window, WindowId};
     use std::str::FromStr;
 
     let window_vec = vec![
@@ -253,18 +253,18 @@ fn parse2() {
         #[cfg(feature = "tmux_2_0")]
         "0",
     ];
-    let window_str = window_vec.join("'");
+    let window_str = FormatsOutput::delimited_record(&window_vec);
     let window = Window::from_str(&window_str).unwrap();
     #[cfg(feature = "tmux_1_6")]
     assert_eq!(window.name, Some("bash".to_string()));
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(window.id, Some(1));
+    assert_eq!(window.id, Some(WindowId(1)));
 }
 
 //let window_str = "1557947146'0'0'0''''1'64'@2'1'0'c3bd,177x64,0,0,0'0'bash'''1'0'3''c3bd,177x64,0,0,0'177'0";
 #[test]
 fn parse3() {
-    use crate::Window;
+    use crate::{FormatsOutput, Window, WindowId};
     use std::str::FromStr;
 
     let window_vec = vec![
@@ -380,18 +380,18 @@ fn parse3() {
         #[cfg(feature = "tmux_2_0")]
         "0",
     ];
-    let window_str = window_vec.join("'");
+    let window_str = FormatsOutput::delimited_record(&window_vec);
     let window = Window::from_str(&window_str).unwrap();
     #[cfg(feature = "tmux_1_6")]
     assert_eq!(window.name, Some("bash".to_string()));
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(window.id, Some(2));
+    assert_eq!(window.id, Some(WindowId(2)));
 }
 
 //let window_str = "1557947146'0'0'0''''1'64'@3'2'0'8b65,177x64,0,0[177x46,0,0,1,177x17,0,47,4]'0'vim'''2'0'2''8b65,177x64,0,0[177x46,0,0,1,177x17,0,47,4]'177'0";
 #[test]
 fn parse4() {
-    use crate::Window;
+    use crate::{FormatsOutput, Window, WindowId};
     use std::str::FromStr;
 
     let window_vec = vec![
@@ -507,16 +507,16 @@ fn parse4() {
         #[cfg(feature = "tmux_2_0")]
         "0",
     ];
-    let window_str = window_vec.join("'");
+    let window_str = FormatsOutput::delimited_record(&window_vec);
     let window = Window::from_str(&window_str).unwrap();
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(window.id, Some(3));
+    assert_eq!(window.id, Some(WindowId(3)));
 }
 
 //let window_str = "1557947146'0'0'0'''-'1'64'@4'3'1'7966,177x64,0,0[177x52,0,0,2,177x11,0,53,3]'0'vim'''2'0'1''7966,177x64,0,0[177x52,0,0,2,177x11,0,53,3]'177'0";
 #[test]
 fn parse5() {
-    use crate::Window;
+    use crate::{FormatsOutput, Window, WindowId};
     use std::str::FromStr;
 
     let window_vec = vec![
@@ -632,16 +632,16 @@ fn parse5() {
         #[cfg(feature = "tmux_2_0")]
         "0",
     ];
-    let window_str = window_vec.join("'");
+    let window_str = FormatsOutput::delimited_record(&window_vec);
     let window = Window::from_str(&window_str).unwrap();
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(window.id, Some(4));
+    assert_eq!(window.id, Some(WindowId(4)));
 }
 
 //let window_str = "1557947146'0'1'0'''*'1'64'@5'4'0'c3c3,177x64,0,0,6'0'bash'''1'0'0''c3c3,177x64,0,0,6'177'0";
 #[test]
 fn parse6() {
-    use crate::Window;
+    use crate::{FormatsOutput, Window, WindowId};
     use std::str::FromStr;
 
     let window_vec = vec![
@@ -757,10 +757,10 @@ fn parse6() {
         #[cfg(feature = "tmux_2_0")]
         "0",
     ];
-    let window_str = window_vec.join("'");
+    let window_str = FormatsOutput::delimited_record(&window_vec);
     let window = Window::from_str(&window_str).unwrap();
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(window.id, Some(5));
+    assert_eq!(window.id, Some(WindowId(5)));
 }
 
 //#[test]
@@ -773,3 +773,12 @@ fn parse6() {
 //assert_eq!(window.name, "asdf");
 //assert_eq!(window.id, 0);
 //}
+
+#[test]
+fn window_flags_to_string() {
+    use crate::WindowFlags;
+
+    let flags: WindowFlags = "Z*#".parse().unwrap();
+    assert_eq!(flags.to_string(), "#*Z");
+    assert_eq!(WindowFlags::default().to_string(), "");
+}
@@ -1,4 +1,4 @@
-use crate::{Error, Window};
+use crate::{Error, FormatsOutput, Window};
 use std::ops::Index;
 use std::str::FromStr;
 
@@ -27,8 +27,8 @@ impl FromStr for Windows {
 
     fn from_str(s: &str) -> Result<Self, Error> {
         let mut windows = Windows::new();
-        for line in s.lines() {
-            windows.push(Window::from_str(line)?);
+        for record in FormatsOutput::delimited_records(s)? {
+            windows.push(Window::from_str(record)?);
         }
         Ok(windows)
     }
@@ -1,6 +1,7 @@
-use crate::variables::window::window::WINDOW_VARS_SEPARATOR;
-use crate::{Error, Formats, ListWindows, Tmux, TmuxCommand, TmuxOutput, Windows};
-use std::borrow::Cow;
+use crate::{
+    Error, Formats, FormatsOutput, IntoTargetSession, ListWindows, Tmux, TmuxCommand, TmuxOutput,
+    Variable, Window, Windows,
+};
 use std::str::FromStr;
 
 // trait top level options, then server session window pane
@@ -34,11 +35,11 @@ impl<'a> WindowsCtl<'a> {
         self.invoker
     }
 
-    pub fn get<S: Into<Cow<'a, str>>>(&self, target_session: Option<S>) -> Result<Windows, Error> {
+    pub fn get<S: IntoTargetSession<'a>>(&self, target_session: Option<S>) -> Result<Windows, Error> {
         Self::get_all_ext(target_session, self.invoker())
     }
 
-    pub fn get_ext<S: Into<Cow<'a, str>>>(
+    pub fn get_ext<S: IntoTargetSession<'a>>(
         target_session: Option<S>,
         invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
     ) -> Result<Windows, Error> {
@@ -49,12 +50,58 @@ impl<'a> WindowsCtl<'a> {
         Self::get_all_ext(None::<&str>, self.invoker())
     }
 
-    pub fn get_all_ext<S: Into<Cow<'a, str>>>(
+    pub fn get_all_ext<S: IntoTargetSession<'a>>(
         target_session: Option<S>,
         invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
     ) -> Result<Windows, Error> {
-        let mut format = Formats::new();
-        format.separator(WINDOW_VARS_SEPARATOR);
+        let lsw_format = Self::formats().to_string();
+
+        let cmd = ListWindows::new().format(lsw_format);
+        let cmd = match target_session {
+            Some(target_session) => cmd.target_session(target_session),
+            None => cmd.all(),
+        };
+        let cmd = cmd.build();
+
+        let output = (invoker)(cmd)?.to_string();
+        Windows::from_str(&output)
+    }
+
+    /// Windows with values of additional variables (e.g. [`Variable::Custom`]), in their order
+    pub fn get_with<S: IntoTargetSession<'a>>(
+        &self,
+        target_session: Option<S>,
+        variables: &[Variable],
+    ) -> Result<Vec<(Window, Vec<String>)>, Error> {
+        Self::get_all_with_ext(target_session, variables, self.invoker())
+    }
+
+    pub fn get_all_with(&self, variables: &[Variable]) -> Result<Vec<(Window, Vec<String>)>, Error> {
+        Self::get_all_with_ext(None::<&str>, variables, self.invoker())
+    }
+
+    pub fn get_all_with_ext<S: IntoTargetSession<'a>>(
+        target_session: Option<S>,
+        variables: &[Variable],
+        invoker: impl FnOnce(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<Vec<(Window, Vec<String>)>, Error> {
+        let mut format = Self::formats();
+        let len = format.variables.len();
+        format.variables.extend_from_slice(variables);
+
+        let cmd = ListWindows::new().format(format.to_string());
+        let cmd = match target_session {
+            Some(target_session) => cmd.target_session(target_session),
+            None => cmd.all(),
+        };
+
+        let output = (invoker)(cmd.build())?.to_string();
+        FormatsOutput::parse_records_with(&output, len, variables.len(), Window::from_values)
+    }
+
+    /// Formats of all window variables, parsed by [`Window::from_values`]
+    pub fn formats() -> Formats {
+        let mut format = Formats::delimited();
 
         #[cfg(feature = "tmux_1_6")]
         format.window_active();
@@ -132,16 +179,6 @@ impl<'a> WindowsCtl<'a> {
         #[cfg(feature = "tmux_2_0")]
         format.window_zoomed_flag();
 
-        let lsw_format = format.to_string();
-
-        let cmd = ListWindows::new().format(lsw_format);
-        let cmd = match target_session {
-            Some(target_session) => cmd.target_session(target_session),
-            None => cmd.all(),
-        };
-        let cmd = cmd.build();
-
-        let output = (invoker)(cmd)?.to_string();
-        Windows::from_str(&output)
+        format
     }
 }
@@ -5,3 +5,29 @@ fn get() {
     let windows = WindowsCtl::default().get_all().unwrap();
     dbg!(windows);
 }
+
+#[test]
+fn get_all_with() {
+    use crate::variables::test_session::TestSession;
+    use crate::{Variable, WindowsCtl};
+
+    const TEST_SESSION_NAME: &str = "windows_ctl_get_all_with";
+
+    let session = TestSession::new(TEST_SESSION_NAME);
+    let window_id = session.window_id;
+
+    let variables = [
+        Variable::Custom("@windows_ctl_unset".to_string()),
+        Variable::Custom("#{window_id}:#{window_index}".to_string()),
+    ];
+    let windows = WindowsCtl::default().get_all_with(&variables).unwrap();
+    let (window, values) = windows
+        .iter()
+        .find(|(window, _)| window.id == Some(window_id))
+        .unwrap();
+    assert_eq!(values[0], "");
+    assert_eq!(
+        values[1],
+        format!("{}:{}", window_id, window.index.unwrap())
+    );
+}
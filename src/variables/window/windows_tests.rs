@@ -1,6 +1,6 @@
 #[test]
 fn parse() {
-    use crate::Windows;
+    use crate::{FormatsOutput, WindowId, Windows};
     use std::str::FromStr;
 
     //let windows_str = "
@@ -233,21 +233,21 @@ fn parse() {
         #[cfg(feature = "tmux_2_0")]
         "0",
     ];
-    let window0_str = window0_vec.join("'");
-    let window1_str = window1_vec.join("'");
+    let window0_str = FormatsOutput::delimited_record(&window0_vec);
+    let window1_str = FormatsOutput::delimited_record(&window1_vec);
     let windows_str = format!("{}\n{}", window0_str, window1_str);
     let windows = Windows::from_str(&windows_str).unwrap();
     dbg!(&windows);
 
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(windows[0].id, Some(0));
+    assert_eq!(windows[0].id, Some(WindowId(0)));
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(windows[1].id, Some(1));
+    assert_eq!(windows[1].id, Some(WindowId(1)));
 }
 
 #[test]
 fn parse2() {
-    use crate::Windows;
+    use crate::{FormatsOutput, WindowId, Windows};
     use std::str::FromStr;
 
     //let windows_str = "
@@ -708,21 +708,21 @@ fn parse2() {
         #[cfg(feature = "tmux_2_0")]
         "0",
     ];
-    let window0_str = window0_vec.join("'");
-    let window1_str = window1_vec.join("'");
-    let window2_str = window2_vec.join("'");
-    let window3_str = window3_vec.join("'");
+    let window0_str = FormatsOutput::delimited_record(&window0_vec);
+    let window1_str = FormatsOutput::delimited_record(&window1_vec);
+    let window2_str = FormatsOutput::delimited_record(&window2_vec);
+    let window3_str = FormatsOutput::delimited_record(&window3_vec);
     let windows_str = format!(
         "{}\n{}\n{}\n{}",
         window0_str, window1_str, window2_str, window3_str
     );
     let windows = Windows::from_str(&windows_str).unwrap();
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(windows[0].id, Some(0));
+    assert_eq!(windows[0].id, Some(WindowId(0)));
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(windows[1].id, Some(1));
+    assert_eq!(windows[1].id, Some(WindowId(1)));
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(windows[2].id, Some(2));
+    assert_eq!(windows[2].id, Some(WindowId(2)));
     #[cfg(feature = "tmux_1_7")]
-    assert_eq!(windows[3].id, Some(3));
+    assert_eq!(windows[3].id, Some(WindowId(3)));
 }
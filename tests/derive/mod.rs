@@ -0,0 +1 @@
+mod tmux_format_tests;
@@ -0,0 +1,84 @@
+use tmux_interface::{PaneId, TmuxFormat};
+
+#[derive(TmuxFormat, Debug, PartialEq)]
+pub struct MyPane {
+    /// doc comments are ignored
+    pub pane_id: PaneId,
+    #[tmux(variable = PaneCurrentCommand)]
+    pub command: String,
+    #[tmux(option = "@derive_test")]
+    pub project: Option<String>,
+    #[tmux(format = "#{pane_width}x#{pane_height}")]
+    pub size: String,
+    #[tmux(skip)]
+    pub note: Vec<String>,
+    pub session_alerts: Vec<usize>,
+}
+
+#[test]
+fn formats() {
+    use tmux_interface::FormatQuery;
+
+    assert_eq!(
+        MyPane::formats(),
+        vec![
+            "#{pane_id}",
+            "#{pane_current_command}",
+            "#{@derive_test}",
+            "#{pane_width}x#{pane_height}",
+            "#{session_alerts}",
+        ]
+    );
+}
+
+#[test]
+fn from_record() {
+    use tmux_interface::{FormatQuery, FormatsOutput};
+
+    let record = FormatsOutput::delimited_record(&["%3", "vim", "", "80x24", "1,2"]);
+    assert_eq!(
+        MyPane::from_record(&record).unwrap(),
+        MyPane {
+            pane_id: PaneId(3),
+            command: "vim".to_string(),
+            project: None,
+            size: "80x24".to_string(),
+            note: Vec::new(),
+            session_alerts: vec![1, 2],
+        }
+    );
+
+    let record = FormatsOutput::delimited_record(&["%3", "vim"]);
+    assert!(MyPane::from_record(&record).is_err());
+}
+
+#[cfg(feature = "tmux_1_6")]
+#[test]
+fn get_panes() {
+    use tmux_interface::{KillSession, NewSession, QueryCtl, SetOption, Tmux};
+
+    const TARGET_SESSION: &str = "derive_get_panes_test";
+
+    Tmux::with_command(NewSession::new().detached().session_name(TARGET_SESSION))
+        .output()
+        .unwrap();
+    Tmux::with_command(
+        SetOption::new()
+            .target_pane(TARGET_SESSION)
+            .option("@derive_test")
+            .value("a:b'c"),
+    )
+    .output()
+    .unwrap();
+
+    let panes: Vec<MyPane> = QueryCtl::new().get_panes(Some(TARGET_SESSION)).unwrap();
+    assert_eq!(panes.len(), 1);
+    assert_eq!(panes[0].project, Some("a:b'c".to_string()));
+
+    let pane: MyPane = QueryCtl::new().get(Some(TARGET_SESSION)).unwrap();
+    assert_eq!(pane, panes[0]);
+
+    Tmux::with_command(KillSession::new().target_session(TARGET_SESSION))
+        .output()
+        .unwrap();
+}
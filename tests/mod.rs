@@ -1,6 +1,10 @@
 mod control_mode;
+#[cfg(feature = "derive")]
+mod derive;
 #[cfg(feature = "tmux_stable")]
 mod examples;
 mod issues;
 mod options_ctl;
+#[cfg(feature = "serde")]
+mod serde;
 mod variables_ctl;
@@ -0,0 +1 @@
+mod serde_tests;
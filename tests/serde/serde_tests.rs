@@ -0,0 +1,119 @@
+#[test]
+fn tmux_string() {
+    use tmux_interface::{PaneId, SessionId, Switch, WindowFlags, WindowId};
+
+    assert_eq!(serde_json::to_string(&SessionId(1)).unwrap(), r#""$1""#);
+    assert_eq!(serde_json::to_string(&WindowId(2)).unwrap(), r#""@2""#);
+    assert_eq!(serde_json::to_string(&PaneId(3)).unwrap(), r#""%3""#);
+    assert_eq!(serde_json::to_string(&Switch::On).unwrap(), r#""on""#);
+    assert_eq!(
+        serde_json::from_str::<Switch>(r#""off""#).unwrap(),
+        Switch::Off
+    );
+
+    let flags: WindowFlags = "*Z".parse().unwrap();
+    let json = serde_json::to_string(&flags).unwrap();
+    assert_eq!(json, r#""*Z""#);
+    assert_eq!(serde_json::from_str::<WindowFlags>(&json).unwrap(), flags);
+
+    assert!(serde_json::from_str::<SessionId>(r#""@1""#).is_err());
+    assert!(serde_json::from_str::<Switch>("1").is_err());
+}
+
+#[test]
+fn variables() {
+    use tmux_interface::{
+        Buffer, Client, Layout, Pane, PaneId, Session, SessionId, SessionStack, Window, WindowId,
+    };
+
+    let mut session = Session::new();
+    session.id = Some(SessionId(1));
+    session.name = Some("session".to_string());
+    session.stack = Some(SessionStack(vec![2, 1]));
+    let json = serde_json::to_string(&session).unwrap();
+    assert!(json.contains(r#""id":"$1""#));
+    assert!(json.contains(r#""stack":[2,1]"#));
+    assert_eq!(serde_json::from_str::<Session>(&json).unwrap(), session);
+
+    let mut window = Window::new();
+    window.id = Some(WindowId(2));
+    window.layout = Some(
+        "c8b4,80x24,0,0[80x12,0,0,3,80x11,0,13,4]"
+            .parse::<Layout>()
+            .unwrap(),
+    );
+    let json = serde_json::to_string(&window).unwrap();
+    assert!(json.contains(r#""style":"top_bottom""#));
+    assert_eq!(serde_json::from_str::<Window>(&json).unwrap(), window);
+
+    let mut pane = Pane::new();
+    pane.id = Some(PaneId(3));
+    pane.active = Some(true);
+    let json = serde_json::to_string(&pane).unwrap();
+    assert_eq!(serde_json::from_str::<Pane>(&json).unwrap(), pane);
+
+    let client = Client {
+        tty: Some("/dev/pts/1".to_string()),
+        ..Default::default()
+    };
+    let json = serde_json::to_string(&client).unwrap();
+    assert_eq!(serde_json::from_str::<Client>(&json).unwrap(), client);
+
+    let buffer = Buffer {
+        name: Some("buffer0".to_string()),
+        ..Default::default()
+    };
+    let json = serde_json::to_string(&buffer).unwrap();
+    assert_eq!(serde_json::from_str::<Buffer>(&json).unwrap(), buffer);
+}
+
+#[cfg(feature = "tmux_3_1")]
+#[test]
+fn options() {
+    use std::borrow::Cow;
+    use tmux_interface::{
+        PaneOptions, RemainOnExit, ServerOptions, SessionOptions, SetClipboard, Status,
+        WindowOptions, WindowSize,
+    };
+
+    let mut session_options = SessionOptions::default();
+    session_options.status = Some(Status::TwoRows);
+    session_options
+        .user_options
+        .insert("@project".to_string(), Some(Cow::Borrowed("dashboard")));
+    let json = serde_json::to_string(&session_options).unwrap();
+    assert!(json.contains(r#""status":"2""#));
+    assert_eq!(
+        serde_json::from_str::<SessionOptions>(&json).unwrap(),
+        session_options
+    );
+
+    // missing fields are default
+    let session_options: SessionOptions = serde_json::from_str(r#"{"status":"off"}"#).unwrap();
+    assert_eq!(session_options.status, Some(Status::Off));
+    assert_eq!(session_options.user_options.len(), 0);
+
+    let mut server_options = ServerOptions::default();
+    server_options.set_clipboard = Some(SetClipboard::External);
+    let json = serde_json::to_string(&server_options).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ServerOptions>(&json).unwrap(),
+        server_options
+    );
+
+    let mut window_options = WindowOptions::default();
+    window_options.window_size = Some(WindowSize::Latest);
+    let json = serde_json::to_string(&window_options).unwrap();
+    assert_eq!(
+        serde_json::from_str::<WindowOptions>(&json).unwrap(),
+        window_options
+    );
+
+    let mut pane_options = PaneOptions::default();
+    pane_options.remain_on_exit = Some(RemainOnExit::Failed);
+    let json = serde_json::to_string(&pane_options).unwrap();
+    assert_eq!(
+        serde_json::from_str::<PaneOptions>(&json).unwrap(),
+        pane_options
+    );
+}
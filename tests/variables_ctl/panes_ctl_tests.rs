@@ -24,3 +24,42 @@ fn get_panes() {
         .output()
         .unwrap();
 }
+
+#[cfg(feature = "tmux_3_0a")]
+#[test]
+fn get_panes_with() {
+    use tmux_interface::{KillSession, NewSession, PanesCtl, SetOption, Tmux, Variable};
+
+    const TARGET_SESSION: &str = "get_panes_with_test";
+
+    Tmux::with_command(NewSession::new().detached().session_name(TARGET_SESSION))
+        .output()
+        .unwrap();
+    Tmux::with_command(
+        SetOption::new()
+            .target_pane(TARGET_SESSION)
+            .option("@my_state")
+            .value("a:b'c"),
+    )
+    .output()
+    .unwrap();
+
+    let variables = [
+        Variable::Custom("@my_state".to_string()),
+        Variable::Custom("#{session_name}/#{window_index}".to_string()),
+        Variable::SessionName,
+    ];
+    let panes = PanesCtl::new()
+        .get_with(Some(TARGET_SESSION), &variables)
+        .unwrap();
+    assert_eq!(panes.len(), 1);
+    let (pane, values) = &panes[0];
+    assert!(pane.id.is_some());
+    assert_eq!(values[0], "a:b'c");
+    assert_eq!(values[1], format!("{}/0", TARGET_SESSION));
+    assert_eq!(values[2], TARGET_SESSION);
+
+    Tmux::with_command(KillSession::new().target_session(TARGET_SESSION))
+        .output()
+        .unwrap();
+}
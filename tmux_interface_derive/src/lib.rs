@@ -0,0 +1,275 @@
+//! Derive macros of the [tmux_interface](https://crates.io/crates/tmux_interface) crate,
+//! enabled by its `derive` feature
+//!
+//! `#[derive(TmuxFormat)]` implements `tmux_interface::FormatQuery` for a struct with named
+//! fields, each field is queried using:
+//!
+//! * the tmux variable named as the field, by default
+//! * `#[tmux(variable = PaneId)]` - the given `tmux_interface::Variable`
+//! * `#[tmux(option = "@user_option")]` - the value of an option (e.g. user option)
+//! * `#[tmux(format = "#{pane_width}x#{pane_height}")]` - a format expression
+//! * `#[tmux(skip)]` - not queried, `Default::default()` is used
+//!
+//! Field types must implement `tmux_interface::FromFormatValue`
+//!
+//! ```text
+//! use tmux_interface::{PaneId, QueryCtl, TmuxFormat};
+//!
+//! #[derive(TmuxFormat)]
+//! struct MyPane {
+//!     pane_id: PaneId,
+//!     #[tmux(variable = PaneCurrentCommand)]
+//!     command: String,
+//!     #[tmux(option = "@project")]
+//!     project: Option<String>,
+//!     #[tmux(format = "#{pane_width}x#{pane_height}")]
+//!     size: String,
+//! }
+//!
+//! let panes: Vec<MyPane> = QueryCtl::new().get_panes(None::<&str>).unwrap();
+//! ```
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+type ParseError = (Span, String);
+
+#[proc_macro_derive(TmuxFormat, attributes(tmux))]
+pub fn derive_tmux_format(input: TokenStream) -> TokenStream {
+    match parse_struct(input) {
+        Ok((name, fields)) => expand(&name, &fields)
+            .parse()
+            .expect("TmuxFormat generated invalid code"),
+        Err((span, msg)) => compile_error(span, &msg),
+    }
+}
+
+enum FieldFormat {
+    // variable named as the field
+    Name,
+    Variable(String),
+    Option(String),
+    Format(String),
+    Skip,
+}
+
+struct Field {
+    name: String,
+    ty: String,
+    format: FieldFormat,
+}
+
+// name and fields of `[attrs] [vis] struct Name { [attrs] [vis] field: Type, ... }`
+fn parse_struct(input: TokenStream) -> Result<(String, Vec<Field>), ParseError> {
+    let mut tokens = input.into_iter();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ref ident) if ident.to_string() == "struct" => {
+                let name = match tokens.next() {
+                    Some(TokenTree::Ident(name)) => name,
+                    _ => return Err((ident.span(), "expected struct name".to_string())),
+                };
+                return match tokens.next() {
+                    Some(TokenTree::Group(ref body)) if body.delimiter() == Delimiter::Brace => {
+                        Ok((name.to_string(), parse_fields(body.stream())?))
+                    }
+                    _ => Err((
+                        name.span(),
+                        "TmuxFormat supports structs with named fields and without generics only"
+                            .to_string(),
+                    )),
+                };
+            }
+            TokenTree::Ident(ref ident) if ident.to_string() == "enum" => {
+                return Err((ident.span(), "TmuxFormat supports structs only".to_string()));
+            }
+            TokenTree::Ident(ref ident) if ident.to_string() == "union" => {
+                return Err((ident.span(), "TmuxFormat supports structs only".to_string()));
+            }
+            // attributes, visibility
+            _ => {}
+        }
+    }
+    Err((Span::call_site(), "expected struct".to_string()))
+}
+
+fn parse_fields(body: TokenStream) -> Result<Vec<Field>, ParseError> {
+    let mut fields = Vec::new();
+    let mut field = Vec::new();
+    // `,` inside of generic arguments does not separate fields
+    let mut depth = 0;
+    let mut arrow = false;
+    for token in body {
+        match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == ',' && depth == 0 => {
+                fields.push(parse_field(field)?);
+                field = Vec::new();
+                continue;
+            }
+            TokenTree::Punct(ref punct) if punct.as_char() == '<' => depth += 1,
+            TokenTree::Punct(ref punct) if punct.as_char() == '>' && !arrow => depth -= 1,
+            _ => {}
+        }
+        arrow = matches!(token, TokenTree::Punct(ref punct) if punct.as_char() == '-');
+        field.push(token);
+    }
+    if !field.is_empty() {
+        fields.push(parse_field(field)?);
+    }
+    Ok(fields)
+}
+
+// `[attrs] [vis] name: Type`
+fn parse_field(tokens: Vec<TokenTree>) -> Result<Field, ParseError> {
+    let mut format = FieldFormat::Name;
+    let mut tokens = tokens.into_iter().peekable();
+    let name = loop {
+        match tokens.next() {
+            Some(TokenTree::Punct(ref punct)) if punct.as_char() == '#' => {
+                if let Some(TokenTree::Group(attr)) = tokens.next() {
+                    if let Some(attr_format) = parse_attr(attr.stream())? {
+                        format = attr_format;
+                    }
+                }
+            }
+            Some(TokenTree::Ident(ref ident)) if ident.to_string() == "pub" => {
+                if let Some(TokenTree::Group(_)) = tokens.peek() {
+                    tokens.next();
+                }
+            }
+            Some(TokenTree::Ident(name)) => break name,
+            Some(token) => return Err((token.span(), "expected field name".to_string())),
+            None => return Err((Span::call_site(), "expected field name".to_string())),
+        }
+    };
+    match tokens.next() {
+        Some(TokenTree::Punct(ref punct)) if punct.as_char() == ':' => {}
+        _ => return Err((name.span(), "expected `:` after field name".to_string())),
+    }
+    let name = name.to_string();
+    Ok(Field {
+        name: name.trim_start_matches("r#").to_string(),
+        ty: tokens.collect::<TokenStream>().to_string(),
+        format,
+    })
+}
+
+// `tmux(...)` attribute, other attributes are ignored
+fn parse_attr(attr: TokenStream) -> Result<Option<FieldFormat>, ParseError> {
+    let mut tokens = attr.into_iter();
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Ident(ref ident)), Some(TokenTree::Group(args)))
+            if ident.to_string() == "tmux" =>
+        {
+            parse_attr_args(args).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+// `skip`, `variable = Ident`, `option = "..."` or `format = "..."`
+fn parse_attr_args(args: Group) -> Result<FieldFormat, ParseError> {
+    let tokens = args.stream().into_iter().collect::<Vec<TokenTree>>();
+    let unexpected = |span| {
+        Err((
+            span,
+            "expected `skip`, `variable = Variable`, `option = \"...\"` or `format = \"...\"`"
+                .to_string(),
+        ))
+    };
+    match tokens.as_slice() {
+        [TokenTree::Ident(key)] if key.to_string() == "skip" => Ok(FieldFormat::Skip),
+        [TokenTree::Ident(key), TokenTree::Punct(eq), value] if eq.as_char() == '=' => {
+            match (key.to_string().as_str(), value) {
+                ("variable", TokenTree::Ident(variable)) => {
+                    Ok(FieldFormat::Variable(variable.to_string()))
+                }
+                ("option", TokenTree::Literal(lit)) if is_str(lit) => {
+                    Ok(FieldFormat::Option(lit.to_string()))
+                }
+                ("format", TokenTree::Literal(lit)) if is_str(lit) => {
+                    Ok(FieldFormat::Format(lit.to_string()))
+                }
+                _ => unexpected(value.span()),
+            }
+        }
+        _ => unexpected(args.span()),
+    }
+}
+
+fn is_str(lit: &Literal) -> bool {
+    let lit = lit.to_string();
+    lit.starts_with('"') || lit.starts_with("r\"") || lit.starts_with("r#")
+}
+
+fn expand(name: &str, fields: &[Field]) -> String {
+    let mut formats = Vec::new();
+    let mut values = Vec::new();
+    for field in fields {
+        let format = match field.format {
+            FieldFormat::Name => format!("::std::string::String::from(\"#{{{}}}\")", field.name),
+            FieldFormat::Variable(ref variable) => {
+                format!("::tmux_interface::Variable::{}.to_string()", variable)
+            }
+            FieldFormat::Option(ref option) => {
+                format!("::std::format!(\"#{{{{{{}}}}}}\", {})", option)
+            }
+            FieldFormat::Format(ref format) => format!("::std::string::String::from({})", format),
+            FieldFormat::Skip => {
+                values.push(format!(
+                    "r#{}: ::std::default::Default::default()",
+                    field.name
+                ));
+                continue;
+            }
+        };
+        values.push(format!(
+            "r#{}: <{} as ::tmux_interface::FromFormatValue>::from_format_value(values[{}])?",
+            field.name,
+            field.ty,
+            formats.len()
+        ));
+        formats.push(format);
+    }
+
+    format!(
+        "impl ::tmux_interface::FormatQuery for {name} {{
+            fn formats() -> ::std::vec::Vec<::std::string::String> {{
+                ::std::vec![{formats}]
+            }}
+
+            fn from_values(
+                values: &[&str],
+            ) -> ::std::result::Result<Self, ::tmux_interface::Error> {{
+                if values.len() != {len} {{
+                    return ::std::result::Result::Err(::tmux_interface::Error::ParseFormatsOutput);
+                }}
+                ::std::result::Result::Ok({name} {{ {values} }})
+            }}
+        }}",
+        name = name,
+        formats = formats.join(", "),
+        len = formats.len(),
+        values = values.join(", "),
+    )
+}
+
+// `compile_error!("...");` pointing to the span
+fn compile_error(span: Span, msg: &str) -> TokenStream {
+    let mut msg = Literal::string(msg);
+    msg.set_span(span);
+    let mut args = Group::new(Delimiter::Parenthesis, TokenTree::Literal(msg).into());
+    args.set_span(span);
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    let mut semi = Punct::new(';', Spacing::Alone);
+    semi.set_span(span);
+    vec![
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(bang),
+        TokenTree::Group(args),
+        TokenTree::Punct(semi),
+    ]
+    .into_iter()
+    .collect()
+}